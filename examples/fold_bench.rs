@@ -0,0 +1,47 @@
+//! Times the `fold` and `for_each` of `StrWindows` against a loop calling
+//! `next`, which `for` loops and `try_fold` based adapters go through.
+//!
+//! Run with `cargo run --release --example fold_bench`.
+
+extern crate str_windows;
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use str_windows::str_windows;
+
+const ROUNDS: u32 = 20;
+
+/// Returns the fastest of `ROUNDS` runs of `f`.
+fn time<F: FnMut() -> usize>(mut f: F) -> Duration {
+    (0..ROUNDS)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .min()
+        .unwrap()
+}
+
+fn main() {
+    let input = "hello, 你好, s 😀😁 ".repeat(50_000);
+    let input = black_box(&input[..]);
+    println!("{:>6} {:>12} {:>12} {:>12}", "size", "next", "fold", "for_each");
+    for &size in &[1, 4, 16, 64] {
+        let next = time(|| {
+            let mut total = 0;
+            for window in str_windows(input, size) {
+                total += window.len();
+            }
+            total
+        });
+        let fold = time(|| str_windows(input, size).fold(0, |total, window| total + window.len()));
+        let for_each = time(|| {
+            let mut total = 0;
+            str_windows(input, size).for_each(|window| total += window.len());
+            total
+        });
+        println!("{:>6} {:>12?} {:>12?} {:>12?}", size, next, fold, for_each);
+    }
+}
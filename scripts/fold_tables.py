@@ -0,0 +1,102 @@
+#!/usr/bin/env python3
+"""Generates src/fold/tables.rs from the Unicode Character Database.
+
+Usage:
+
+    python3 scripts/fold_tables.py [UCD_DIR]
+
+The case foldings are read from `CaseFolding.txt` of the UCD version
+`UNICODE_VERSION`, under `UCD_DIR` if it's given, or else downloaded from
+unicode.org. The Turkic mappings (status `T`) are left out.
+"""
+
+import os
+import re
+import sys
+import urllib.request
+
+UNICODE_VERSION = "14.0.0"
+UCD_URL = "https://www.unicode.org/Public/%s/ucd/" % UNICODE_VERSION
+OUTPUT = os.path.join(os.path.dirname(__file__), "..", "src", "fold", "tables.rs")
+
+LINE = re.compile(r"^([0-9A-F]+); ([CFST]); ([0-9A-F ]+);")
+
+
+def read(ucd_dir, path):
+    if ucd_dir is not None:
+        with open(os.path.join(ucd_dir, path), encoding="utf-8") as f:
+            return f.read()
+    with urllib.request.urlopen(UCD_URL + path) as f:
+        return f.read().decode("utf-8")
+
+
+def parse(text):
+    """Yields the (char, status, mapping) of the lines of `CaseFolding.txt`."""
+    version = re.search(r"^# \S+-(\d+\.\d+\.\d+)\.txt", text, re.M)
+    if version is not None and version.group(1) != UNICODE_VERSION:
+        sys.exit("expected version %s, got %s" % (UNICODE_VERSION, version.group(1)))
+    for line in text.splitlines():
+        m = LINE.match(line)
+        if m:
+            yield int(m.group(1), 16), m.group(2), [int(c, 16) for c in m.group(3).split()]
+
+
+def escape(c):
+    return "\\u{%x}" % c
+
+
+def pack(entries):
+    """Packs the entries of a table into lines of at most 100 columns."""
+    lines = []
+    line = "   "
+    for entry in entries:
+        entry = " " + entry
+        if len(line) + len(entry) > 100:
+            lines.append(line)
+            line = "   "
+        line += entry
+    lines.append(line)
+    return lines
+
+
+def main():
+    ucd_dir = sys.argv[1] if len(sys.argv) > 1 else None
+    simple = []
+    full = []
+    for c, status, mapping in parse(read(ucd_dir, "CaseFolding.txt")):
+        if status in "CS":
+            simple.append((c, mapping[0]))
+        elif status == "F":
+            full.append((c, mapping))
+    simple.sort()
+    full.sort()
+
+    major_minor = UNICODE_VERSION.rsplit(".", 1)[0]
+    with open(OUTPUT, "w", encoding="utf-8") as f:
+        f.write(
+            "//! The case foldings of Unicode %s, generated by `scripts/fold_tables.py`\n"
+            "//! from `CaseFolding.txt`.\n"
+            "\n"
+            "/// The simple case foldings, the `C` and `S` mappings, sorted.\n"
+            "pub(super) const SIMPLE: &[(char, char)] = &[\n" % major_minor
+        )
+        for line in pack("('%s', '%s')," % (escape(c), escape(to)) for c, to in simple):
+            f.write(line + "\n")
+        f.write(
+            "];\n"
+            "\n"
+            "/// The full case foldings mapping a char to several, the `F` mappings,\n"
+            "/// sorted. The chars without one fold as with the simple folding.\n"
+            "pub(super) const FULL: &[(char, &str)] = &[\n"
+        )
+        entries = (
+            "('%s', \"%s\")," % (escape(c), "".join(escape(to) for to in mapping))
+            for c, mapping in full
+        )
+        for line in pack(entries):
+            f.write(line + "\n")
+        f.write("];\n")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,180 @@
+#!/usr/bin/env python3
+"""Generates src/grapheme/tables.rs and src/grapheme/break_tests.rs from the
+Unicode Character Database.
+
+Usage:
+
+    python3 scripts/grapheme_tables.py [UCD_DIR]
+
+The grapheme cluster break properties are read from
+`auxiliary/GraphemeBreakProperty.txt` and the `Extended_Pictographic`
+property from `emoji/emoji-data.txt` of the UCD version `UNICODE_VERSION`,
+and the tests from `auxiliary/GraphemeBreakTest.txt`, under `UCD_DIR` if
+it's given, or else downloaded from unicode.org. Bumping the version means
+checking the rules of UAX #29 of the new version against
+`next_grapheme_boundary` too.
+"""
+
+import os
+import re
+import sys
+import urllib.request
+
+UNICODE_VERSION = "14.0.0"
+UCD_URL = "https://www.unicode.org/Public/%s/ucd/" % UNICODE_VERSION
+OUTPUT = os.path.join(os.path.dirname(__file__), "..", "src", "grapheme", "tables.rs")
+TESTS_OUTPUT = os.path.join(os.path.dirname(__file__), "..", "src", "grapheme", "break_tests.rs")
+
+# the `Gcb` variants of the properties in the table
+VARIANTS = {
+    "Prepend": "Prepend",
+    "Control": "Control",
+    "Extend": "Extend",
+    "SpacingMark": "SpacingMark",
+    "Extended_Pictographic": "ExtendedPictographic",
+}
+
+# the properties `gcb` matches itself, with their ranges
+BUILT_IN = {
+    "CR": [(0xD, 0xD)],
+    "LF": [(0xA, 0xA)],
+    "ZWJ": [(0x200D, 0x200D)],
+    "Regional_Indicator": [(0x1F1E6, 0x1F1FF)],
+    "L": [(0x1100, 0x115F), (0xA960, 0xA97C)],
+    "V": [(0x1160, 0x11A7), (0xD7B0, 0xD7C6)],
+    "T": [(0x11A8, 0x11FF), (0xD7CB, 0xD7FB)],
+    "LV": None,
+    "LVT": None,
+}
+
+LINE = re.compile(r"^([0-9A-F]+)(?:\.\.([0-9A-F]+))?\s*;\s*(\w+)")
+
+
+def read(ucd_dir, path):
+    if ucd_dir is not None:
+        with open(os.path.join(ucd_dir, path), encoding="utf-8") as f:
+            return f.read()
+    with urllib.request.urlopen(UCD_URL + path) as f:
+        return f.read().decode("utf-8")
+
+
+def parse(text):
+    """Yields the (start, end, property) of the lines of a UCD file."""
+    version = re.search(r"^# \S+-(\d+\.\d+\.\d+)\.txt", text, re.M)
+    if version is not None and version.group(1) != UNICODE_VERSION:
+        sys.exit("expected version %s, got %s" % (UNICODE_VERSION, version.group(1)))
+    for line in text.splitlines():
+        m = LINE.match(line)
+        if m:
+            start = int(m.group(1), 16)
+            end = int(m.group(2) or m.group(1), 16)
+            yield start, end, m.group(3)
+
+
+def parse_tests(text):
+    """Yields the clusters of the tests of `GraphemeBreakTest.txt`."""
+    version = re.search(r"^# \S+-(\d+\.\d+\.\d+)\.txt", text, re.M)
+    if version is not None and version.group(1) != UNICODE_VERSION:
+        sys.exit("expected version %s, got %s" % (UNICODE_VERSION, version.group(1)))
+    for line in text.splitlines():
+        line = line.split("#", 1)[0].split()
+        if not line:
+            continue
+        clusters = []
+        for token in line:
+            if token == "\u00f7":
+                clusters.append("")
+            elif token != "\u00d7":
+                clusters[-1] += "\\u{%x}" % int(token, 16)
+        # the last break is at the end of the string
+        yield clusters[:-1]
+
+
+def pack(entries):
+    """Packs the entries of a table into lines of at most 100 columns."""
+    lines = []
+    line = "   "
+    for entry in entries:
+        entry = " " + entry
+        if len(line) + len(entry) > 100:
+            lines.append(line)
+            line = "   "
+        line += entry
+    lines.append(line)
+    return lines
+
+
+def ranges(values):
+    """Merges a map of chars to variants into sorted ranges."""
+    out = []
+    for c in sorted(values):
+        if out and out[-1][1] == c - 1 and out[-1][2] == values[c]:
+            out[-1][1] = c
+        else:
+            out.append([c, c, values[c]])
+    return out
+
+
+def main():
+    ucd_dir = sys.argv[1] if len(sys.argv) > 1 else None
+    values = {}
+    built_in = {}
+    for start, end, prop in parse(read(ucd_dir, "auxiliary/GraphemeBreakProperty.txt")):
+        if prop in BUILT_IN:
+            built_in.setdefault(prop, []).append((start, end))
+            continue
+        for c in range(start, end + 1):
+            values[c] = VARIANTS[prop]
+    for prop, expected in BUILT_IN.items():
+        if expected is not None and sorted(built_in.get(prop, [])) != expected:
+            sys.exit("the %s ranges changed, update `gcb`" % prop)
+    for start, end, prop in parse(read(ucd_dir, "emoji/emoji-data.txt")):
+        if prop != "Extended_Pictographic":
+            continue
+        for c in range(start, end + 1):
+            if c in values:
+                sys.exit("U+%04X is both %s and Extended_Pictographic" % (c, values[c]))
+            values[c] = VARIANTS[prop]
+
+    entries = (
+        "('\\u{%x}', '\\u{%x}', %s)," % (start, end, variant)
+        for start, end, variant in ranges(values)
+    )
+    lines = pack(entries)
+
+    major_minor = UNICODE_VERSION.rsplit(".", 1)[0]
+    with open(OUTPUT, "w", encoding="utf-8") as f:
+        f.write(
+            "//! The grapheme cluster break properties of Unicode %s, generated by\n"
+            "//! `scripts/grapheme_tables.py` from `GraphemeBreakProperty.txt` and the\n"
+            "//! `Extended_Pictographic` property of `emoji-data.txt`.\n"
+            "\n"
+            "use super::Gcb;\n"
+            "use super::Gcb::*;\n"
+            "\n"
+            "/// The ranges of chars with a break property other than `Other`, sorted. The\n"
+            "/// CR, LF, ZWJ, regional indicators and Hangul properties aren't included.\n"
+            "pub(super) const GRAPHEME_CLUSTER_BREAK: &[(char, char, Gcb)] = &[\n"
+            % major_minor
+        )
+        for line in lines:
+            f.write(line + "\n")
+        f.write("];\n")
+
+    tests = parse_tests(read(ucd_dir, "auxiliary/GraphemeBreakTest.txt"))
+    entries = ("&[%s]," % ", ".join('"%s"' % cluster for cluster in test) for test in tests)
+    with open(TESTS_OUTPUT, "w", encoding="utf-8") as f:
+        f.write(
+            "//! The tests of `GraphemeBreakTest.txt` of Unicode %s, generated by\n"
+            "//! `scripts/grapheme_tables.py`.\n"
+            "\n"
+            "/// The strings of the tests, split into their grapheme clusters.\n"
+            "pub(super) const BREAK_TESTS: &[&[&str]] = &[\n" % major_minor
+        )
+        for line in pack(entries):
+            f.write(line + "\n")
+        f.write("];\n")
+
+
+if __name__ == "__main__":
+    main()
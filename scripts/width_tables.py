@@ -0,0 +1,92 @@
+#!/usr/bin/env python3
+"""Generates src/width/tables.rs from the Unicode Character Database.
+
+Usage:
+
+    python3 scripts/width_tables.py
+
+The general categories and East Asian widths of the chars come from the
+`unicodedata` module, so the Python version picks the UCD version: it must
+be `UNICODE_VERSION`, which Python 3.11 ships.
+"""
+
+import os
+import sys
+import unicodedata
+
+UNICODE_VERSION = "14.0.0"
+OUTPUT = os.path.join(os.path.dirname(__file__), "..", "src", "width", "tables.rs")
+
+# the blocks whose unassigned chars default to wide in EastAsianWidth.txt
+IDEOGRAPHS = [
+    (0x3400, 0x4DBF),
+    (0x4E00, 0x9FFF),
+    (0xF900, 0xFAFF),
+    (0x20000, 0x2FFFD),
+    (0x30000, 0x3FFFD),
+]
+
+# the medial and final Hangul jamo, which join the initial one before them
+JAMO = (0x1160, 0x11FF)
+
+
+def width(c):
+    """Returns the width of `c` if it isn't 1, or `None`."""
+    ch = chr(c)
+    category = unicodedata.category(ch)
+    # the soft hyphen is a format char, but it's shown as a hyphen
+    if (category in ("Mn", "Me", "Cf") and c != 0xAD) or JAMO[0] <= c <= JAMO[1]:
+        return 0
+    if category == "Cn":
+        # `unicodedata` doesn't give the widths of unassigned chars
+        return 2 if any(start <= c <= end for start, end in IDEOGRAPHS) else None
+    if unicodedata.east_asian_width(ch) in ("W", "F"):
+        return 2
+    return None
+
+
+def main():
+    if unicodedata.unidata_version != UNICODE_VERSION:
+        sys.exit(
+            "expected Unicode %s, this Python has %s"
+            % (UNICODE_VERSION, unicodedata.unidata_version)
+        )
+    ranges = []
+    for c in range(0x110000):
+        w = width(c)
+        if w is None:
+            continue
+        if ranges and ranges[-1][1] == c - 1 and ranges[-1][2] == w:
+            ranges[-1][1] = c
+        else:
+            ranges.append([c, c, w])
+
+    lines = []
+    line = "   "
+    for start, end, w in ranges:
+        entry = " ('\\u{%x}', '\\u{%x}', %d)," % (start, end, w)
+        if len(line) + len(entry) > 100:
+            lines.append(line)
+            line = "   "
+        line += entry
+    lines.append(line)
+
+    major_minor = UNICODE_VERSION.rsplit(".", 1)[0]
+    with open(OUTPUT, "w", encoding="utf-8") as f:
+        f.write(
+            "//! The display widths of chars, derived from the general categories and the\n"
+            "//! East Asian widths of Unicode %s by `scripts/width_tables.py`.\n"
+            "\n"
+            "/// The ranges of chars that are zero width or wide, sorted, with their\n"
+            "/// widths. Control chars aren't included, and unassigned chars are only\n"
+            "/// wide in the blocks of ideographs.\n"
+            "pub(super) const WIDTHS: &[(char, char, u8)] = &[\n"
+            % major_minor
+        )
+        for line in lines:
+            f.write(line + "\n")
+        f.write("];\n")
+
+
+if __name__ == "__main__":
+    main()
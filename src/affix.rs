@@ -0,0 +1,217 @@
+use std::iter::FusedIterator;
+
+use boundary::{next_char_boundary, nth_char_boundary, nth_char_boundary_back, prev_char_boundary};
+
+/// Returns the prefixes of the input of 1 to `max_len` chars, from the
+/// shortest to the longest.
+///
+/// Pass `usize::MAX` as `max_len` for all the prefixes, the last one being
+/// the whole input.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::char_prefixes;
+///
+/// let prefixes = char_prefixes("😀bcd", 3).collect::<Vec<_>>();
+/// assert_eq!(prefixes, ["😀", "😀b", "😀bc"]);
+/// ```
+pub fn char_prefixes(input: &str, max_len: usize) -> CharPrefixes<'_> {
+    CharPrefixes {
+        input,
+        front: next_char_boundary(input, 0),
+        back: nth_char_boundary(input, max_len),
+        len: input.chars().count().min(max_len),
+    }
+}
+
+/// Returns the suffixes of the input of 1 to `max_len` chars, from the
+/// shortest to the longest.
+///
+/// Pass `usize::MAX` as `max_len` for all the suffixes, the last one being
+/// the whole input.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::char_suffixes;
+///
+/// let suffixes = char_suffixes("abc😀", usize::MAX).collect::<Vec<_>>();
+/// assert_eq!(suffixes, ["😀", "c😀", "bc😀", "abc😀"]);
+/// ```
+pub fn char_suffixes(input: &str, max_len: usize) -> CharSuffixes<'_> {
+    CharSuffixes {
+        input,
+        front: prev_char_boundary(input, input.len()),
+        back: nth_char_boundary_back(input, max_len),
+        len: input.chars().count().min(max_len),
+    }
+}
+
+/// An iterator over the prefixes of a `&str` up to a number of chars.
+///
+/// This struct is created by [`char_prefixes`].
+#[derive(Clone, Debug)]
+pub struct CharPrefixes<'a> {
+    input: &'a str,
+    /// End of the next prefix from the front.
+    front: usize,
+    /// End of the next prefix from the back.
+    back: usize,
+    /// Number of prefixes left to yield.
+    len: usize,
+}
+
+impl<'a> FusedIterator for CharPrefixes<'a> { }
+
+impl<'a> Iterator for CharPrefixes<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        let prefix = &self.input[..self.front];
+        self.front = next_char_boundary(self.input, self.front);
+        Some(prefix)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a> DoubleEndedIterator for CharPrefixes<'a> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        let prefix = &self.input[..self.back];
+        self.back = prev_char_boundary(self.input, self.back);
+        Some(prefix)
+    }
+}
+
+impl<'a> ExactSizeIterator for CharPrefixes<'a> { }
+
+/// An iterator over the suffixes of a `&str` up to a number of chars.
+///
+/// This struct is created by [`char_suffixes`].
+#[derive(Clone, Debug)]
+pub struct CharSuffixes<'a> {
+    input: &'a str,
+    /// Start of the next suffix from the front.
+    front: usize,
+    /// Start of the next suffix from the back.
+    back: usize,
+    /// Number of suffixes left to yield.
+    len: usize,
+}
+
+impl<'a> FusedIterator for CharSuffixes<'a> { }
+
+impl<'a> Iterator for CharSuffixes<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        let suffix = &self.input[self.front..];
+        self.front = prev_char_boundary(self.input, self.front);
+        Some(suffix)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a> DoubleEndedIterator for CharSuffixes<'a> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        let suffix = &self.input[self.back..];
+        self.back = next_char_boundary(self.input, self.back);
+        Some(suffix)
+    }
+}
+
+impl<'a> ExactSizeIterator for CharSuffixes<'a> { }
+
+
+#[cfg(test)]
+mod tests {
+    use super::{char_prefixes, char_suffixes};
+
+    #[test]
+    fn prefixes_test() {
+        let prefixes = char_prefixes("头b尾", usize::MAX).collect::<Vec<_>>();
+        assert_eq!(prefixes, ["头", "头b", "头b尾"]);
+        let prefixes = char_prefixes("😀😁b", 2).rev().collect::<Vec<_>>();
+        assert_eq!(prefixes, ["😀😁", "😀"]);
+        assert_eq!(char_prefixes("头尾", 0).next(), None);
+        assert_eq!(char_prefixes("", usize::MAX).next(), None);
+
+        let mut iter = char_prefixes("é😀头尾", 3);
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.next_back(), Some("é😀头"));
+        assert_eq!(iter.next(), Some("é"));
+        assert_eq!(iter.len(), 1);
+        assert_eq!(iter.next_back(), Some("é😀"));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn suffixes_test() {
+        let suffixes = char_suffixes("头b尾", usize::MAX).collect::<Vec<_>>();
+        assert_eq!(suffixes, ["尾", "b尾", "头b尾"]);
+        let suffixes = char_suffixes("b😀😁", 2).rev().collect::<Vec<_>>();
+        assert_eq!(suffixes, ["😀😁", "😁"]);
+        assert_eq!(char_suffixes("头尾", 0).next(), None);
+        assert_eq!(char_suffixes("", usize::MAX).next(), None);
+
+        let mut iter = char_suffixes("头尾😀é", 3);
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.next_back(), Some("尾😀é"));
+        assert_eq!(iter.next(), Some("é"));
+        assert_eq!(iter.next_back(), Some("😀é"));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn all_affixes_test() {
+        for &src in &["hello, 你好, hi", "头尾都是", "😀 😁", "é"] {
+            let chars = src.chars().count();
+            for max_len in 0..=chars + 1 {
+                let expected = src.char_indices()
+                    .map(|x| x.0)
+                    .skip(1)
+                    .chain(Some(src.len()))
+                    .take(max_len)
+                    .map(|end| &src[..end])
+                    .collect::<Vec<_>>();
+                assert_eq!(char_prefixes(src, max_len).collect::<Vec<_>>(), expected);
+                let mut back = char_prefixes(src, max_len).rev().collect::<Vec<_>>();
+                back.reverse();
+                assert_eq!(back, expected);
+
+                let expected = src.char_indices()
+                    .rev()
+                    .take(max_len)
+                    .map(|x| &src[x.0..])
+                    .collect::<Vec<_>>();
+                assert_eq!(char_suffixes(src, max_len).collect::<Vec<_>>(), expected);
+                let mut back = char_suffixes(src, max_len).rev().collect::<Vec<_>>();
+                back.reverse();
+                assert_eq!(back, expected);
+            }
+        }
+    }
+}
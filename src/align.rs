@@ -0,0 +1,150 @@
+use std::iter::FusedIterator;
+
+use boundary::{next_char_boundary, prev_char_boundary};
+
+/// Returns the alignment of `b` over `a` matching the most chars, as
+/// `(offset, matches)`, e.g. to match OCR output against a reference.
+///
+/// See [`alignments`] for the meaning of the offset. Ties go to the smallest
+/// offset. If no chars match, including when either string is empty, this
+/// returns `(0, 0)`.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::best_alignment;
+///
+/// assert_eq!(best_alignment("the cat", "cab"), (4, 2));
+/// assert_eq!(best_alignment("头尾", "😀头尾"), (-1, 2));
+/// ```
+pub fn best_alignment(a: &str, b: &str) -> (isize, usize) {
+    alignments(a, b).fold((0, 0), |best, x| if x.1 > best.1 { x } else { best })
+}
+
+/// Returns the number of matching chars for every alignment of `b` over `a`,
+/// as `(offset, matches)`, i.e. their cross-correlation.
+///
+/// At offset `o`, the char of index `i` of `a` is compared with the char of
+/// index `i - o` of `b`, so a positive offset shifts `b` to the right. The
+/// offsets go from `1 - b_chars` to `a_chars - 1`, all the alignments where
+/// the strings overlap by at least one char.
+///
+/// The chars are compared in place, walking the boundaries of both strings,
+/// without collecting them.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::alignments;
+///
+/// let scores = alignments("aba", "ba").collect::<Vec<_>>();
+/// assert_eq!(scores, [(-1, 1), (0, 0), (1, 2), (2, 0)]);
+/// ```
+pub fn alignments<'a>(a: &'a str, b: &'a str) -> Alignments<'a> {
+    let a_chars = a.chars().count() as isize;
+    let b_chars = b.chars().count() as isize;
+    let offset = if a_chars == 0 || b_chars == 0 { a_chars } else { 1 - b_chars };
+    Alignments {
+        a,
+        b,
+        a_start: 0,
+        b_start: prev_char_boundary(b, b.len()),
+        offset,
+        end: a_chars,
+    }
+}
+
+/// An iterator over the number of matching chars for every alignment of two
+/// `&str`s.
+///
+/// This struct is created by [`alignments`].
+#[derive(Clone, Debug)]
+pub struct Alignments<'a> {
+    a: &'a str,
+    b: &'a str,
+    /// Start of the overlap of the next alignment, in `a` and `b`.
+    a_start: usize,
+    b_start: usize,
+    /// Offset of the next alignment.
+    offset: isize,
+    /// The offset past the last alignment.
+    end: isize,
+}
+
+impl<'a> FusedIterator for Alignments<'a> { }
+
+impl<'a> Iterator for Alignments<'a> {
+    type Item = (isize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset >= self.end {
+            return None;
+        }
+        let matches = self.a[self.a_start..].chars()
+            .zip(self.b[self.b_start..].chars())
+            .filter(|&(a, b)| a == b)
+            .count();
+        let offset = self.offset;
+        if offset < 0 {
+            self.b_start = prev_char_boundary(self.b, self.b_start);
+        } else {
+            self.a_start = next_char_boundary(self.a, self.a_start);
+        }
+        self.offset += 1;
+        Some((offset, matches))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.end - self.offset).max(0) as usize;
+        (len, Some(len))
+    }
+}
+
+impl<'a> ExactSizeIterator for Alignments<'a> { }
+
+
+#[cfg(test)]
+mod tests {
+    use super::{alignments, best_alignment};
+
+    #[test]
+    fn best_test() {
+        assert_eq!(best_alignment("hello world", "wor1d"), (6, 4));
+        assert_eq!(best_alignment("東京都庁", "京都"), (1, 2));
+        assert_eq!(best_alignment("😀", "a😀b😀"), (-3, 1));
+        assert_eq!(best_alignment("abc", "xyz"), (0, 0));
+        assert_eq!(best_alignment("", "abc"), (0, 0));
+        assert_eq!(best_alignment("abc", ""), (0, 0));
+    }
+
+    #[test]
+    fn naive_test() {
+        let srcs = ["hello, 你好, hi", "你好", "s 😀😁", "😀", "aa", ""];
+        for &a in &srcs {
+            for &b in &srcs {
+                let a_chars = a.chars().collect::<Vec<_>>();
+                let b_chars = b.chars().collect::<Vec<_>>();
+                let low = if a.is_empty() || b.is_empty() {
+                    a_chars.len() as isize
+                } else {
+                    1 - b_chars.len() as isize
+                };
+                let expected = (low..a_chars.len() as isize)
+                    .map(|o| {
+                        let matches = (0..a_chars.len() as isize)
+                            .filter(|&i| {
+                                let j = i - o;
+                                0 <= j && j < b_chars.len() as isize
+                                    && a_chars[i as usize] == b_chars[j as usize]
+                            })
+                            .count();
+                        (o, matches)
+                    })
+                    .collect::<Vec<_>>();
+                let iter = alignments(a, b);
+                assert_eq!(iter.len(), expected.len());
+                assert_eq!(iter.collect::<Vec<_>>(), expected, "{:?} {:?}", a, b);
+            }
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use std::iter::FusedIterator;
+use std::str::Chars;
+
+/// Returns the windows of `N` chars of [`str_windows`] as `[char; N]`
+/// arrays, e.g. to hash fixed-size n-grams into sets without borrowing the
+/// input.
+///
+/// Each window is the previous one shifted by one char, so each char is
+/// decoded once. An `N` of 0 fails to compile.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::char_array_windows;
+///
+/// let windows = char_array_windows::<2>("a头😀").collect::<Vec<_>>();
+/// assert_eq!(windows, [['a', '头'], ['头', '😀']]);
+/// ```
+///
+/// ```compile_fail
+/// use str_windows::char_array_windows;
+///
+/// char_array_windows::<0>("头尾").next();
+/// ```
+///
+/// [`str_windows`]: crate::str_windows
+pub fn char_array_windows<const N: usize>(input: &str) -> CharArrayWindows<'_, N> {
+    const { assert!(N != 0, "window size must be non-zero") };
+    CharArrayWindows {
+        chars: input.chars(),
+        window: None,
+    }
+}
+
+/// An iterator over the windows of `N` chars of a `&str` as `[char; N]`
+/// arrays.
+///
+/// This struct is created by [`char_array_windows`].
+#[derive(Clone, Debug)]
+pub struct CharArrayWindows<'a, const N: usize> {
+    chars: Chars<'a>,
+    /// The last window yielded, if any.
+    window: Option<[char; N]>,
+}
+
+impl<'a, const N: usize> FusedIterator for CharArrayWindows<'a, N> { }
+
+impl<'a, const N: usize> Iterator for CharArrayWindows<'a, N> {
+    type Item = [char; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let window = match self.window.as_mut() {
+            Some(window) => {
+                let c = self.chars.next()?;
+                window.copy_within(1.., 0);
+                window[N - 1] = c;
+                *window
+            }
+            None => {
+                let mut window = ['\0'; N];
+                for slot in &mut window {
+                    *slot = self.chars.next()?;
+                }
+                *self.window.insert(window)
+            }
+        };
+        Some(window)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (low, high) = self.chars.size_hint();
+        match self.window {
+            Some(_) => (low, high),
+            None => (low.saturating_sub(N - 1), high.map(|high| high.saturating_sub(N - 1))),
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::char_array_windows;
+    use str_windows;
+
+    fn check<const N: usize>(src: &str) {
+        let windows = char_array_windows::<N>(src).collect::<Vec<_>>();
+        let expected = str_windows(src, N).map(|window| window.chars().collect::<Vec<_>>());
+        assert!(windows.iter().map(|window| window.to_vec()).eq(expected));
+    }
+
+    #[test]
+    fn char_array_test() {
+        for &src in &["hello, 你好, hi", "头尾都是", "头部是!", ": 尾部是", "s 😀😁", ""] {
+            check::<1>(src);
+            check::<2>(src);
+            check::<3>(src);
+            check::<5>(src);
+            check::<8>(src);
+        }
+        let mut iter = char_array_windows::<3>("头尾");
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+    }
+}
@@ -0,0 +1,260 @@
+use std::borrow::Cow;
+use std::iter::FusedIterator;
+use std::ops::Range;
+
+/// Returns `true` if `c` is in one of the combining diacritical marks blocks.
+fn is_mark(c: char) -> bool {
+    matches!(
+        c,
+        '\u{300}'..='\u{36f}'
+            | '\u{1ab0}'..='\u{1aff}'
+            | '\u{1dc0}'..='\u{1dff}'
+            | '\u{20d0}'..='\u{20ff}'
+            | '\u{fe20}'..='\u{fe2f}'
+    )
+}
+
+/// Returns the ASCII folding of the Latin letter `c`, if it has one.
+fn ascii_fold(c: char) -> Option<&'static str> {
+    Some(match c {
+        'À' | 'Á' | 'Â' | 'Ã' | 'Ä' | 'Å' | 'Ā' | 'Ă' | 'Ą' | 'Ǎ' | 'Ǟ' | 'Ǡ' | 'Ǻ' | 'Ȁ' | 'Ȃ'
+        | 'Ȧ' | 'Ḁ' | 'Ạ' | 'Ả' | 'Ấ' | 'Ầ' | 'Ẩ' | 'Ẫ' | 'Ậ' | 'Ắ' | 'Ằ' | 'Ẳ' | 'Ẵ' | 'Ặ' => "A",
+        'Æ' => "AE",
+        'Ḃ' | 'Ḅ' | 'Ḇ' => "B",
+        'Ç' | 'Ć' | 'Ĉ' | 'Ċ' | 'Č' | 'Ḉ' => "C",
+        'Ð' | 'Ď' | 'Đ' | 'Ḋ' | 'Ḍ' | 'Ḏ' | 'Ḑ' | 'Ḓ' => "D",
+        'È' | 'É' | 'Ê' | 'Ë' | 'Ē' | 'Ĕ' | 'Ė' | 'Ę' | 'Ě' | 'Ȅ' | 'Ȇ' | 'Ȩ' | 'Ḕ' | 'Ḗ' | 'Ḙ'
+        | 'Ḛ' | 'Ḝ' | 'Ẹ' | 'Ẻ' | 'Ẽ' | 'Ế' | 'Ề' | 'Ể' | 'Ễ' | 'Ệ' => "E",
+        'Ḟ' => "F",
+        'Ĝ' | 'Ğ' | 'Ġ' | 'Ģ' | 'Ǧ' | 'Ǵ' | 'Ḡ' => "G",
+        'Ĥ' | 'Ħ' | 'Ȟ' | 'Ḣ' | 'Ḥ' | 'Ḧ' | 'Ḩ' | 'Ḫ' => "H",
+        'Ì' | 'Í' | 'Î' | 'Ï' | 'Ĩ' | 'Ī' | 'Ĭ' | 'Į' | 'İ' | 'Ɨ' | 'Ǐ' | 'Ȉ' | 'Ȋ' | 'Ḭ' | 'Ḯ'
+        | 'Ỉ' | 'Ị' => "I",
+        'Ĵ' => "J",
+        'Ķ' | 'Ǩ' | 'Ḱ' | 'Ḳ' | 'Ḵ' => "K",
+        'Ĺ' | 'Ļ' | 'Ľ' | 'Ŀ' | 'Ł' | 'Ḷ' | 'Ḹ' | 'Ḻ' | 'Ḽ' => "L",
+        'Ḿ' | 'Ṁ' | 'Ṃ' => "M",
+        'Ñ' | 'Ń' | 'Ņ' | 'Ň' | 'Ǹ' | 'Ṅ' | 'Ṇ' | 'Ṉ' | 'Ṋ' => "N",
+        'Ò' | 'Ó' | 'Ô' | 'Õ' | 'Ö' | 'Ø' | 'Ō' | 'Ŏ' | 'Ő' | 'Ơ' | 'Ǒ' | 'Ǫ' | 'Ǭ' | 'Ȍ' | 'Ȏ'
+        | 'Ȫ' | 'Ȭ' | 'Ȯ' | 'Ȱ' | 'Ṍ' | 'Ṏ' | 'Ṑ' | 'Ṓ' | 'Ọ' | 'Ỏ' | 'Ố' | 'Ồ' | 'Ổ' | 'Ỗ' | 'Ộ'
+        | 'Ớ' | 'Ờ' | 'Ở' | 'Ỡ' | 'Ợ' => "O",
+        'Œ' => "OE",
+        'Ṕ' | 'Ṗ' => "P",
+        'Ŕ' | 'Ŗ' | 'Ř' | 'Ȑ' | 'Ȓ' | 'Ṙ' | 'Ṛ' | 'Ṝ' | 'Ṟ' => "R",
+        'Ś' | 'Ŝ' | 'Ş' | 'Š' | 'Ș' | 'Ṡ' | 'Ṣ' | 'Ṥ' | 'Ṧ' | 'Ṩ' => "S",
+        'ẞ' => "SS",
+        'Ţ' | 'Ť' | 'Ŧ' | 'Ț' | 'Ṫ' | 'Ṭ' | 'Ṯ' | 'Ṱ' => "T",
+        'Þ' => "TH",
+        'Ù' | 'Ú' | 'Û' | 'Ü' | 'Ũ' | 'Ū' | 'Ŭ' | 'Ů' | 'Ű' | 'Ų' | 'Ư' | 'Ǔ' | 'Ǖ' | 'Ǘ' | 'Ǚ'
+        | 'Ǜ' | 'Ȕ' | 'Ȗ' | 'Ṳ' | 'Ṵ' | 'Ṷ' | 'Ṹ' | 'Ṻ' | 'Ụ' | 'Ủ' | 'Ứ' | 'Ừ' | 'Ử' | 'Ữ' | 'Ự'
+        => "U",
+        'Ṽ' | 'Ṿ' => "V",
+        'Ŵ' | 'Ẁ' | 'Ẃ' | 'Ẅ' | 'Ẇ' | 'Ẉ' => "W",
+        'Ẋ' | 'Ẍ' => "X",
+        'Ý' | 'Ŷ' | 'Ÿ' | 'Ȳ' | 'Ẏ' | 'Ỳ' | 'Ỵ' | 'Ỷ' | 'Ỹ' => "Y",
+        'Ź' | 'Ż' | 'Ž' | 'Ẑ' | 'Ẓ' | 'Ẕ' => "Z",
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ă' | 'ą' | 'ǎ' | 'ǟ' | 'ǡ' | 'ǻ' | 'ȁ' | 'ȃ'
+        | 'ȧ' | 'ḁ' | 'ạ' | 'ả' | 'ấ' | 'ầ' | 'ẩ' | 'ẫ' | 'ậ' | 'ắ' | 'ằ' | 'ẳ' | 'ẵ' | 'ặ' => "a",
+        'æ' => "ae",
+        'ƀ' | 'ḃ' | 'ḅ' | 'ḇ' => "b",
+        'ç' | 'ć' | 'ĉ' | 'ċ' | 'č' | 'ḉ' => "c",
+        'ð' | 'ď' | 'đ' | 'ḋ' | 'ḍ' | 'ḏ' | 'ḑ' | 'ḓ' => "d",
+        'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ĕ' | 'ė' | 'ę' | 'ě' | 'ȅ' | 'ȇ' | 'ȩ' | 'ḕ' | 'ḗ' | 'ḙ'
+        | 'ḛ' | 'ḝ' | 'ẹ' | 'ẻ' | 'ẽ' | 'ế' | 'ề' | 'ể' | 'ễ' | 'ệ' => "e",
+        'ƒ' | 'ḟ' => "f",
+        'ĝ' | 'ğ' | 'ġ' | 'ģ' | 'ǧ' | 'ǵ' | 'ḡ' => "g",
+        'ĥ' | 'ħ' | 'ȟ' | 'ḣ' | 'ḥ' | 'ḧ' | 'ḩ' | 'ḫ' | 'ẖ' => "h",
+        'ì' | 'í' | 'î' | 'ï' | 'ĩ' | 'ī' | 'ĭ' | 'į' | 'ı' | 'ǐ' | 'ȉ' | 'ȋ' | 'ḭ' | 'ḯ' | 'ỉ'
+        | 'ị' => "i",
+        'ĵ' | 'ǰ' => "j",
+        'ķ' | 'ǩ' | 'ḱ' | 'ḳ' | 'ḵ' => "k",
+        'ĺ' | 'ļ' | 'ľ' | 'ŀ' | 'ł' | 'ḷ' | 'ḹ' | 'ḻ' | 'ḽ' => "l",
+        'ḿ' | 'ṁ' | 'ṃ' => "m",
+        'ñ' | 'ń' | 'ņ' | 'ň' | 'ǹ' | 'ṅ' | 'ṇ' | 'ṉ' | 'ṋ' => "n",
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' | 'ŏ' | 'ő' | 'ơ' | 'ǒ' | 'ǫ' | 'ǭ' | 'ȍ' | 'ȏ'
+        | 'ȫ' | 'ȭ' | 'ȯ' | 'ȱ' | 'ṍ' | 'ṏ' | 'ṑ' | 'ṓ' | 'ọ' | 'ỏ' | 'ố' | 'ồ' | 'ổ' | 'ỗ' | 'ộ'
+        | 'ớ' | 'ờ' | 'ở' | 'ỡ' | 'ợ' => "o",
+        'œ' => "oe",
+        'ṕ' | 'ṗ' => "p",
+        'ŕ' | 'ŗ' | 'ř' | 'ȑ' | 'ȓ' | 'ṙ' | 'ṛ' | 'ṝ' | 'ṟ' => "r",
+        'ś' | 'ŝ' | 'ş' | 'š' | 'ș' | 'ṡ' | 'ṣ' | 'ṥ' | 'ṧ' | 'ṩ' => "s",
+        'ß' => "ss",
+        'ţ' | 'ť' | 'ŧ' | 'ț' | 'ṫ' | 'ṭ' | 'ṯ' | 'ṱ' | 'ẗ' => "t",
+        'þ' => "th",
+        'ù' | 'ú' | 'û' | 'ü' | 'ũ' | 'ū' | 'ŭ' | 'ů' | 'ű' | 'ų' | 'ư' | 'ǔ' | 'ǖ' | 'ǘ' | 'ǚ'
+        | 'ǜ' | 'ȕ' | 'ȗ' | 'ṳ' | 'ṵ' | 'ṷ' | 'ṹ' | 'ṻ' | 'ụ' | 'ủ' | 'ứ' | 'ừ' | 'ử' | 'ữ' | 'ự'
+        => "u",
+        'ṽ' | 'ṿ' => "v",
+        'ŵ' | 'ẁ' | 'ẃ' | 'ẅ' | 'ẇ' | 'ẉ' | 'ẘ' => "w",
+        'ẋ' | 'ẍ' => "x",
+        'ý' | 'ÿ' | 'ŷ' | 'ȳ' | 'ẏ' | 'ẙ' | 'ỳ' | 'ỵ' | 'ỷ' | 'ỹ' => "y",
+        'ź' | 'ż' | 'ž' | 'ẑ' | 'ẓ' | 'ẕ' => "z",
+        _ => return None,
+    })
+}
+
+/// Returns the end of the char starting at `i` and the marks following it.
+fn unit_end(input: &str, i: usize) -> usize {
+    let mut chars = input[i..].chars();
+    chars.next();
+    input.len() - chars.as_str().trim_start_matches(is_mark).len()
+}
+
+/// Returns `window` with the marks removed and the Latin letters folded,
+/// borrowed if that doesn't change it.
+fn fold_window(window: &str) -> Cow<'_, str> {
+    if window.chars().all(|c| !is_mark(c) && ascii_fold(c).is_none()) {
+        return Cow::Borrowed(window);
+    }
+    let mut folded = String::with_capacity(window.len());
+    for c in window.chars().filter(|&c| !is_mark(c)) {
+        match ascii_fold(c) {
+            Some(s) => folded.push_str(s),
+            None => folded.push(c),
+        }
+    }
+    Cow::Owned(folded)
+}
+
+/// Returns the windows of `size` chars of the input with the diacritics
+/// removed and the Latin letters folded to ASCII, e.g. for accent-insensitive
+/// search, together with the byte range of each window in the input.
+///
+/// The combining marks are removed and don't count as chars of a window,
+/// they are part of the range of the char they follow. Precomposed Latin
+/// letters like `'é'` fold to their base letter, and a few letters without a
+/// decomposition to their usual transliteration, e.g. `'ø'` to `"o"` and
+/// `'ß'` to `"ss"`, so a folded window may be longer than `size` chars. Other
+/// chars are kept as they are.
+///
+/// A window is borrowed from the input when folding doesn't change it,
+/// otherwise it's folded into a `String`.
+///
+/// This requires the `ascii-folding` feature.
+///
+/// # Panics
+///
+/// Panics if `size` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::str_windows_ascii_folded;
+///
+/// // the second "é" is an "e" and a combining acute accent
+/// let windows = str_windows_ascii_folded("café cafe\u{301}", 4)
+///     .filter(|(_, w)| w == "cafe")
+///     .map(|(range, _)| range)
+///     .collect::<Vec<_>>();
+/// assert_eq!(windows, [0..5, 6..12]);
+/// ```
+pub fn str_windows_ascii_folded(input: &str, size: usize) -> StrWindowsAsciiFolded<'_> {
+    assert!(size != 0, "window size must be non-zero");
+    // marks without a char before them are removed
+    let start = input.len() - input.trim_start_matches(is_mark).len();
+    let mut chars = 0;
+    let mut end = start;
+    let mut i = start;
+    while i != input.len() {
+        i = unit_end(input, i);
+        chars += 1;
+        if chars == size {
+            end = i;
+        }
+    }
+    StrWindowsAsciiFolded {
+        input,
+        start,
+        end,
+        len: (chars + 1).saturating_sub(size),
+    }
+}
+
+/// An iterator over the windows of `size` chars of a `&str` folded to ASCII,
+/// and their byte ranges.
+///
+/// This struct is created by [`str_windows_ascii_folded`].
+#[derive(Clone, Debug)]
+pub struct StrWindowsAsciiFolded<'a> {
+    input: &'a str,
+    /// Byte range of the next window.
+    start: usize,
+    end: usize,
+    /// Number of windows left to yield.
+    len: usize,
+}
+
+impl<'a> FusedIterator for StrWindowsAsciiFolded<'a> { }
+
+impl<'a> Iterator for StrWindowsAsciiFolded<'a> {
+    type Item = (Range<usize>, Cow<'a, str>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        let range = self.start..self.end;
+        self.start = unit_end(self.input, self.start);
+        self.end = unit_end(self.input, self.end);
+        let window = fold_window(&self.input[range.clone()]);
+        Some((range, window))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a> ExactSizeIterator for StrWindowsAsciiFolded<'a> { }
+
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+    use super::str_windows_ascii_folded;
+
+    #[test]
+    fn folded_test() {
+        let src = "Crème brûlée, 東京 Łódź";
+        let windows = str_windows_ascii_folded(src, 5).collect::<Vec<_>>();
+        assert_eq!(windows.len(), 17);
+        assert_eq!(windows[0], (0..6, "Creme".into()));
+        assert_eq!(windows[6], (7..14, "brule".into()));
+        let (ref range, ref window) = windows[14];
+        assert_eq!((&src[range.clone()], &**window), ("東京 Łó", "東京 Lo"));
+        assert_eq!(windows[16].1, " Lodz");
+        for (range, window) in windows {
+            assert_eq!(src[range.clone()].chars().count(), 5);
+            assert_eq!(&*window, &*super::fold_window(&src[range]));
+        }
+    }
+
+    #[test]
+    fn marks_test() {
+        // decomposed "Ångström", with a stray mark at the start
+        let src = "\u{301}A\u{30a}ngstro\u{308}m";
+        let windows = str_windows_ascii_folded(src, 3).collect::<Vec<_>>();
+        assert_eq!(windows[0], (2..7, "Ang".into()));
+        assert_eq!(windows[5], (9..14, "rom".into()));
+        assert_eq!(windows.len(), 6);
+        let windows = str_windows_ascii_folded("Straße ø", 8).collect::<Vec<_>>();
+        assert_eq!(windows, [(0..10, "Strasse o".into())]);
+    }
+
+    #[test]
+    fn borrowed_test() {
+        let src = "plain ascii, 你好世界";
+        for (range, window) in str_windows_ascii_folded(src, 4) {
+            assert_eq!(window, Cow::Borrowed(&src[range]));
+        }
+        assert_eq!(str_windows_ascii_folded("\u{300}\u{301}", 1).next(), None);
+        assert_eq!(str_windows_ascii_folded("", 1).len(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "window size must be non-zero")]
+    fn zero_size() {
+        str_windows_ascii_folded("头尾都是", 0);
+    }
+}
@@ -0,0 +1,205 @@
+//! Char boundary helpers, the primitives the windows iterators are built on.
+//!
+//! All of them take byte offsets and return byte offsets that are char
+//! boundaries of the given string. Offsets past the end of the string are
+//! allowed and never cause a panic.
+
+/// Returns `true` if `byte` starts a char (i.e. isn't a continuation byte).
+const fn is_char_start(byte: u8) -> bool {
+    byte & 0xC0 != 0x80
+}
+
+/// Returns the smallest char boundary of `s` strictly greater than `i`.
+///
+/// Returns `s.len()` when `i >= s.len()`. `i` doesn't have to be a char
+/// boundary itself.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::boundary::next_char_boundary;
+///
+/// let s = "a😀b";
+/// assert_eq!(next_char_boundary(s, 0), 1);
+/// assert_eq!(next_char_boundary(s, 1), 5);
+/// assert_eq!(next_char_boundary(s, 2), 5);
+/// assert_eq!(next_char_boundary(s, 6), 6);
+/// ```
+pub const fn next_char_boundary(s: &str, i: usize) -> usize {
+    let bytes = s.as_bytes();
+    if i >= bytes.len() {
+        return bytes.len();
+    }
+    let mut i = i + 1;
+    while i < bytes.len() && !is_char_start(bytes[i]) {
+        i += 1;
+    }
+    i
+}
+
+/// Returns the largest char boundary of `s` strictly less than `i`.
+///
+/// Returns 0 when `i` is 0, and `s.len()` when `i > s.len()`. `i` doesn't
+/// have to be a char boundary itself.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::boundary::prev_char_boundary;
+///
+/// let s = "a😀b";
+/// assert_eq!(prev_char_boundary(s, 6), 5);
+/// assert_eq!(prev_char_boundary(s, 5), 1);
+/// assert_eq!(prev_char_boundary(s, 3), 1);
+/// assert_eq!(prev_char_boundary(s, 0), 0);
+/// ```
+pub const fn prev_char_boundary(s: &str, i: usize) -> usize {
+    let bytes = s.as_bytes();
+    if i > bytes.len() {
+        return bytes.len();
+    }
+    if i == 0 {
+        return 0;
+    }
+    let mut i = i - 1;
+    while i > 0 && !is_char_start(bytes[i]) {
+        i -= 1;
+    }
+    i
+}
+
+/// Returns the byte offset of the char with index `n` of `s`, i.e. the
+/// boundary `n` chars from the start.
+///
+/// Returns `s.len()` when `s` has `n` chars or fewer.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::boundary::nth_char_boundary;
+///
+/// let s = "a😀b";
+/// assert_eq!(nth_char_boundary(s, 0), 0);
+/// assert_eq!(nth_char_boundary(s, 2), 5);
+/// assert_eq!(nth_char_boundary(s, 3), 6);
+/// assert_eq!(nth_char_boundary(s, 9), 6);
+/// ```
+pub const fn nth_char_boundary(s: &str, n: usize) -> usize {
+    let bytes = s.as_bytes();
+    let mut chars = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        if is_char_start(bytes[i]) {
+            if chars == n {
+                return i;
+            }
+            chars += 1;
+        }
+        i += 1;
+    }
+    bytes.len()
+}
+
+/// Returns the byte offset of the char `n` chars before the end of `s`, i.e.
+/// the boundary `n` chars from the end.
+///
+/// Returns 0 when `s` has `n` chars or fewer.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::boundary::nth_char_boundary_back;
+///
+/// let s = "a😀b";
+/// assert_eq!(nth_char_boundary_back(s, 0), 6);
+/// assert_eq!(nth_char_boundary_back(s, 1), 5);
+/// assert_eq!(nth_char_boundary_back(s, 2), 1);
+/// assert_eq!(nth_char_boundary_back(s, 9), 0);
+/// ```
+pub const fn nth_char_boundary_back(s: &str, n: usize) -> usize {
+    let bytes = s.as_bytes();
+    let mut chars = 0;
+    let mut i = bytes.len();
+    while chars < n && i > 0 {
+        i -= 1;
+        if is_char_start(bytes[i]) {
+            chars += 1;
+        }
+    }
+    i
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::{next_char_boundary, nth_char_boundary, nth_char_boundary_back, prev_char_boundary};
+
+    const SRC: &str = "s 😀你好é!";
+
+    fn boundaries(s: &str) -> Vec<usize> {
+        s.char_indices().map(|x| x.0).chain(Some(s.len())).collect()
+    }
+
+    #[test]
+    fn next_test() {
+        let boundaries = boundaries(SRC);
+        for i in 0..SRC.len() + 3 {
+            let expected = boundaries.iter()
+                .cloned()
+                .find(|&b| b > i)
+                .unwrap_or(SRC.len());
+            assert_eq!(next_char_boundary(SRC, i), expected, "{}", i);
+        }
+        assert_eq!(next_char_boundary("", 0), 0);
+        assert_eq!(next_char_boundary("😀", 0), 4);
+        assert_eq!(next_char_boundary("😀", 3), 4);
+    }
+
+    #[test]
+    fn prev_test() {
+        let boundaries = boundaries(SRC);
+        for i in 0..SRC.len() + 3 {
+            let expected = boundaries.iter()
+                .cloned()
+                .rev()
+                .find(|&b| b < i)
+                .unwrap_or(0);
+            assert_eq!(prev_char_boundary(SRC, i), expected, "{}", i);
+        }
+        assert_eq!(prev_char_boundary("", 0), 0);
+        assert_eq!(prev_char_boundary("", 1), 0);
+        assert_eq!(prev_char_boundary("😀", 4), 0);
+        assert_eq!(prev_char_boundary("😀", 2), 0);
+    }
+
+    #[test]
+    fn nth_test() {
+        let boundaries = boundaries(SRC);
+        for n in 0..12 {
+            let expected = boundaries.get(n).cloned().unwrap_or(SRC.len());
+            assert_eq!(nth_char_boundary(SRC, n), expected, "{}", n);
+        }
+        assert_eq!(nth_char_boundary("", 0), 0);
+        assert_eq!(nth_char_boundary("😀😁", 1), 4);
+    }
+
+    #[test]
+    fn nth_back_test() {
+        let boundaries = boundaries(SRC);
+        for n in 0..12 {
+            let expected = boundaries.iter().rev().nth(n).cloned().unwrap_or(0);
+            assert_eq!(nth_char_boundary_back(SRC, n), expected, "{}", n);
+        }
+        assert_eq!(nth_char_boundary_back("", 0), 0);
+        assert_eq!(nth_char_boundary_back("", 1), 0);
+        assert_eq!(nth_char_boundary_back("😀😁", 1), 4);
+    }
+
+    #[test]
+    fn const_test() {
+        const NEXT: usize = next_char_boundary("😀b", 0);
+        const PREV: usize = prev_char_boundary("😀b", 4);
+        const NTH: usize = nth_char_boundary("😀b", 1);
+        assert_eq!((NEXT, PREV, NTH), (4, 0, 4));
+    }
+}
@@ -0,0 +1,425 @@
+use std::error::Error;
+use std::fmt;
+use std::iter::FusedIterator;
+
+use boundary::{nth_char_boundary, nth_char_boundary_back};
+use StrWindows;
+
+impl<'a> StrWindows<'a> {
+    /// Returns a builder to combine the window options of this crate (size,
+    /// step, partial windows at either end, direction) in one iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use str_windows::StrWindows;
+    ///
+    /// let windows = StrWindows::builder("abcdefg")
+    ///     .size(4)
+    ///     .step(2)
+    ///     .trailing_partial(true)
+    ///     .build()
+    ///     .unwrap()
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(windows, ["abcd", "cdef", "efg", "g"]);
+    /// ```
+    pub fn builder(input: &'a str) -> WindowsBuilder<'a> {
+        WindowsBuilder {
+            input,
+            size: 1,
+            step: 1,
+            leading: false,
+            trailing: false,
+            reverse: false,
+        }
+    }
+}
+
+/// A builder for [`BuiltWindows`], created by [`StrWindows::builder`].
+///
+/// Conceptually, windows start every `step` chars at virtual positions of the
+/// input. Without partial windows, these are the positions where a whole
+/// window of `size` chars fits, as with [`str_windows`]. Leading partial
+/// windows move the first position `size - 1` chars before the input, and
+/// trailing partial windows move the last one to the last char, so windows
+/// sticking out of the input are cut to the part inside it. With the
+/// default options, it yields the windows of [`str_windows`], which is built
+/// on it.
+///
+/// Some combinations worth spelling out:
+///
+/// - With `step > size` and trailing partials, the last window is whatever
+///   is left at its position, which can be a single char.
+/// - With leading partials the positions are counted from `size - 1` chars
+///   before the input, so a `step` can skip the first chars entirely.
+/// - `reverse` yields the same windows in the opposite order, it doesn't
+///   change where the positions are.
+///
+/// ```
+/// use str_windows::StrWindows;
+///
+/// let builder = StrWindows::builder("abcdefg").size(2).step(3);
+/// let windows = builder.clone().trailing_partial(true).build().unwrap();
+/// assert_eq!(windows.collect::<Vec<_>>(), ["ab", "de", "g"]);
+/// let windows = builder.clone().leading_partial(true).build().unwrap();
+/// assert_eq!(windows.collect::<Vec<_>>(), ["a", "cd", "fg"]);
+/// let windows = builder.leading_partial(true).reverse(true).build().unwrap();
+/// assert_eq!(windows.collect::<Vec<_>>(), ["fg", "cd", "a"]);
+/// ```
+///
+/// [`str_windows`]: crate::str_windows
+#[derive(Clone, Debug)]
+pub struct WindowsBuilder<'a> {
+    input: &'a str,
+    size: usize,
+    step: usize,
+    leading: bool,
+    trailing: bool,
+    reverse: bool,
+}
+
+impl<'a> WindowsBuilder<'a> {
+    /// Sets the window size in chars, 1 by default.
+    pub fn size(mut self, size: usize) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the number of chars between the starts of consecutive windows, 1
+    /// by default.
+    pub fn step(mut self, step: usize) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Sets whether to yield the windows cut by the start of the input, off by
+    /// default. See [`str_windows_ramp`](crate::str_windows_ramp).
+    pub fn leading_partial(mut self, leading: bool) -> Self {
+        self.leading = leading;
+        self
+    }
+
+    /// Sets whether to yield the windows cut by the end of the input, off by
+    /// default. See [`str_windows_partial`](crate::str_windows_partial).
+    pub fn trailing_partial(mut self, trailing: bool) -> Self {
+        self.trailing = trailing;
+        self
+    }
+
+    /// Sets whether to yield the windows from the last one to the first one,
+    /// off by default.
+    pub fn reverse(mut self, reverse: bool) -> Self {
+        self.reverse = reverse;
+        self
+    }
+
+    /// Builds the iterator, or returns an error if the size or the step is 0.
+    ///
+    /// Windows can be longer than the input, so the number of windows
+    /// saturates at `usize::MAX`, e.g. with partial windows at both ends of
+    /// a size near `usize::MAX`.
+    pub fn build(self) -> Result<BuiltWindows<'a>, BuildError> {
+        if self.size == 0 {
+            return Err(BuildError::ZeroSize);
+        }
+        if self.step == 0 {
+            return Err(BuildError::ZeroStep);
+        }
+        if self.step == 1 && !self.leading && !self.trailing {
+            let reverse = self.reverse;
+            return Ok(BuiltWindows {
+                inner: Inner::Plain(self.plain(), reverse),
+            });
+        }
+        // the positions don't fit in `isize` for the largest sizes and steps
+        let chars = self.input.chars().count() as i128;
+        let size = self.size as i128;
+        let step = self.step as i128;
+        let first = if self.leading { 1 - size } else { 0 };
+        let last = if self.trailing { chars - 1 } else { chars - size };
+        // with partial windows an empty input would only yield empty windows
+        let len = if last < first || chars == 0 {
+            0
+        } else {
+            ((last - first) / step + 1).min(usize::MAX as i128) as usize
+        };
+        let mut windows = Stepped {
+            input: self.input,
+            size,
+            step,
+            chars,
+            reverse: self.reverse,
+            pos: 0,
+            start: (0, 0),
+            end: (0, 0),
+            len,
+        };
+        if len != 0 {
+            let pos = if self.reverse {
+                first + (last - first) / step * step
+            } else {
+                first
+            };
+            windows.seek(pos);
+        }
+        Ok(BuiltWindows {
+            inner: Inner::Stepped(windows),
+        })
+    }
+
+    /// Returns the windows of the size of the builder with the other options
+    /// at their defaults, the iterator of [`str_windows`].
+    ///
+    /// Unlike [`build`](WindowsBuilder::build), this accepts a size of 0, for
+    /// which [`str_windows`] yields `""` forever.
+    ///
+    /// [`str_windows`]: crate::str_windows
+    pub(crate) fn plain(self) -> StrWindows<'a> {
+        StrWindows::new(self.input, self.size)
+    }
+}
+
+/// The error returned by [`WindowsBuilder::build`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BuildError {
+    /// The window size is 0.
+    ZeroSize,
+    /// The step is 0.
+    ZeroStep,
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            BuildError::ZeroSize => f.write_str("window size must be non-zero"),
+            BuildError::ZeroStep => f.write_str("step must be non-zero"),
+        }
+    }
+}
+
+impl Error for BuildError { }
+
+/// An iterator over windows of a `&str` configured by a [`WindowsBuilder`].
+#[derive(Clone, Debug)]
+pub struct BuiltWindows<'a> {
+    inner: Inner<'a>,
+}
+
+#[derive(Clone, Debug)]
+enum Inner<'a> {
+    /// The windows of the default options, and whether they're reversed.
+    Plain(StrWindows<'a>, bool),
+    Stepped(Stepped<'a>),
+}
+
+/// The windows of the builder options other than the defaults.
+#[derive(Clone, Debug)]
+struct Stepped<'a> {
+    input: &'a str,
+    size: i128,
+    step: i128,
+    chars: i128,
+    reverse: bool,
+    /// Virtual start of the next window in chars, negative for leading
+    /// partial windows.
+    pos: i128,
+    /// Char index and byte offset of the start and the end of the next
+    /// window.
+    start: (i128, usize),
+    end: (i128, usize),
+    /// Number of windows left to yield.
+    len: usize,
+}
+
+impl<'a> Stepped<'a> {
+    /// Moves the next window to the virtual start `pos`.
+    fn seek(&mut self, pos: i128) {
+        self.pos = pos;
+        let start = pos.clamp(0, self.chars);
+        let end = (pos + self.size).clamp(0, self.chars);
+        self.start = move_to(self.input, self.start, start);
+        self.end = move_to(self.input, self.end, end);
+    }
+}
+
+/// Moves `from`, a char index and its byte offset, to the char index `to`.
+fn move_to(input: &str, from: (i128, usize), to: i128) -> (i128, usize) {
+    let (index, offset) = from;
+    let offset = if to >= index {
+        offset + nth_char_boundary(&input[offset..], (to - index) as usize)
+    } else {
+        nth_char_boundary_back(&input[..offset], (index - to) as usize)
+    };
+    (to, offset)
+}
+
+impl<'a> FusedIterator for BuiltWindows<'a> { }
+
+impl<'a> Iterator for BuiltWindows<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.inner {
+            Inner::Plain(ref mut windows, false) => windows.next(),
+            Inner::Plain(ref mut windows, true) => windows.next_back(),
+            Inner::Stepped(ref mut windows) => windows.next(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.inner {
+            Inner::Plain(ref windows, _) => windows.size_hint(),
+            Inner::Stepped(ref windows) => (windows.len, Some(windows.len)),
+        }
+    }
+}
+
+impl<'a> Iterator for Stepped<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        let window = &self.input[self.start.1..self.end.1];
+        if self.len != 0 {
+            let pos = if self.reverse {
+                self.pos - self.step
+            } else {
+                self.pos + self.step
+            };
+            self.seek(pos);
+        }
+        Some(window)
+    }
+}
+
+impl<'a> ExactSizeIterator for BuiltWindows<'a> { }
+
+
+#[cfg(test)]
+mod tests {
+    use super::BuildError;
+    use {str_windows, str_windows_partial, str_windows_ramp, str_windows_ramp_partial};
+    use {str_windows_step, StrWindows};
+
+    const SRCS: &[&str] = &["hello, 你好, hi", "头尾都是", "s 😀😁", "😀", ""];
+
+    #[test]
+    fn defaults_test() {
+        for &src in SRCS {
+            for size in 1..=6 {
+                let built = StrWindows::builder(src).size(size).build().unwrap();
+                assert_eq!(built.len(), str_windows(src, size).count());
+                assert_eq!(built.collect::<Vec<_>>(), str_windows(src, size).collect::<Vec<_>>());
+            }
+        }
+    }
+
+    #[test]
+    fn matches_constructors_test() {
+        for &src in SRCS {
+            for size in 1..=6 {
+                let builder = StrWindows::builder(src).size(size);
+                let collect = |leading, trailing, reverse| {
+                    builder.clone()
+                        .leading_partial(leading)
+                        .trailing_partial(trailing)
+                        .reverse(reverse)
+                        .build()
+                        .unwrap()
+                        .collect::<Vec<_>>()
+                };
+                assert_eq!(collect(false, true, false), str_windows_partial(src, size).collect::<Vec<_>>());
+                assert_eq!(collect(true, false, false), str_windows_ramp(src, size).collect::<Vec<_>>());
+                let mut both = str_windows_ramp_partial(src, size).collect::<Vec<_>>();
+                assert_eq!(collect(true, true, false), both);
+                both.reverse();
+                assert_eq!(collect(true, true, true), both);
+                for step in 1..=4 {
+                    let stepped = builder.clone().step(step).build().unwrap();
+                    let expected = str_windows_step(src, size, step).collect::<Vec<_>>();
+                    assert_eq!(stepped.len(), expected.len());
+                    assert_eq!(stepped.collect::<Vec<_>>(), expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn step_partial_test() {
+        for &src in SRCS {
+            for size in 1..=4 {
+                for step in 1..=6 {
+                    let windows = StrWindows::builder(src)
+                        .size(size)
+                        .step(step)
+                        .leading_partial(true)
+                        .trailing_partial(true);
+                    let forward = windows.clone().build().unwrap();
+                    let expected = str_windows_ramp_partial(src, size)
+                        .step_by(step)
+                        .collect::<Vec<_>>();
+                    assert_eq!(forward.len(), expected.len());
+                    assert_eq!(forward.collect::<Vec<_>>(), expected);
+                    let mut backward = windows.reverse(true).build().unwrap().collect::<Vec<_>>();
+                    backward.reverse();
+                    assert_eq!(backward, expected);
+                }
+            }
+        }
+        let windows = StrWindows::builder("头尾都是!")
+            .size(2)
+            .step(3)
+            .trailing_partial(true)
+            .build()
+            .unwrap();
+        assert_eq!(windows.collect::<Vec<_>>(), ["头尾", "是!"]);
+    }
+
+    #[test]
+    fn large_test() {
+        let windows = StrWindows::builder("头尾😀").size(usize::MAX).build().unwrap();
+        assert_eq!(windows.len(), 0);
+        let windows = StrWindows::builder("头尾😀").size(usize::MAX).trailing_partial(true);
+        assert_eq!(windows.build().unwrap().collect::<Vec<_>>(), ["头尾😀", "尾😀", "😀"]);
+        let windows = StrWindows::builder("头尾😀").size(usize::MAX).leading_partial(true);
+        assert_eq!(windows.reverse(true).build().unwrap().collect::<Vec<_>>(), ["头尾😀", "头尾", "头"]);
+        let windows = StrWindows::builder("头尾😀").size(2).step(usize::MAX).trailing_partial(true);
+        assert_eq!(windows.clone().build().unwrap().collect::<Vec<_>>(), ["头尾"]);
+        assert_eq!(windows.reverse(true).build().unwrap().collect::<Vec<_>>(), ["头尾"]);
+        let windows = StrWindows::builder("头尾😀")
+            .size(usize::MAX)
+            .step(usize::MAX - 1)
+            .leading_partial(true)
+            .trailing_partial(true)
+            .reverse(true)
+            .build()
+            .unwrap();
+        assert_eq!(windows.len(), 2);
+        assert_eq!(windows.collect::<Vec<_>>(), ["头尾😀", "头"]);
+        let windows = StrWindows::builder("头尾😀").size(usize::MAX).leading_partial(true);
+        let windows = windows.trailing_partial(true).build().unwrap();
+        assert_eq!(windows.len(), usize::MAX);
+        assert_eq!(windows.take(4).collect::<Vec<_>>(), ["头", "头尾", "头尾😀", "头尾😀"]);
+    }
+
+    #[test]
+    fn reverse_test() {
+        for &src in SRCS {
+            for size in 1..=6 {
+                let built = StrWindows::builder(src).size(size).reverse(true).build().unwrap();
+                assert_eq!(built.len(), str_windows(src, size).count());
+                assert!(built.eq(str_windows(src, size).rev()));
+            }
+        }
+    }
+
+    #[test]
+    fn errors_test() {
+        assert_eq!(StrWindows::builder("头尾").size(0).build().err(), Some(BuildError::ZeroSize));
+        assert_eq!(StrWindows::builder("头尾").step(0).build().err(), Some(BuildError::ZeroStep));
+        assert_eq!(BuildError::ZeroStep.to_string(), "step must be non-zero");
+    }
+}
@@ -0,0 +1,248 @@
+use std::error::Error;
+use std::fmt;
+use std::iter::FusedIterator;
+
+use boundary::{next_char_boundary, prev_char_boundary};
+
+/// Returns the longest prefix of `s` of at most `max_bytes` bytes that
+/// doesn't split a char.
+fn bytes_prefix(s: &str, max_bytes: usize) -> &str {
+    if max_bytes >= s.len() {
+        return s;
+    }
+    // the largest boundary not greater than `max_bytes`
+    &s[..prev_char_boundary(s, max_bytes + 1)]
+}
+
+/// Returns windows bounded by a byte budget instead of a char count: at every
+/// char of the input, the longest substring starting there of at most
+/// `max_bytes` bytes.
+///
+/// Adding the next char to a window would exceed the budget, except for the
+/// windows reaching the end of the input. A char of more than `max_bytes`
+/// bytes yields an empty window at its position, so there is always one
+/// window per char and the windows can be zipped with `char_indices`.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::str_windows_bytes;
+///
+/// let windows = str_windows_bytes("ab😀c", 5).collect::<Vec<_>>();
+/// assert_eq!(windows, ["ab", "b😀", "😀c", "c"]);
+///
+/// let windows = str_windows_bytes("a😀", 3).collect::<Vec<_>>();
+/// assert_eq!(windows, ["a", ""]);
+/// ```
+pub fn str_windows_bytes(input: &str, max_bytes: usize) -> StrWindowsBytes<'_> {
+    StrWindowsBytes {
+        inner: input,
+        max_bytes,
+        len: input.chars().count(),
+    }
+}
+
+/// An iterator over the longest windows of at most `max_bytes` bytes at every
+/// char of a `&str`.
+///
+/// This struct is created by [`str_windows_bytes`].
+#[derive(Clone, Debug)]
+pub struct StrWindowsBytes<'a> {
+    /// The input from the start of the next window.
+    inner: &'a str,
+    max_bytes: usize,
+    /// Number of windows left to yield.
+    len: usize,
+}
+
+impl<'a> FusedIterator for StrWindowsBytes<'a> { }
+
+impl<'a> Iterator for StrWindowsBytes<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.inner.is_empty() {
+            return None;
+        }
+        let window = bytes_prefix(self.inner, self.max_bytes);
+        self.inner = &self.inner[next_char_boundary(self.inner, 0)..];
+        self.len -= 1;
+        Some(window)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a> ExactSizeIterator for StrWindowsBytes<'a> { }
+
+/// Splits the input into consecutive substrings of at most `max_bytes`
+/// bytes, each as long as possible without splitting a char.
+///
+/// Contrary to `as_bytes().chunks(max_bytes)`, the chunks are `&str`s and
+/// concatenate back to `input`. Only the last chunk can be shorter than
+/// needed to fit the next char.
+///
+/// Returns an error if a char of the input is longer than `max_bytes`, since
+/// no chunk could hold it.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::str_chunks_bytes;
+///
+/// let chunks = str_chunks_bytes("ab😀c", 5).unwrap().collect::<Vec<_>>();
+/// assert_eq!(chunks, ["ab", "😀c"]);
+///
+/// assert!(str_chunks_bytes("ab😀c", 2).is_err());
+/// ```
+pub fn str_chunks_bytes(
+    input: &str,
+    max_bytes: usize,
+) -> Result<StrChunksBytes<'_>, CharTooLongError> {
+    if let Some((offset, c)) = input.char_indices().find(|x| x.1.len_utf8() > max_bytes) {
+        return Err(CharTooLongError { c, offset, max_bytes });
+    }
+    Ok(StrChunksBytes {
+        inner: input,
+        max_bytes,
+    })
+}
+
+/// The error returned by [`str_chunks_bytes`] when a char doesn't fit in the
+/// byte budget.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CharTooLongError {
+    c: char,
+    offset: usize,
+    max_bytes: usize,
+}
+
+impl CharTooLongError {
+    /// Returns the first char longer than the budget.
+    pub fn char(&self) -> char {
+        self.c
+    }
+
+    /// Returns the byte offset of the char in the input.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl fmt::Display for CharTooLongError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "char {:?} at byte {} is longer than {} bytes",
+            self.c, self.offset, self.max_bytes,
+        )
+    }
+}
+
+impl Error for CharTooLongError { }
+
+/// An iterator over consecutive chunks of at most `max_bytes` bytes of a
+/// `&str`.
+///
+/// This struct is created by [`str_chunks_bytes`].
+#[derive(Clone, Debug)]
+pub struct StrChunksBytes<'a> {
+    inner: &'a str,
+    max_bytes: usize,
+}
+
+impl<'a> FusedIterator for StrChunksBytes<'a> { }
+
+impl<'a> Iterator for StrChunksBytes<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.inner.is_empty() {
+            return None;
+        }
+        let chunk = bytes_prefix(self.inner, self.max_bytes);
+        self.inner = &self.inner[chunk.len()..];
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // a chunk holds at least one byte and at most `max_bytes` bytes
+        let len = self.inner.len();
+        (len.div_ceil(self.max_bytes.max(1)), Some(len))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::{str_chunks_bytes, str_windows_bytes};
+
+    #[test]
+    fn windows_bytes_test() {
+        let windows = str_windows_bytes("😀😁!", 4).collect::<Vec<_>>();
+        assert_eq!(windows, ["😀", "😁", "!"]);
+        let windows = str_windows_bytes("😀😁!", 7).collect::<Vec<_>>();
+        assert_eq!(windows, ["😀", "😁!", "!"]);
+        let windows = str_windows_bytes("😀😁!", 8).collect::<Vec<_>>();
+        assert_eq!(windows, ["😀😁", "😁!", "!"]);
+        let windows = str_windows_bytes("a😀b", 2).collect::<Vec<_>>();
+        assert_eq!(windows, ["a", "", "b"]);
+        assert_eq!(str_windows_bytes("头尾", 0).collect::<Vec<_>>(), ["", ""]);
+        assert_eq!(str_windows_bytes("", 4).next(), None);
+    }
+
+    #[test]
+    fn longest_test() {
+        for &src in &["hello, 你好, hi", "头尾都是", "s 😀😁é", "😀a😁bc😂"] {
+            for max_bytes in 0..=12 {
+                let iter = str_windows_bytes(src, max_bytes);
+                assert_eq!(iter.len(), src.chars().count());
+                for ((i, _), window) in src.char_indices().zip(iter) {
+                    let rest = &src[i..];
+                    assert!(rest.starts_with(window));
+                    assert!(window.len() <= max_bytes);
+                    if let Some(c) = rest[window.len()..].chars().next() {
+                        assert!(window.len() + c.len_utf8() > max_bytes);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn chunks_bytes_test() {
+        let chunks = str_chunks_bytes("😀😁!", 4).unwrap().collect::<Vec<_>>();
+        assert_eq!(chunks, ["😀", "😁", "!"]);
+        let chunks = str_chunks_bytes("😀😁!", 7).unwrap().collect::<Vec<_>>();
+        assert_eq!(chunks, ["😀", "😁!"]);
+        let chunks = str_chunks_bytes("a😀😁!", 9).unwrap().collect::<Vec<_>>();
+        assert_eq!(chunks, ["a😀😁", "!"]);
+        assert_eq!(str_chunks_bytes("", 0).unwrap().next(), None);
+
+        let err = str_chunks_bytes("a头😀", 3).unwrap_err();
+        assert_eq!((err.char(), err.offset()), ('😀', 4));
+        assert_eq!(err.to_string(), "char '😀' at byte 4 is longer than 3 bytes");
+        assert!(str_chunks_bytes("a", 0).is_err());
+    }
+
+    #[test]
+    fn chunks_bytes_concat_test() {
+        for &src in &["hello, 你好, hi", "头尾都是", "s 😀😁é", "😀a😁bc😂"] {
+            for max_bytes in 4..=12 {
+                let iter = str_chunks_bytes(src, max_bytes).unwrap();
+                let (lower, upper) = iter.size_hint();
+                let chunks = iter.collect::<Vec<_>>();
+                assert!(lower <= chunks.len() && chunks.len() <= upper.unwrap());
+                assert_eq!(chunks.concat(), src);
+                assert_eq!(chunks.iter().map(|chunk| chunk.len()).sum::<usize>(), src.len());
+                for pair in chunks.windows(2) {
+                    assert!(pair[0].len() <= max_bytes);
+                    let c = pair[1].chars().next().unwrap();
+                    assert!(pair[0].len() + c.len_utf8() > max_bytes);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,413 @@
+use std::iter::FusedIterator;
+
+use boundary::{nth_char_boundary, nth_char_boundary_back};
+
+/// Returns consecutive substrings of `size` chars, the last one possibly
+/// shorter, similar to `slice::chunks`.
+///
+/// The chunks don't overlap and concatenate back to `input`.
+///
+/// # Panics
+///
+/// Panics if `size` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::str_chunks;
+///
+/// let mut iter = str_chunks("s 😀😁!", 2);
+/// assert_eq!(iter.next(), Some("s "));
+/// assert_eq!(iter.next(), Some("😀😁"));
+/// assert_eq!(iter.next(), Some("!"));
+/// assert!(iter.next().is_none());
+/// ```
+pub fn str_chunks(input: &str, size: usize) -> StrChunks<'_> {
+    assert!(size != 0, "chunk size must be non-zero");
+    let chars = input.chars().count();
+    StrChunks {
+        inner: input,
+        size,
+        len: chars.div_ceil(size),
+    }
+}
+
+/// An iterator over non-overlapping chunks of `size` chars of a `&str`.
+///
+/// This struct is created by [`str_chunks`].
+#[derive(Clone, Debug)]
+pub struct StrChunks<'a> {
+    inner: &'a str,
+    size: usize,
+    len: usize,
+}
+
+impl<'a> FusedIterator for StrChunks<'a> { }
+
+impl<'a> Iterator for StrChunks<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.inner.is_empty() {
+            return None;
+        }
+        let (chunk, rest) = self.inner.split_at(nth_char_boundary(self.inner, self.size));
+        self.inner = rest;
+        self.len -= 1;
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a> ExactSizeIterator for StrChunks<'a> { }
+
+/// Returns consecutive substrings of exactly `size` chars, similar to
+/// `slice::chunks_exact`.
+///
+/// The trailing chars that don't fill a chunk are not yielded, but are
+/// available from [`StrChunksExact::remainder`].
+///
+/// # Panics
+///
+/// Panics if `size` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::str_chunks_exact;
+///
+/// let mut iter = str_chunks_exact("s 😀😁!", 2);
+/// assert_eq!(iter.next(), Some("s "));
+/// assert_eq!(iter.next(), Some("😀😁"));
+/// assert!(iter.next().is_none());
+/// assert_eq!(iter.remainder(), "!");
+/// ```
+pub fn str_chunks_exact(input: &str, size: usize) -> StrChunksExact<'_> {
+    assert!(size != 0, "chunk size must be non-zero");
+    let len = input.chars().count() / size;
+    let (inner, rem) = input.split_at(nth_char_boundary(input, len * size));
+    StrChunksExact {
+        inner,
+        rem,
+        size,
+        len,
+    }
+}
+
+/// An iterator over non-overlapping chunks of exactly `size` chars of a
+/// `&str`.
+///
+/// This struct is created by [`str_chunks_exact`].
+#[derive(Clone, Debug)]
+pub struct StrChunksExact<'a> {
+    inner: &'a str,
+    rem: &'a str,
+    size: usize,
+    len: usize,
+}
+
+impl<'a> StrChunksExact<'a> {
+    /// Returns the trailing chars that don't fill a whole chunk, which is
+    /// empty when the char count of the input is a multiple of `size`.
+    pub fn remainder(&self) -> &'a str {
+        self.rem
+    }
+}
+
+impl<'a> FusedIterator for StrChunksExact<'a> { }
+
+impl<'a> Iterator for StrChunksExact<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.inner.is_empty() {
+            return None;
+        }
+        let (chunk, rest) = self.inner.split_at(nth_char_boundary(self.inner, self.size));
+        self.inner = rest;
+        self.len -= 1;
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a> ExactSizeIterator for StrChunksExact<'a> { }
+
+/// Returns consecutive substrings of `size` chars starting from the end, the
+/// last one (which is the start of `input`) possibly shorter, similar to
+/// `slice::rchunks`.
+///
+/// The chunks concatenate back to `input` when taken in reverse order.
+///
+/// # Panics
+///
+/// Panics if `size` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::str_rchunks;
+///
+/// let mut iter = str_rchunks("1234567", 3);
+/// assert_eq!(iter.next(), Some("567"));
+/// assert_eq!(iter.next(), Some("234"));
+/// assert_eq!(iter.next(), Some("1"));
+/// assert!(iter.next().is_none());
+/// ```
+pub fn str_rchunks(input: &str, size: usize) -> StrRChunks<'_> {
+    assert!(size != 0, "chunk size must be non-zero");
+    let chars = input.chars().count();
+    StrRChunks {
+        inner: input,
+        size,
+        len: chars.div_ceil(size),
+    }
+}
+
+/// An iterator over non-overlapping chunks of `size` chars of a `&str`,
+/// starting from the end.
+///
+/// This struct is created by [`str_rchunks`].
+#[derive(Clone, Debug)]
+pub struct StrRChunks<'a> {
+    inner: &'a str,
+    size: usize,
+    len: usize,
+}
+
+impl<'a> FusedIterator for StrRChunks<'a> { }
+
+impl<'a> Iterator for StrRChunks<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.inner.is_empty() {
+            return None;
+        }
+        let (rest, chunk) = self.inner.split_at(nth_char_boundary_back(self.inner, self.size));
+        self.inner = rest;
+        self.len -= 1;
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a> ExactSizeIterator for StrRChunks<'a> { }
+
+/// Returns consecutive substrings of exactly `size` chars starting from the
+/// end, similar to `slice::rchunks_exact`.
+///
+/// The leading chars that don't fill a chunk are not yielded, but are
+/// available from [`StrRChunksExact::remainder`].
+///
+/// # Panics
+///
+/// Panics if `size` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::str_rchunks_exact;
+///
+/// let mut iter = str_rchunks_exact("1234567", 3);
+/// assert_eq!(iter.next(), Some("567"));
+/// assert_eq!(iter.next(), Some("234"));
+/// assert!(iter.next().is_none());
+/// assert_eq!(iter.remainder(), "1");
+/// ```
+pub fn str_rchunks_exact(input: &str, size: usize) -> StrRChunksExact<'_> {
+    assert!(size != 0, "chunk size must be non-zero");
+    let len = input.chars().count() / size;
+    let (rem, inner) = input.split_at(nth_char_boundary_back(input, len * size));
+    StrRChunksExact {
+        inner,
+        rem,
+        size,
+        len,
+    }
+}
+
+/// An iterator over non-overlapping chunks of exactly `size` chars of a
+/// `&str`, starting from the end.
+///
+/// This struct is created by [`str_rchunks_exact`].
+#[derive(Clone, Debug)]
+pub struct StrRChunksExact<'a> {
+    inner: &'a str,
+    rem: &'a str,
+    size: usize,
+    len: usize,
+}
+
+impl<'a> StrRChunksExact<'a> {
+    /// Returns the leading chars that don't fill a whole chunk, which is
+    /// empty when the char count of the input is a multiple of `size`.
+    pub fn remainder(&self) -> &'a str {
+        self.rem
+    }
+}
+
+impl<'a> FusedIterator for StrRChunksExact<'a> { }
+
+impl<'a> Iterator for StrRChunksExact<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.inner.is_empty() {
+            return None;
+        }
+        let (rest, chunk) = self.inner.split_at(nth_char_boundary_back(self.inner, self.size));
+        self.inner = rest;
+        self.len -= 1;
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a> ExactSizeIterator for StrRChunksExact<'a> { }
+
+
+#[cfg(test)]
+mod tests {
+    use super::{str_chunks, str_chunks_exact, str_rchunks, str_rchunks_exact};
+
+    #[test]
+    fn chunks_test() {
+        assert_eq!(str_chunks("头尾都是", 2).collect::<Vec<_>>(), ["头尾", "都是"]);
+        assert_eq!(str_chunks("头尾都是!", 2).collect::<Vec<_>>(), ["头尾", "都是", "!"]);
+        assert_eq!(str_chunks("😀😁😂", 2).collect::<Vec<_>>(), ["😀😁", "😂"]);
+        assert_eq!(str_chunks("😀😁😂", 1).collect::<Vec<_>>(), ["😀", "😁", "😂"]);
+        assert_eq!(str_chunks("😀😁😂", 5).collect::<Vec<_>>(), ["😀😁😂"]);
+        assert_eq!(str_chunks("", 3).next(), None);
+    }
+
+    #[test]
+    fn concat_test() {
+        for &src in &["hello, 你好, hi", "头尾都是", "s 😀😁", ""] {
+            for size in 1..=6 {
+                let iter = str_chunks(src, size);
+                let expected_len = src.chars().count().div_ceil(size);
+                assert_eq!(iter.size_hint(), (expected_len, Some(expected_len)));
+                let chunks = iter.collect::<Vec<_>>();
+                assert_eq!(chunks.len(), expected_len);
+                assert_eq!(chunks.concat(), src);
+                for chunk in &chunks[..chunks.len().saturating_sub(1)] {
+                    assert_eq!(chunk.chars().count(), size);
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk size must be non-zero")]
+    fn zero_size() {
+        str_chunks("头尾都是", 0);
+    }
+
+    #[test]
+    fn chunks_exact_test() {
+        let mut iter = str_chunks_exact("头尾都是", 2);
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.next(), Some("头尾"));
+        assert_eq!(iter.len(), 1);
+        assert_eq!(iter.next(), Some("都是"));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.remainder(), "");
+
+        let mut iter = str_chunks_exact("头尾都😀", 3);
+        assert_eq!(iter.remainder(), "😀");
+        assert_eq!(iter.next(), Some("头尾都"));
+        assert_eq!(iter.next(), None);
+
+        let mut iter = str_chunks_exact("头尾都是", 5);
+        assert_eq!(iter.len(), 0);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.remainder(), "头尾都是");
+
+        for &src in &["hello, 你好, hi", "头尾都是", "s 😀😁", ""] {
+            for size in 1..=6 {
+                let iter = str_chunks_exact(src, size);
+                let expected = str_chunks(src, size)
+                    .filter(|chunk| chunk.chars().count() == size)
+                    .collect::<Vec<_>>();
+                assert_eq!(iter.len(), expected.len());
+                let rem = iter.remainder();
+                assert_eq!(iter.collect::<Vec<_>>(), expected);
+                assert_eq!(expected.concat() + rem, src);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk size must be non-zero")]
+    fn exact_zero_size() {
+        str_chunks_exact("头尾都是", 0);
+    }
+
+    #[test]
+    fn rchunks_test() {
+        assert_eq!(str_rchunks("1234567", 3).collect::<Vec<_>>(), ["567", "234", "1"]);
+        assert_eq!(str_rchunks("一二三四五六七", 3).collect::<Vec<_>>(), ["五六七", "二三四", "一"]);
+        assert_eq!(str_rchunks("😀😁😂", 2).collect::<Vec<_>>(), ["😁😂", "😀"]);
+        assert_eq!(str_rchunks("头尾都是", 2).collect::<Vec<_>>(), ["都是", "头尾"]);
+        assert_eq!(str_rchunks("", 2).next(), None);
+
+        for &src in &["hello, 你好, hi", "头尾都是", "s 😀😁", ""] {
+            for size in 1..=6 {
+                let iter = str_rchunks(src, size);
+                let expected_len = src.chars().count().div_ceil(size);
+                assert_eq!(iter.len(), expected_len);
+                let mut chunks = iter.collect::<Vec<_>>();
+                assert_eq!(chunks.len(), expected_len);
+                chunks.reverse();
+                assert_eq!(chunks.concat(), src);
+            }
+        }
+    }
+
+    #[test]
+    fn rchunks_exact_test() {
+        let mut iter = str_rchunks_exact("a你好世界", 2);
+        assert_eq!(iter.remainder(), "a");
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.next(), Some("世界"));
+        assert_eq!(iter.next(), Some("你好"));
+        assert_eq!(iter.next(), None);
+
+        let mut iter = str_rchunks_exact("😀你好世界", 3);
+        assert_eq!(iter.remainder(), "😀你");
+        assert_eq!(iter.next(), Some("好世界"));
+        assert_eq!(iter.next(), None);
+
+        let iter = str_rchunks_exact("头尾都是", 2);
+        assert_eq!(iter.remainder(), "");
+        assert_eq!(str_rchunks_exact("头尾都是", 5).remainder(), "头尾都是");
+
+        for &src in &["hello, 你好, hi", "头尾都是", "s 😀😁", ""] {
+            for size in 1..=6 {
+                let iter = str_rchunks_exact(src, size);
+                let expected = str_rchunks(src, size)
+                    .filter(|chunk| chunk.chars().count() == size)
+                    .collect::<Vec<_>>();
+                assert_eq!(iter.len(), expected.len());
+                let rem = iter.remainder();
+                let mut chunks = iter.collect::<Vec<_>>();
+                assert_eq!(chunks, expected);
+                chunks.reverse();
+                assert_eq!(rem.to_string() + &chunks.concat(), src);
+            }
+        }
+    }
+}
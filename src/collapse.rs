@@ -0,0 +1,242 @@
+use std::borrow::Cow;
+use std::iter::FusedIterator;
+
+/// Returns the end of the char or whitespace run starting at `i`.
+fn unit_end(input: &str, i: usize) -> usize {
+    let mut chars = input[i..].chars();
+    match chars.next() {
+        Some(c) if c.is_whitespace() => input.len() - chars.as_str().trim_start().len(),
+        Some(c) => i + c.len_utf8(),
+        None => i,
+    }
+}
+
+/// Returns `window` with every whitespace run replaced by a single `' '`,
+/// borrowed if that doesn't change it.
+fn collapse(window: &str) -> Cow<'_, str> {
+    let mut prev_ws = false;
+    let unchanged = window.chars().all(|c| {
+        let ws = c.is_whitespace();
+        let unchanged = !ws || (c == ' ' && !prev_ws);
+        prev_ws = ws;
+        unchanged
+    });
+    if unchanged {
+        return Cow::Borrowed(window);
+    }
+    let mut collapsed = String::with_capacity(window.len());
+    prev_ws = false;
+    for c in window.chars() {
+        let ws = c.is_whitespace();
+        if !ws {
+            collapsed.push(c);
+        } else if !prev_ws {
+            collapsed.push(' ');
+        }
+        prev_ws = ws;
+    }
+    Cow::Owned(collapsed)
+}
+
+/// Returns the windows of `size` chars of the input with every run of
+/// whitespace collapsed to a single `' '`, without normalizing a copy of the
+/// whole input.
+///
+/// A whitespace run counts as one char of a window. A window is borrowed
+/// from the input when its whitespace runs are all a single `' '` already,
+/// otherwise it's collapsed into a `String`.
+/// [`str_windows_collapse_ws_indices`] also gives where each window starts
+/// in the input.
+///
+/// # Panics
+///
+/// Panics if `size` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use std::borrow::Cow;
+/// use str_windows::str_windows_collapse_ws;
+///
+/// let mut iter = str_windows_collapse_ws("a b\t\nc", 3);
+/// assert_eq!(iter.next(), Some(Cow::Borrowed("a b")));
+/// assert_eq!(iter.next(), Some(Cow::Owned(" b ".to_string())));
+/// assert_eq!(iter.next(), Some(Cow::Owned("b c".to_string())));
+/// assert!(iter.next().is_none());
+/// ```
+pub fn str_windows_collapse_ws(input: &str, size: usize) -> StrWindowsCollapseWs<'_> {
+    assert!(size != 0, "window size must be non-zero");
+    let mut units = 0;
+    let mut end = 0;
+    let mut i = 0;
+    while i != input.len() {
+        i = unit_end(input, i);
+        units += 1;
+        if units == size {
+            end = i;
+        }
+    }
+    StrWindowsCollapseWs {
+        input,
+        start: 0,
+        end,
+        len: (units + 1).saturating_sub(size),
+    }
+}
+
+/// An iterator over the windows of `size` chars of a `&str` with collapsed
+/// whitespace.
+///
+/// This struct is created by [`str_windows_collapse_ws`].
+#[derive(Clone, Debug)]
+pub struct StrWindowsCollapseWs<'a> {
+    input: &'a str,
+    /// Byte range of the next window.
+    start: usize,
+    end: usize,
+    /// Number of windows left to yield.
+    len: usize,
+}
+
+impl<'a> StrWindowsCollapseWs<'a> {
+    /// Returns the byte range of the next window, and moves past it.
+    fn next_range(&mut self) -> Option<(usize, usize)> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        let range = (self.start, self.end);
+        self.start = unit_end(self.input, self.start);
+        self.end = unit_end(self.input, self.end);
+        Some(range)
+    }
+}
+
+impl<'a> FusedIterator for StrWindowsCollapseWs<'a> { }
+
+impl<'a> Iterator for StrWindowsCollapseWs<'a> {
+    type Item = Cow<'a, str>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (start, end) = self.next_range()?;
+        Some(collapse(&self.input[start..end]))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a> ExactSizeIterator for StrWindowsCollapseWs<'a> { }
+
+/// Returns the windows of [`str_windows_collapse_ws`] together with the byte
+/// offset in the input of the first char of each window.
+///
+/// # Panics
+///
+/// Panics if `size` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::str_windows_collapse_ws_indices;
+///
+/// let windows = str_windows_collapse_ws_indices("头  尾都", 2).collect::<Vec<_>>();
+/// assert_eq!(windows, [(0, "头 ".into()), (3, " 尾".into()), (5, "尾都".into())]);
+/// ```
+pub fn str_windows_collapse_ws_indices(
+    input: &str,
+    size: usize,
+) -> StrWindowsCollapseWsIndices<'_> {
+    StrWindowsCollapseWsIndices {
+        inner: str_windows_collapse_ws(input, size),
+    }
+}
+
+/// An iterator over the windows of `size` chars of a `&str` with collapsed
+/// whitespace, and their byte offsets.
+///
+/// This struct is created by [`str_windows_collapse_ws_indices`].
+#[derive(Clone, Debug)]
+pub struct StrWindowsCollapseWsIndices<'a> {
+    inner: StrWindowsCollapseWs<'a>,
+}
+
+impl<'a> FusedIterator for StrWindowsCollapseWsIndices<'a> { }
+
+impl<'a> Iterator for StrWindowsCollapseWsIndices<'a> {
+    type Item = (usize, Cow<'a, str>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (start, end) = self.inner.next_range()?;
+        Some((start, collapse(&self.inner.input[start..end])))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a> ExactSizeIterator for StrWindowsCollapseWsIndices<'a> { }
+
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+    use super::{str_windows_collapse_ws, str_windows_collapse_ws_indices};
+    use str_windows;
+
+    #[test]
+    fn collapse_test() {
+        let windows = str_windows_collapse_ws("a \t\nb\n c d", 2).collect::<Vec<_>>();
+        assert_eq!(windows, ["a ", " b", "b ", " c", "c ", " d"]);
+        assert!(matches!(windows[0], Cow::Owned(_)));
+        assert!(matches!(windows[4], Cow::Borrowed(_)));
+        let windows = str_windows_collapse_ws("\tx  ", 2).collect::<Vec<_>>();
+        assert_eq!(windows, [" x", "x "]);
+        assert_eq!(str_windows_collapse_ws("ab  ", 4).next(), None);
+    }
+
+    #[test]
+    fn whitespace_window_test() {
+        let windows = str_windows_collapse_ws(" \t\r\n\u{3000}", 1).collect::<Vec<_>>();
+        assert_eq!(windows, [" "]);
+        assert!(matches!(windows[0], Cow::Owned(_)));
+        let windows = str_windows_collapse_ws_indices("a \n b", 3).collect::<Vec<_>>();
+        assert_eq!(windows, [(0, "a b".into())]);
+        let windows = str_windows_collapse_ws_indices("a\n\nb  ", 1).collect::<Vec<_>>();
+        assert_eq!(windows, [(0, "a".into()), (1, " ".into()), (3, "b".into()), (4, " ".into())]);
+    }
+
+    #[test]
+    fn cjk_test() {
+        let src = "東京都庁東京都";
+        for size in 1..=8 {
+            let windows = str_windows_collapse_ws(src, size).collect::<Vec<_>>();
+            assert_eq!(windows, str_windows(src, size).collect::<Vec<_>>());
+            assert!(windows.iter().all(|w| matches!(w, Cow::Borrowed(_))));
+            let iter = str_windows_collapse_ws_indices(src, size);
+            assert_eq!(iter.len(), 8 - size);
+            for (i, window) in iter {
+                assert!(src[i..].starts_with(&*window));
+            }
+        }
+    }
+
+    #[test]
+    fn normalized_test() {
+        let src = " 头 \t尾\n\n都  是 😀\u{2003}x ";
+        let normalized = src.split_whitespace().collect::<Vec<_>>().join(" ");
+        let normalized = format!(" {} ", normalized);
+        for size in 1..=12 {
+            let windows = str_windows_collapse_ws(src, size).collect::<Vec<_>>();
+            assert_eq!(windows, str_windows(&normalized, size).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "window size must be non-zero")]
+    fn zero_size() {
+        str_windows_collapse_ws("头尾都是", 0);
+    }
+}
@@ -0,0 +1,334 @@
+use std::collections::VecDeque;
+use std::iter::FusedIterator;
+use std::ops::Range;
+use std::slice;
+
+use boundary::{next_char_boundary, nth_char_boundary, nth_char_boundary_back, prev_char_boundary};
+
+/// Returns the largest char boundary of `s` not greater than `offset`.
+fn floor_char_boundary(s: &str, offset: usize) -> usize {
+    if offset >= s.len() {
+        s.len()
+    } else if s.is_char_boundary(offset) {
+        offset
+    } else {
+        prev_char_boundary(s, offset)
+    }
+}
+
+/// Returns the slice of up to `before` chars before `byte_offset` and `after`
+/// chars from it, e.g. to quote the surroundings of an error position.
+///
+/// The slice is clamped at the ends of the input. A `byte_offset` inside a
+/// char is snapped to the start of that char, and one past the end of the
+/// input to its end. See [`char_context_range`] for the byte range instead.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::char_context;
+///
+/// let input = "let x = 😀;";
+/// assert_eq!(char_context(input, 8, 2, 3), "= 😀;");
+/// // inside the emoji
+/// assert_eq!(char_context(input, 10, 1, 1), " 😀");
+/// ```
+pub fn char_context(input: &str, byte_offset: usize, before: usize, after: usize) -> &str {
+    &input[char_context_range(input, byte_offset, before, after)]
+}
+
+/// Returns the byte range of [`char_context`].
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::char_context_range;
+///
+/// assert_eq!(char_context_range("a😀b", 3, 1, 2), 0..6);
+/// ```
+pub fn char_context_range(
+    input: &str,
+    byte_offset: usize,
+    before: usize,
+    after: usize,
+) -> Range<usize> {
+    let offset = floor_char_boundary(input, byte_offset);
+    let start = nth_char_boundary_back(&input[..offset], before);
+    let end = offset + nth_char_boundary(&input[offset..], after);
+    start..end
+}
+
+/// Returns the [`char_context`] of every offset of `offsets`, together with
+/// the offset, walking the input once.
+///
+/// `offsets` must be ascending, e.g. the match positions of a search pass.
+/// The contexts of close offsets overlap, they aren't merged.
+///
+/// # Panics
+///
+/// The iterator panics when it reaches an offset smaller than the previous
+/// one.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::char_contexts;
+///
+/// let contexts = char_contexts("a😀bcd", &[1, 5], 1, 2).collect::<Vec<_>>();
+/// assert_eq!(contexts, [(1, "a😀b"), (5, "😀bc")]);
+/// ```
+pub fn char_contexts<'a, 'b>(
+    input: &'a str,
+    offsets: &'b [usize],
+    before: usize,
+    after: usize,
+) -> CharContexts<'a, 'b> {
+    let mut starts = VecDeque::with_capacity(before.saturating_add(1).min(64));
+    starts.push_back(0);
+    CharContexts {
+        input,
+        offsets: offsets.iter(),
+        before,
+        after,
+        last: 0,
+        starts,
+        index: 0,
+        end: 0,
+        end_index: 0,
+    }
+}
+
+/// An iterator over the contexts of ascending byte offsets of a `&str`.
+///
+/// This struct is created by [`char_contexts`].
+#[derive(Clone, Debug)]
+pub struct CharContexts<'a, 'b> {
+    input: &'a str,
+    offsets: slice::Iter<'b, usize>,
+    before: usize,
+    after: usize,
+    /// The previous offset.
+    last: usize,
+    /// The last `before + 1` boundaries up to the current position, which is
+    /// the back one.
+    starts: VecDeque<usize>,
+    /// Char index of the current position.
+    index: usize,
+    /// A boundary at or after the current position, and its char index.
+    end: usize,
+    end_index: usize,
+}
+
+impl<'a, 'b> FusedIterator for CharContexts<'a, 'b> { }
+
+impl<'a, 'b> Iterator for CharContexts<'a, 'b> {
+    type Item = (usize, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let offset = *self.offsets.next()?;
+        assert!(offset >= self.last, "offsets must be ascending");
+        self.last = offset;
+        let target = floor_char_boundary(self.input, offset);
+        let mut pos = *self.starts.back().unwrap();
+        while pos < target {
+            pos = next_char_boundary(self.input, pos);
+            self.index += 1;
+            if self.starts.len() > self.before {
+                self.starts.pop_front();
+            }
+            self.starts.push_back(pos);
+        }
+        if self.end_index < self.index {
+            self.end = pos;
+            self.end_index = self.index;
+        }
+        let end_index = self.index.saturating_add(self.after);
+        while self.end_index < end_index && self.end < self.input.len() {
+            self.end = next_char_boundary(self.input, self.end);
+            self.end_index += 1;
+        }
+        Some((offset, &self.input[*self.starts.front().unwrap()..self.end]))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.offsets.size_hint()
+    }
+}
+
+impl<'a, 'b> ExactSizeIterator for CharContexts<'a, 'b> { }
+
+/// Returns every char of the input together with its context: the slice of
+/// up to `before` chars before it, the char itself and up to `after` chars
+/// after it, as for word2vec-style features.
+///
+/// The contexts are clamped at the ends of the input, so the first and last
+/// chars have shorter ones. This is [`char_contexts`] for every char, walking
+/// the input once with two cursors.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::str_context_windows;
+///
+/// let contexts = str_context_windows("abcd", 1, 2).collect::<Vec<_>>();
+/// assert_eq!(contexts, [('a', "abc"), ('b', "abcd"), ('c', "bcd"), ('d', "cd")]);
+/// ```
+pub fn str_context_windows(input: &str, before: usize, after: usize) -> StrContextWindows<'_> {
+    StrContextWindows {
+        input,
+        center: 0,
+        start: 0,
+        end: nth_char_boundary(input, after.saturating_add(1)),
+        index: 0,
+        before,
+        len: input.chars().count(),
+    }
+}
+
+/// An iterator over the chars of a `&str` and their contexts.
+///
+/// This struct is created by [`str_context_windows`].
+#[derive(Clone, Debug)]
+pub struct StrContextWindows<'a> {
+    input: &'a str,
+    /// Start of the next center char.
+    center: usize,
+    /// Byte range of the next context.
+    start: usize,
+    end: usize,
+    /// Char index of the next center char.
+    index: usize,
+    before: usize,
+    /// Number of chars left.
+    len: usize,
+}
+
+impl<'a> FusedIterator for StrContextWindows<'a> { }
+
+impl<'a> Iterator for StrContextWindows<'a> {
+    type Item = (char, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let c = self.input[self.center..].chars().next()?;
+        let context = &self.input[self.start..self.end];
+        self.center += c.len_utf8();
+        self.index += 1;
+        if self.index > self.before {
+            self.start = next_char_boundary(self.input, self.start);
+        }
+        self.end = next_char_boundary(self.input, self.end);
+        self.len -= 1;
+        Some((c, context))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a> ExactSizeIterator for StrContextWindows<'a> { }
+
+
+#[cfg(test)]
+mod tests {
+    use super::{char_context, char_context_range, char_contexts, str_context_windows};
+
+    #[test]
+    fn context_test() {
+        let input = "头尾😀都是";
+        assert_eq!(char_context(input, 6, 1, 1), "尾😀");
+        for offset in 7..10 {
+            assert_eq!(char_context(input, offset, 1, 1), "尾😀");
+            assert_eq!(char_context_range(input, offset, 1, 2), 3..13);
+        }
+        assert_eq!(char_context(input, 0, 3, 2), "头尾");
+        assert_eq!(char_context(input, 0, 0, 0), "");
+        assert_eq!(char_context(input, input.len(), 2, 3), "都是");
+        assert_eq!(char_context(input, 100, 1, 3), "是");
+        assert_eq!(char_context(input, 10, 100, 100), input);
+        assert_eq!(char_context("", 3, 1, 1), "");
+    }
+
+    #[test]
+    fn naive_test() {
+        let input = "s 😀你好é!";
+        let boundaries = input.char_indices()
+            .map(|x| x.0)
+            .chain(Some(input.len()))
+            .collect::<Vec<_>>();
+        for offset in 0..input.len() + 2 {
+            let index = boundaries.iter().rposition(|&b| b <= offset).unwrap();
+            for before in 0..4 {
+                for after in 0..4 {
+                    let start = boundaries[index.saturating_sub(before)];
+                    let end = boundaries[(index + after).min(boundaries.len() - 1)];
+                    assert_eq!(char_context_range(input, offset, before, after), start..end);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn contexts_test() {
+        let input = "头尾😀都是";
+        let offsets = [0, 3, 7, 10, 10, 16, 20];
+        let contexts = char_contexts(input, &offsets, 1, 2).collect::<Vec<_>>();
+        assert_eq!(contexts, [
+            (0, "头尾"),
+            (3, "头尾😀"),
+            (7, "尾😀都"),
+            (10, "😀都是"),
+            (10, "😀都是"),
+            (16, "是"),
+            (20, "是"),
+        ]);
+        assert_eq!(char_contexts(input, &[], 1, 2).next(), None);
+    }
+
+    #[test]
+    fn contexts_naive_test() {
+        let input = "s 😀你好é!, 头尾都是";
+        let offsets = (0..input.len() + 2).collect::<Vec<_>>();
+        for before in 0..5 {
+            for after in 0..5 {
+                for gap in 1..4 {
+                    let offsets = offsets.iter().cloned().step_by(gap).collect::<Vec<_>>();
+                    let iter = char_contexts(input, &offsets, before, after);
+                    assert_eq!(iter.len(), offsets.len());
+                    for (offset, context) in iter {
+                        assert_eq!(context, char_context(input, offset, before, after));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "offsets must be ascending")]
+    fn descending_offsets() {
+        char_contexts("头尾都是", &[6, 3], 1, 1).for_each(drop);
+    }
+
+    #[test]
+    fn context_windows_test() {
+        let contexts = str_context_windows("😀ab😁", 2, 1).collect::<Vec<_>>();
+        assert_eq!(contexts, [('😀', "😀a"), ('a', "😀ab"), ('b', "😀ab😁"), ('😁', "ab😁")]);
+        let contexts = str_context_windows("😀头😁", 0, 0).collect::<Vec<_>>();
+        assert_eq!(contexts, [('😀', "😀"), ('头', "头"), ('😁', "😁")]);
+        let contexts = str_context_windows("😀头😁", usize::MAX, usize::MAX).collect::<Vec<_>>();
+        assert!(contexts.iter().all(|x| x.1 == "😀头😁"));
+        assert_eq!(str_context_windows("", 1, 1).next(), None);
+
+        let src = "s 😀你好é!";
+        for before in 0..4 {
+            for after in 0..4 {
+                let iter = str_context_windows(src, before, after);
+                assert_eq!(iter.len(), src.chars().count());
+                let expected = src.char_indices()
+                    .map(|(i, c)| (c, char_context(src, i, before, after + 1)))
+                    .collect::<Vec<_>>();
+                assert_eq!(iter.collect::<Vec<_>>(), expected);
+            }
+        }
+    }
+}
@@ -0,0 +1,132 @@
+use std::iter::FusedIterator;
+
+use {str_windows_strict, StrWindowsNonZero};
+
+/// A window of [`str_windows_delta`] and how it differs from the previous
+/// one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct WindowDelta<'a> {
+    /// The window.
+    pub window: &'a str,
+    /// The char that entered the window, its last char.
+    pub entered: char,
+    /// The char that left the previous window, its first char, or `None` for
+    /// the first window.
+    pub left: Option<char>,
+}
+
+/// Returns the windows of `size` chars of [`str_windows`] with the chars
+/// entering and leaving each one, e.g. to update a frequency table of the
+/// chars of the windows as they slide.
+///
+/// Each window is the previous one without its `left` char and with its
+/// `entered` char. The first window has no previous one, so all of its chars
+/// entered it, and `entered` is its last char.
+///
+/// # Panics
+///
+/// Panics if `size` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::{str_windows_delta, WindowDelta};
+///
+/// let mut iter = str_windows_delta("头尾😀", 2);
+/// let delta = iter.next().unwrap();
+/// assert_eq!(delta, WindowDelta { window: "头尾", entered: '尾', left: None });
+/// let delta = iter.next().unwrap();
+/// assert_eq!(delta, WindowDelta { window: "尾😀", entered: '😀', left: Some('头') });
+/// ```
+///
+/// [`str_windows`]: crate::str_windows
+pub fn str_windows_delta(input: &str, size: usize) -> StrWindowsDelta<'_> {
+    StrWindowsDelta {
+        inner: str_windows_strict(input, size),
+        first: None,
+    }
+}
+
+/// An iterator over the windows of `size` chars of a `&str` and their
+/// [`WindowDelta`].
+///
+/// This struct is created by [`str_windows_delta`].
+#[derive(Clone, Debug)]
+pub struct StrWindowsDelta<'a> {
+    inner: StrWindowsNonZero<'a>,
+    /// The first char of the last window yielded.
+    first: Option<char>,
+}
+
+impl<'a> FusedIterator for StrWindowsDelta<'a> { }
+
+impl<'a> Iterator for StrWindowsDelta<'a> {
+    type Item = WindowDelta<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let window = self.inner.next()?;
+        let mut chars = window.chars();
+        // windows aren't empty
+        let first = chars.next().unwrap();
+        let entered = chars.next_back().unwrap_or(first);
+        Some(WindowDelta {
+            window,
+            entered,
+            left: self.first.replace(first),
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a> ExactSizeIterator for StrWindowsDelta<'a> { }
+
+
+#[cfg(test)]
+mod tests {
+    use super::{str_windows_delta, WindowDelta};
+    use str_windows;
+
+    #[test]
+    fn delta_test() {
+        for &src in &["hello, 你好, hi", "头尾都是", "头部是!", ": 尾部是", "s 😀😁", ""] {
+            for size in 1..=6 {
+                let iter = str_windows_delta(src, size);
+                assert_eq!(iter.len(), str_windows(src, size).count());
+                let mut window = String::new();
+                for (delta, expected) in iter.zip(str_windows(src, size)) {
+                    match delta.left {
+                        Some(left) => assert_eq!(window.remove(0), left),
+                        // only the last char of the first window is given
+                        None => {
+                            let end = expected.len() - delta.entered.len_utf8();
+                            window.push_str(&expected[..end]);
+                        }
+                    }
+                    window.push(delta.entered);
+                    assert_eq!(window, expected);
+                    assert_eq!(delta.window, expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn single_test() {
+        let windows = str_windows_delta("头尾", 1).collect::<Vec<_>>();
+        let expected = [
+            WindowDelta { window: "头", entered: '头', left: None },
+            WindowDelta { window: "尾", entered: '尾', left: Some('头') },
+        ];
+        assert_eq!(windows, expected);
+        assert_eq!(str_windows_delta("头尾", 3).next(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "window size must be non-zero")]
+    fn zero_size() {
+        str_windows_delta("头尾都是", 0);
+    }
+}
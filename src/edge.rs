@@ -0,0 +1,136 @@
+use std::iter::FusedIterator;
+use std::str::SplitWhitespace;
+
+use boundary::{next_char_boundary, nth_char_boundary};
+
+/// Returns the edge n-grams of the words of the input: the prefixes of each
+/// word of `min` to `max` chars, as used by search engines for
+/// search-as-you-type.
+///
+/// Words are the runs of non-whitespace chars, so a run of CJK chars without
+/// spaces is a single word. The prefixes are slices of the input, yielded in
+/// document order, from the shortest to the longest for each word. Words of
+/// fewer than `min` chars are skipped, unless
+/// [`keep_short_words`](EdgeNgrams::keep_short_words) is set.
+///
+/// # Panics
+///
+/// Panics if `min` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::edge_ngrams;
+///
+/// let grams = edge_ngrams("a search", 2, 4).collect::<Vec<_>>();
+/// assert_eq!(grams, ["se", "sea", "sear"]);
+///
+/// let grams = edge_ngrams("a search", 2, 4).keep_short_words(true).collect::<Vec<_>>();
+/// assert_eq!(grams, ["a", "se", "sea", "sear"]);
+/// ```
+pub fn edge_ngrams(input: &str, min: usize, max: usize) -> EdgeNgrams<'_> {
+    assert!(min != 0, "window size must be non-zero");
+    EdgeNgrams {
+        words: input.split_whitespace(),
+        word: "",
+        end: 0,
+        size: max,
+        min,
+        max,
+        keep_short: false,
+    }
+}
+
+/// An iterator over the edge n-grams of the words of a `&str`.
+///
+/// This struct is created by [`edge_ngrams`].
+#[derive(Clone, Debug)]
+pub struct EdgeNgrams<'a> {
+    words: SplitWhitespace<'a>,
+    /// The current word.
+    word: &'a str,
+    /// End of the last prefix yielded from `word`.
+    end: usize,
+    /// Number of chars of the last prefix yielded from `word`.
+    size: usize,
+    min: usize,
+    max: usize,
+    keep_short: bool,
+}
+
+impl<'a> EdgeNgrams<'a> {
+    /// Sets whether to yield the words of fewer than `min` chars whole,
+    /// instead of skipping them. Off by default.
+    pub fn keep_short_words(mut self, keep_short: bool) -> Self {
+        self.keep_short = keep_short;
+        self
+    }
+}
+
+impl<'a> FusedIterator for EdgeNgrams<'a> { }
+
+impl<'a> Iterator for EdgeNgrams<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.size >= self.max || self.end == self.word.len() {
+            self.word = self.words.next()?;
+            self.end = nth_char_boundary(self.word, self.min - 1);
+            self.size = self.min - 1;
+            if self.end == self.word.len() {
+                // fewer than `min` chars
+                if self.keep_short {
+                    return Some(self.word);
+                }
+            }
+        }
+        self.end = next_char_boundary(self.word, self.end);
+        self.size += 1;
+        Some(&self.word[..self.end])
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::edge_ngrams;
+
+    #[test]
+    fn edge_ngrams_test() {
+        let grams = edge_ngrams("  café crème ", 3, 5).collect::<Vec<_>>();
+        assert_eq!(grams, ["caf", "café", "crè", "crèm", "crème"]);
+        let grams = edge_ngrams("东京都 大阪", 1, 2).collect::<Vec<_>>();
+        assert_eq!(grams, ["东", "东京", "大", "大阪"]);
+        let grams = edge_ngrams("😀 😀😁!", 2, 9).collect::<Vec<_>>();
+        assert_eq!(grams, ["😀😁", "😀😁!"]);
+        let grams = edge_ngrams("😀 😀😁!", 2, 9).keep_short_words(true).collect::<Vec<_>>();
+        assert_eq!(grams, ["😀", "😀😁", "😀😁!"]);
+        assert_eq!(edge_ngrams("é", 2, 2).next(), None);
+        assert_eq!(edge_ngrams("search", 3, 2).next(), None);
+        assert_eq!(edge_ngrams(" \t\n", 1, 2).keep_short_words(true).next(), None);
+    }
+
+    #[test]
+    fn prefixes_test() {
+        let src = "hello, 你好 hi 😀é\u{3000}s";
+        for min in 1..=4 {
+            for max in min..=6 {
+                let expected = src.split_whitespace()
+                    .flat_map(|word| {
+                        let chars = word.chars().count();
+                        (min..=max.min(chars)).map(move |n| {
+                            &word[..word.char_indices().nth(n).map_or(word.len(), |x| x.0)]
+                        })
+                    })
+                    .collect::<Vec<_>>();
+                assert_eq!(edge_ngrams(src, min, max).collect::<Vec<_>>(), expected);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "window size must be non-zero")]
+    fn zero_size() {
+        edge_ngrams("头尾都是", 0, 2);
+    }
+}
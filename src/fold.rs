@@ -0,0 +1,228 @@
+use std::borrow::Cow;
+use std::iter::FusedIterator;
+
+use {str_windows_strict, StrWindowsNonZero};
+
+mod tables;
+
+/// The case folding of a char.
+enum Folded {
+    One(char),
+    Many(&'static str),
+}
+
+/// Returns the multi-char full case folding of `c`, if it has one.
+fn full_fold(c: char) -> Option<&'static str> {
+    let table = tables::FULL;
+    table.binary_search_by_key(&c, |&(from, _)| from).ok().map(|i| table[i].1)
+}
+
+/// Returns the simple case folding of `c`.
+fn simple_fold(c: char) -> char {
+    if c.is_ascii() {
+        return c.to_ascii_lowercase();
+    }
+    let table = tables::SIMPLE;
+    table.binary_search_by_key(&c, |&(from, _)| from).map_or(c, |i| table[i].1)
+}
+
+/// Returns the case folding of `c`, the full one unless `simple`.
+fn fold(c: char, simple: bool) -> Folded {
+    match full_fold(c) {
+        Some(folded) if !simple => Folded::Many(folded),
+        _ => Folded::One(simple_fold(c)),
+    }
+}
+
+/// Returns `window` case folded, borrowed if that doesn't change it.
+fn fold_window(window: &str, simple: bool) -> Cow<'_, str> {
+    let unchanged = window.chars().all(|c| match fold(c, simple) {
+        Folded::One(folded) => folded == c,
+        Folded::Many(_) => false,
+    });
+    if unchanged {
+        return Cow::Borrowed(window);
+    }
+    let mut folded = String::with_capacity(window.len());
+    for c in window.chars() {
+        match fold(c, simple) {
+            Folded::One(c) => folded.push(c),
+            Folded::Many(s) => folded.push_str(s),
+        }
+    }
+    Cow::Owned(folded)
+}
+
+/// Returns the windows of `size` chars of [`str_windows`] case folded, e.g.
+/// for case-insensitive matching.
+///
+/// The full Unicode case folding is used by default, without the Turkic
+/// mappings: `'I'` folds to `'i'` and `'ı'` to itself. It maps some chars to
+/// several, e.g. `'ß'` to `"ss"`, so a folded window may be longer than
+/// `size` chars. [`simple`](StrWindowsFolded::simple) folding maps each char
+/// to a single one instead. The mappings are built in, generated from
+/// `CaseFolding.txt` of Unicode 14.0.
+///
+/// A window is borrowed from the input when it's already folded, otherwise
+/// it's folded into a `String`.
+///
+/// # Panics
+///
+/// Panics if `size` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use std::borrow::Cow;
+/// use str_windows::str_windows_folded;
+///
+/// let mut iter = str_windows_folded("Maße", 2);
+/// assert_eq!(iter.next(), Some(Cow::Owned("ma".to_string())));
+/// assert_eq!(iter.next(), Some(Cow::Owned("ass".to_string())));
+/// assert_eq!(iter.next(), Some(Cow::Owned("sse".to_string())));
+/// assert!(iter.next().is_none());
+///
+/// let windows = str_windows_folded("Maße", 2).simple(true).collect::<Vec<_>>();
+/// assert_eq!(windows, ["ma", "aß", "ße"]);
+/// ```
+///
+/// [`str_windows`]: crate::str_windows
+pub fn str_windows_folded(input: &str, size: usize) -> StrWindowsFolded<'_> {
+    StrWindowsFolded {
+        inner: str_windows_strict(input, size),
+        simple: false,
+    }
+}
+
+/// An iterator over the windows of `size` chars of a `&str`, case folded.
+///
+/// This struct is created by [`str_windows_folded`].
+#[derive(Clone, Debug)]
+pub struct StrWindowsFolded<'a> {
+    inner: StrWindowsNonZero<'a>,
+    simple: bool,
+}
+
+impl<'a> StrWindowsFolded<'a> {
+    /// Sets whether to use the simple case folding, mapping every char to a
+    /// single one so that the windows keep `size` chars. Defaults to `false`.
+    pub fn simple(mut self, simple: bool) -> Self {
+        self.simple = simple;
+        self
+    }
+}
+
+impl<'a> FusedIterator for StrWindowsFolded<'a> { }
+
+impl<'a> Iterator for StrWindowsFolded<'a> {
+    type Item = Cow<'a, str>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let simple = self.simple;
+        self.inner.next().map(|window| fold_window(window, simple))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let simple = self.simple;
+        self.inner.nth(n).map(|window| fold_window(window, simple))
+    }
+}
+
+impl<'a> DoubleEndedIterator for StrWindowsFolded<'a> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let simple = self.simple;
+        self.inner.next_back().map(|window| fold_window(window, simple))
+    }
+}
+
+impl<'a> ExactSizeIterator for StrWindowsFolded<'a> { }
+
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+    use super::{fold_window, str_windows_folded};
+
+    #[test]
+    fn turkish_test() {
+        let windows = str_windows_folded("Iıİi", 1).collect::<Vec<_>>();
+        assert_eq!(windows, ["i", "ı", "i\u{307}", "i"]);
+        assert!(matches!(windows[1], Cow::Borrowed(_)));
+        let windows = str_windows_folded("Iıİi", 1).simple(true).collect::<Vec<_>>();
+        assert_eq!(windows, ["i", "ı", "İ", "i"]);
+        assert!(matches!(windows[2], Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn sharp_s_test() {
+        let windows = str_windows_folded("STRAẞE", 5).collect::<Vec<_>>();
+        assert_eq!(windows, ["strass", "trasse"]);
+        let windows = str_windows_folded("STRAẞE", 5).simple(true).collect::<Vec<_>>();
+        assert_eq!(windows, ["straß", "traße"]);
+        let windows = str_windows_folded("straße", 6).collect::<Vec<_>>();
+        assert_eq!(windows, ["strasse"]);
+        let window = str_windows_folded("straße", 6).simple(true).next();
+        assert_eq!(window, Some(Cow::Borrowed("straße")));
+    }
+
+    #[test]
+    fn folded_test() {
+        let windows = str_windows_folded("ΣΊΣΥΦΟΣ", 7).collect::<Vec<_>>();
+        assert_eq!(windows, ["σίσυφοσ"]);
+        let windows = str_windows_folded("σίσυφος", 7).collect::<Vec<_>>();
+        assert_eq!(windows, ["σίσυφοσ"]);
+        let windows = str_windows_folded("ǅﬁ头", 2).rev().collect::<Vec<_>>();
+        assert_eq!(windows, ["fi头", "ǆfi"]);
+        assert_eq!(str_windows_folded("Ꭰꭰ", 2).next(), Some(Cow::Owned("ᎠᎠ".into())));
+
+        let src = "hello, 你好, hi 😀";
+        let windows = str_windows_folded(src, 3).collect::<Vec<_>>();
+        assert_eq!(windows.len(), 13);
+        assert!(windows.iter().all(|w| matches!(w, Cow::Borrowed(_))));
+    }
+
+    #[test]
+    fn known_folds_test() {
+        // (char, full folding, simple folding) of CaseFolding.txt
+        let folds = [
+            // the micro and Kelvin signs, the angstrom sign and final sigma
+            ('\u{b5}', "\u{3bc}", '\u{3bc}'),
+            ('\u{212a}', "k", 'k'),
+            ('\u{212b}', "\u{e5}", '\u{e5}'),
+            ('\u{3c2}', "\u{3c3}", '\u{3c3}'),
+            // Cyrillic variant letters
+            ('\u{1c80}', "\u{432}", '\u{432}'),
+            ('\u{1c84}', "\u{442}", '\u{442}'),
+            ('\u{1c85}', "\u{442}", '\u{442}'),
+            ('\u{1c88}', "\u{a64b}", '\u{a64b}'),
+            // Greek letters with diacritics that have no precomposed folding
+            ('\u{1f50}', "\u{3c5}\u{313}", '\u{1f50}'),
+            ('\u{1f56}', "\u{3c5}\u{313}\u{342}", '\u{1f56}'),
+            ('\u{1fb6}', "\u{3b1}\u{342}", '\u{1fb6}'),
+            ('\u{1fd3}', "\u{3b9}\u{308}\u{301}", '\u{1fd3}'),
+            ('\u{1fe4}', "\u{3c1}\u{313}", '\u{1fe4}'),
+            ('\u{1ff6}', "\u{3c9}\u{342}", '\u{1ff6}'),
+            // iota subscripts and adscripts
+            ('\u{1f80}', "\u{1f00}\u{3b9}", '\u{1f80}'),
+            ('\u{1f88}', "\u{1f00}\u{3b9}", '\u{1f80}'),
+            ('\u{1fbc}', "\u{3b1}\u{3b9}", '\u{1fb3}'),
+        ];
+        for &(c, full, simple) in &folds {
+            let c = c.to_string();
+            assert_eq!(fold_window(&c, false), full, "U+{:04X}", c.chars().next().unwrap() as u32);
+            assert_eq!(fold_window(&c, true), simple.to_string());
+        }
+        let windows = str_windows_folded("µm μm", 2).collect::<Vec<_>>();
+        assert_eq!(windows[0], windows[3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "window size must be non-zero")]
+    fn zero_size() {
+        str_windows_folded("头尾都是", 0);
+    }
+}
@@ -0,0 +1,686 @@
+use std::cmp::Ordering;
+use std::error::Error;
+use std::fmt;
+use std::iter::FusedIterator;
+
+use windows::{Boundaries, Windows};
+
+#[cfg(test)]
+mod break_tests;
+mod tables;
+
+/// The grapheme cluster break property of a char.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Gcb {
+    Other,
+    Cr,
+    Lf,
+    Control,
+    Extend,
+    Zwj,
+    RegionalIndicator,
+    Prepend,
+    SpacingMark,
+    L,
+    V,
+    T,
+    Lv,
+    Lvt,
+    ExtendedPictographic,
+}
+
+/// Returns the grapheme cluster break property of `c`.
+fn gcb(c: char) -> Gcb {
+    match c {
+        '\r' => Gcb::Cr,
+        '\n' => Gcb::Lf,
+        '\u{200d}' => Gcb::Zwj,
+        '\u{1f1e6}'..='\u{1f1ff}' => Gcb::RegionalIndicator,
+        '\u{1100}'..='\u{115f}' | '\u{a960}'..='\u{a97c}' => Gcb::L,
+        '\u{1160}'..='\u{11a7}' | '\u{d7b0}'..='\u{d7c6}' => Gcb::V,
+        '\u{11a8}'..='\u{11ff}' | '\u{d7cb}'..='\u{d7fb}' => Gcb::T,
+        // the syllables without a final consonant are every 28th one
+        '\u{ac00}'..='\u{d7a3}' if (c as u32 - 0xac00).is_multiple_of(28) => Gcb::Lv,
+        '\u{ac00}'..='\u{d7a3}' => Gcb::Lvt,
+        _ => {
+            let table = tables::GRAPHEME_CLUSTER_BREAK;
+            match table.binary_search_by(|&(start, end, _)| {
+                if end < c {
+                    Ordering::Less
+                } else if start > c {
+                    Ordering::Greater
+                } else {
+                    Ordering::Equal
+                }
+            }) {
+                Ok(i) => table[i].2,
+                Err(_) => Gcb::Other,
+            }
+        }
+    }
+}
+
+/// Returns `true` if `c` extends the char before it, as combining marks do.
+#[cfg(feature = "words")]
+pub(crate) fn is_extend(c: char) -> bool {
+    matches!(gcb(c), Gcb::Extend | Gcb::SpacingMark | Gcb::Zwj)
+}
+
+/// Returns the end of the grapheme cluster of `input` starting at the char
+/// boundary `i`, or `input.len()` if `i` is at the end.
+///
+/// This implements the extended grapheme cluster rules of UAX #29 for
+/// Unicode 14.0, which predate the GB9c rule of Unicode 15.1, so Indic
+/// conjuncts are split after their virama.
+fn next_grapheme_boundary(input: &str, i: usize) -> usize {
+    let mut chars = input[i..].char_indices();
+    let mut prev = match chars.next() {
+        Some((_, c)) => gcb(c),
+        None => return input.len(),
+    };
+    // whether `prev` ends an emoji followed by extenders, for GB11
+    let mut pictographic = prev == Gcb::ExtendedPictographic;
+    // the number of regional indicators ending at `prev`, for GB12 and GB13
+    let mut regional = (prev == Gcb::RegionalIndicator) as usize;
+    for (j, c) in chars {
+        let cur = gcb(c);
+        let join = match (prev, cur) {
+            (Gcb::Cr, Gcb::Lf) => true,
+            (Gcb::Control, _) | (Gcb::Cr, _) | (Gcb::Lf, _) => false,
+            (_, Gcb::Control) | (_, Gcb::Cr) | (_, Gcb::Lf) => false,
+            (Gcb::L, Gcb::L) | (Gcb::L, Gcb::V) | (Gcb::L, Gcb::Lv) | (Gcb::L, Gcb::Lvt) => true,
+            (Gcb::Lv, Gcb::V) | (Gcb::Lv, Gcb::T) | (Gcb::V, Gcb::V) | (Gcb::V, Gcb::T) => true,
+            (Gcb::Lvt, Gcb::T) | (Gcb::T, Gcb::T) => true,
+            (_, Gcb::Extend) | (_, Gcb::Zwj) | (_, Gcb::SpacingMark) | (Gcb::Prepend, _) => true,
+            (Gcb::Zwj, Gcb::ExtendedPictographic) => pictographic,
+            (Gcb::RegionalIndicator, Gcb::RegionalIndicator) => regional % 2 == 1,
+            _ => false,
+        };
+        if !join {
+            return i + j;
+        }
+        pictographic = match cur {
+            Gcb::ExtendedPictographic => true,
+            Gcb::Extend => pictographic && prev != Gcb::Zwj,
+            Gcb::Zwj => pictographic && prev != Gcb::Zwj,
+            _ => false,
+        };
+        regional = if cur == Gcb::RegionalIndicator { regional + 1 } else { 0 };
+        prev = cur;
+    }
+    input.len()
+}
+
+/// Returns the byte offset of the grapheme cluster with index `n` of
+/// `input`, or `input.len()` if it has `n` clusters or fewer.
+fn nth_grapheme_boundary(input: &str, n: usize) -> usize {
+    let mut i = 0;
+    for _ in 0..n {
+        if i == input.len() {
+            break;
+        }
+        i = next_grapheme_boundary(input, i);
+    }
+    i
+}
+
+/// Returns the number of grapheme clusters of `input`.
+fn grapheme_count(input: &str) -> usize {
+    let mut count = 0;
+    let mut i = 0;
+    while i != input.len() {
+        i = next_grapheme_boundary(input, i);
+        count += 1;
+    }
+    count
+}
+
+/// The boundaries of the extended grapheme clusters of a `&str`, the units
+/// of [`grapheme_windows`], to use them with [`Windows`].
+///
+/// This requires the `graphemes` feature.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::{GraphemeBoundaries, Windows};
+///
+/// let windows = Windows::new("cafe\u{301}!", 2, GraphemeBoundaries).collect::<Vec<_>>();
+/// assert_eq!(windows, ["ca", "af", "fe\u{301}", "e\u{301}!"]);
+/// ```
+///
+/// [`Windows`]: crate::Windows
+#[derive(Clone, Copy, Debug, Default)]
+pub struct GraphemeBoundaries;
+
+impl Boundaries for GraphemeBoundaries {
+    fn next_boundary(&self, s: &str, from: usize) -> Option<usize> {
+        if from < s.len() {
+            Some(next_grapheme_boundary(s, from))
+        } else {
+            None
+        }
+    }
+
+    fn count_hint(&self, s: &str) -> Option<usize> {
+        Some(grapheme_count(s))
+    }
+}
+
+/// Returns substrings of `size` extended grapheme clusters, the
+/// user-perceived characters, like [`str_windows`] does with chars.
+///
+/// Emoji sequences joined with ZWJs, flags and letters followed by
+/// combining marks are thus never split. The windows overlap as those of
+/// [`str_windows`], and as with it an input of fewer than `size` clusters
+/// yields nothing, and a `size` of 0 yields `Some("")` forever.
+///
+/// The clusters follow the rules of UAX #29 with the properties of Unicode
+/// 14.0, which predate the rule keeping Indic conjuncts together. This
+/// requires the `graphemes` feature.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::grapheme_windows;
+///
+/// // "e" followed by a combining acute accent
+/// let windows = grapheme_windows("cafe\u{301}!", 2).collect::<Vec<_>>();
+/// assert_eq!(windows, ["ca", "af", "fe\u{301}", "e\u{301}!"]);
+/// ```
+///
+/// [`str_windows`]: crate::str_windows
+pub fn grapheme_windows(input: &str, size: usize) -> GraphemeWindows<'_> {
+    GraphemeWindows {
+        inner: Windows::new(input, size, GraphemeBoundaries),
+    }
+}
+
+/// An iterator over windows of `size` grapheme clusters of a `&str`.
+///
+/// This struct is created by [`grapheme_windows`].
+#[derive(Clone, Debug)]
+pub struct GraphemeWindows<'a> {
+    inner: Windows<'a, GraphemeBoundaries>,
+}
+
+impl<'a> FusedIterator for GraphemeWindows<'a> { }
+
+impl<'a> Iterator for GraphemeWindows<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+/// Returns the windows of [`grapheme_windows`] together with the byte offset
+/// of each window in the input, i.e. of its first cluster. A `size` of 0
+/// yields `(0, "")` forever.
+///
+/// This requires the `graphemes` feature.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::grapheme_windows_indices;
+///
+/// let windows = grapheme_windows_indices("ae\u{301}b", 2).collect::<Vec<_>>();
+/// assert_eq!(windows, [(0, "ae\u{301}"), (1, "e\u{301}b")]);
+/// ```
+pub fn grapheme_windows_indices(input: &str, size: usize) -> GraphemeWindowsIndices<'_> {
+    GraphemeWindowsIndices {
+        inner: grapheme_windows(input, size),
+    }
+}
+
+/// An iterator over windows of `size` grapheme clusters of a `&str` and
+/// their byte offsets.
+///
+/// This struct is created by [`grapheme_windows_indices`].
+#[derive(Clone, Debug)]
+pub struct GraphemeWindowsIndices<'a> {
+    inner: GraphemeWindows<'a>,
+}
+
+impl<'a> FusedIterator for GraphemeWindowsIndices<'a> { }
+
+impl<'a> Iterator for GraphemeWindowsIndices<'a> {
+    type Item = (usize, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let offset = self.inner.inner.offset();
+        self.inner.next().map(|window| (offset, window))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+/// Returns consecutive substrings of `size` grapheme clusters, the last one
+/// possibly shorter, like [`str_chunks`] does with chars, e.g. to paginate
+/// text without splitting a user-perceived character.
+///
+/// The chunks don't overlap and concatenate back to `input`. See
+/// [`grapheme_chunks_exact`] to leave out a shorter last chunk. This
+/// requires the `graphemes` feature.
+///
+/// # Panics
+///
+/// Panics if `size` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::grapheme_chunks;
+///
+/// let chunks = grapheme_chunks("🇷🇸🇺🇸a\u{308}b", 2).collect::<Vec<_>>();
+/// assert_eq!(chunks, ["🇷🇸🇺🇸", "a\u{308}b"]);
+/// ```
+///
+/// [`str_chunks`]: crate::str_chunks
+pub fn grapheme_chunks(input: &str, size: usize) -> GraphemeChunks<'_> {
+    assert!(size != 0, "chunk size must be non-zero");
+    GraphemeChunks {
+        inner: input,
+        size,
+        len: grapheme_count(input).div_ceil(size),
+    }
+}
+
+/// An iterator over non-overlapping chunks of `size` grapheme clusters of a
+/// `&str`.
+///
+/// This struct is created by [`grapheme_chunks`].
+#[derive(Clone, Debug)]
+pub struct GraphemeChunks<'a> {
+    inner: &'a str,
+    size: usize,
+    len: usize,
+}
+
+impl<'a> FusedIterator for GraphemeChunks<'a> { }
+
+impl<'a> Iterator for GraphemeChunks<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.inner.is_empty() {
+            return None;
+        }
+        let (chunk, rest) = self.inner.split_at(nth_grapheme_boundary(self.inner, self.size));
+        self.inner = rest;
+        self.len -= 1;
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a> ExactSizeIterator for GraphemeChunks<'a> { }
+
+/// Returns consecutive substrings of exactly `size` grapheme clusters, like
+/// [`str_chunks_exact`] does with chars.
+///
+/// The trailing clusters that don't fill a chunk are not yielded, but are
+/// available from [`GraphemeChunksExact::remainder`]. This requires the
+/// `graphemes` feature.
+///
+/// # Panics
+///
+/// Panics if `size` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::grapheme_chunks_exact;
+///
+/// let mut iter = grapheme_chunks_exact("🇷🇸🇺🇸a\u{308}", 2);
+/// assert_eq!(iter.next(), Some("🇷🇸🇺🇸"));
+/// assert!(iter.next().is_none());
+/// assert_eq!(iter.remainder(), "a\u{308}");
+/// ```
+///
+/// [`str_chunks_exact`]: crate::str_chunks_exact
+pub fn grapheme_chunks_exact(input: &str, size: usize) -> GraphemeChunksExact<'_> {
+    assert!(size != 0, "chunk size must be non-zero");
+    let len = grapheme_count(input) / size;
+    let (inner, rem) = input.split_at(nth_grapheme_boundary(input, len * size));
+    GraphemeChunksExact {
+        inner,
+        rem,
+        size,
+        len,
+    }
+}
+
+/// An iterator over non-overlapping chunks of exactly `size` grapheme
+/// clusters of a `&str`.
+///
+/// This struct is created by [`grapheme_chunks_exact`].
+#[derive(Clone, Debug)]
+pub struct GraphemeChunksExact<'a> {
+    inner: &'a str,
+    rem: &'a str,
+    size: usize,
+    len: usize,
+}
+
+impl<'a> GraphemeChunksExact<'a> {
+    /// Returns the trailing clusters that don't fill a whole chunk, which is
+    /// empty when the cluster count of the input is a multiple of `size`.
+    pub fn remainder(&self) -> &'a str {
+        self.rem
+    }
+}
+
+impl<'a> FusedIterator for GraphemeChunksExact<'a> { }
+
+impl<'a> Iterator for GraphemeChunksExact<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.inner.is_empty() {
+            return None;
+        }
+        let (chunk, rest) = self.inner.split_at(nth_grapheme_boundary(self.inner, self.size));
+        self.inner = rest;
+        self.len -= 1;
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a> ExactSizeIterator for GraphemeChunksExact<'a> { }
+
+/// Splits the input into consecutive substrings of at most `max_bytes`
+/// bytes, each as long as possible without splitting a grapheme cluster,
+/// like [`str_chunks_bytes`] does with chars.
+///
+/// The chunks concatenate back to `input`. Returns an error if a cluster of
+/// the input is longer than `max_bytes`, since no chunk could hold it. This
+/// requires the `graphemes` feature.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::grapheme_chunks_bytes;
+///
+/// // the flag is 8 bytes
+/// let chunks = grapheme_chunks_bytes("ab🇷🇸c", 9).unwrap().collect::<Vec<_>>();
+/// assert_eq!(chunks, ["ab", "🇷🇸c"]);
+///
+/// let error = grapheme_chunks_bytes("ab🇷🇸c", 4).unwrap_err();
+/// assert_eq!((error.offset(), error.cluster_len()), (2, 8));
+/// ```
+///
+/// [`str_chunks_bytes`]: crate::str_chunks_bytes
+pub fn grapheme_chunks_bytes(
+    input: &str,
+    max_bytes: usize,
+) -> Result<GraphemeChunksBytes<'_>, GraphemeTooLongError> {
+    let mut i = 0;
+    while i != input.len() {
+        let end = next_grapheme_boundary(input, i);
+        if end - i > max_bytes {
+            return Err(GraphemeTooLongError {
+                offset: i,
+                cluster_len: end - i,
+                max_bytes,
+            });
+        }
+        i = end;
+    }
+    Ok(GraphemeChunksBytes {
+        inner: input,
+        max_bytes,
+    })
+}
+
+/// The error returned by [`grapheme_chunks_bytes`] when a grapheme cluster
+/// doesn't fit in the byte budget.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GraphemeTooLongError {
+    offset: usize,
+    cluster_len: usize,
+    max_bytes: usize,
+}
+
+impl GraphemeTooLongError {
+    /// Returns the byte offset in the input of the first cluster longer than
+    /// the budget.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the length in bytes of the cluster.
+    pub fn cluster_len(&self) -> usize {
+        self.cluster_len
+    }
+}
+
+impl fmt::Display for GraphemeTooLongError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "grapheme cluster of {} bytes at byte {} is longer than {} bytes",
+            self.cluster_len, self.offset, self.max_bytes,
+        )
+    }
+}
+
+impl Error for GraphemeTooLongError { }
+
+/// An iterator over consecutive chunks of at most `max_bytes` bytes of a
+/// `&str`, split between grapheme clusters.
+///
+/// This struct is created by [`grapheme_chunks_bytes`].
+#[derive(Clone, Debug)]
+pub struct GraphemeChunksBytes<'a> {
+    inner: &'a str,
+    max_bytes: usize,
+}
+
+impl<'a> FusedIterator for GraphemeChunksBytes<'a> { }
+
+impl<'a> Iterator for GraphemeChunksBytes<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.inner.is_empty() {
+            return None;
+        }
+        let mut end = 0;
+        while end != self.inner.len() {
+            let next = next_grapheme_boundary(self.inner, end);
+            if next > self.max_bytes {
+                break;
+            }
+            end = next;
+        }
+        let (chunk, rest) = self.inner.split_at(end);
+        self.inner = rest;
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // a chunk holds at least one byte and at most `max_bytes` bytes
+        let len = self.inner.len();
+        (len.div_ceil(self.max_bytes.max(1)), Some(len))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::break_tests::BREAK_TESTS;
+    use super::{
+        grapheme_chunks, grapheme_chunks_bytes, grapheme_chunks_exact, grapheme_count,
+        grapheme_windows, grapheme_windows_indices, GraphemeBoundaries,
+    };
+    use Windows;
+
+    const FAMILY: &str = "👨\u{200d}👩\u{200d}👧\u{200d}👦";
+
+    #[test]
+    fn clusters_test() {
+        assert_eq!(grapheme_count(FAMILY), 1);
+        assert_eq!(grapheme_count("🇷🇸🇺🇸🇷"), 3);
+        assert_eq!(grapheme_count("e\u{301}\u{302}"), 1);
+        assert_eq!(grapheme_count("\r\n\n\r"), 3);
+        assert_eq!(grapheme_count("👍🏽a\u{200d}👍"), 3);
+        assert_eq!(grapheme_count("한국어 \u{1112}\u{1161}\u{11ab}"), 5);
+        assert_eq!(grapheme_count("नमस्ते"), 4);
+        assert_eq!(grapheme_count(""), 0);
+    }
+
+    #[test]
+    fn break_test() {
+        for &clusters in BREAK_TESTS {
+            let src = clusters.concat();
+            assert_eq!(grapheme_count(&src), clusters.len(), "{:?}", src);
+            assert_eq!(grapheme_chunks(&src, 1).collect::<Vec<_>>(), clusters);
+            assert_eq!(grapheme_windows(&src, 1).collect::<Vec<_>>(), clusters);
+        }
+    }
+
+    #[test]
+    fn windows_test() {
+        let src = format!("a{}🇷🇸e\u{301}", FAMILY);
+        let windows = grapheme_windows(&src, 2).collect::<Vec<_>>();
+        let expected = [format!("a{}", FAMILY), format!("{}🇷🇸", FAMILY), "🇷🇸e\u{301}".into()];
+        assert_eq!(windows, expected);
+        let windows = grapheme_windows(&src, 4).collect::<Vec<_>>();
+        assert_eq!(windows, [&src[..]]);
+        assert_eq!(grapheme_windows(&src, 5).next(), None);
+        assert_eq!(grapheme_windows(&src, usize::MAX).next(), None);
+
+        // the decomposed "é" windows like the precomposed one
+        let decomposed = grapheme_windows("de\u{301}ja\u{300}", 1).collect::<Vec<_>>();
+        assert_eq!(decomposed, ["d", "e\u{301}", "j", "a\u{300}"]);
+
+        for size in 1..=5 {
+            let iter = Windows::new(&src, size, GraphemeBoundaries);
+            assert_eq!(iter.size_hint(), grapheme_windows(&src, size).size_hint());
+            let windows = iter.collect::<Vec<_>>();
+            assert_eq!(windows, grapheme_windows(&src, size).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn size_hint_test() {
+        let src = format!("{}🇷🇸🇷🇸e\u{301}", FAMILY);
+        let mut iter = grapheme_windows(&src, 2);
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+        iter.next();
+        assert_eq!(iter.size_hint(), (2, Some(2)));
+        assert_eq!(grapheme_windows(&src, 5).size_hint(), (0, Some(0)));
+        assert_eq!(grapheme_windows(&src, 0).size_hint(), (usize::MAX, None));
+        assert_eq!(grapheme_windows(&src, 0).take(3).collect::<Vec<_>>(), ["", "", ""]);
+    }
+
+    #[test]
+    fn indices_test() {
+        let src = format!("ab{}한국어 🇷🇸x", FAMILY);
+        for size in 1..=9 {
+            let iter = grapheme_windows_indices(&src, size);
+            assert_eq!(iter.size_hint(), grapheme_windows(&src, size).size_hint());
+            let windows = iter.collect::<Vec<_>>();
+            let expected = grapheme_windows(&src, size).collect::<Vec<_>>();
+            assert_eq!(windows.iter().map(|x| x.1).collect::<Vec<_>>(), expected);
+            for (offset, window) in windows {
+                assert_eq!(&src[offset..offset + window.len()], window);
+            }
+        }
+        let windows = grapheme_windows_indices(&src, 3).map(|x| x.0).collect::<Vec<_>>();
+        assert_eq!(windows, [0, 1, 2, 27, 30, 33, 36]);
+        assert_eq!(grapheme_windows_indices(&src, usize::MAX).next(), None);
+    }
+
+    #[test]
+    fn chunks_test() {
+        // thumbs up and waving hand with skin tones
+        let src = "👍🏽👋🏿x👍🏻\u{200d}";
+        let chunks = grapheme_chunks(src, 2).collect::<Vec<_>>();
+        assert_eq!(chunks, ["👍🏽👋🏿", "x👍🏻\u{200d}"]);
+        let chunks = grapheme_chunks(src, 3).collect::<Vec<_>>();
+        assert_eq!(chunks, ["👍🏽👋🏿x", "👍🏻\u{200d}"]);
+        let iter = grapheme_chunks(src, 3);
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.collect::<String>(), src);
+        assert_eq!(grapheme_chunks("", 2).next(), None);
+        assert_eq!(grapheme_chunks(src, usize::MAX).collect::<Vec<_>>(), [src]);
+
+        let mut iter = grapheme_chunks_exact(src, 3);
+        assert_eq!(iter.len(), 1);
+        assert_eq!(iter.remainder(), "👍🏻\u{200d}");
+        assert_eq!(iter.next(), Some("👍🏽👋🏿x"));
+        assert_eq!(iter.next(), None);
+        let iter = grapheme_chunks_exact(src, 2);
+        assert_eq!(iter.remainder(), "");
+        assert_eq!(iter.collect::<Vec<_>>(), ["👍🏽👋🏿", "x👍🏻\u{200d}"]);
+        assert_eq!(grapheme_chunks_exact(src, usize::MAX).remainder(), src);
+    }
+
+    #[test]
+    fn chunks_bytes_test() {
+        // an astronaut with a skin tone, 15 bytes
+        let astronaut = "👩🏽\u{200d}🚀";
+        let src = format!("ab{}c{}", astronaut, FAMILY);
+        let error = grapheme_chunks_bytes(&src, 14).unwrap_err();
+        assert_eq!((error.offset(), error.cluster_len()), (2, 15));
+        let message = "grapheme cluster of 15 bytes at byte 2 is longer than 14 bytes";
+        assert_eq!(error.to_string(), message);
+        let error = grapheme_chunks_bytes(&src, 24).unwrap_err();
+        assert_eq!((error.offset(), error.cluster_len()), (18, 25));
+
+        let chunks = grapheme_chunks_bytes(&src, 25).unwrap().collect::<Vec<_>>();
+        assert_eq!(chunks, [format!("ab{}c", astronaut), FAMILY.into()]);
+        // budgets ending exactly on a cluster boundary
+        let src = format!("ab{}cd", astronaut);
+        let chunks = grapheme_chunks_bytes(&src, 17).unwrap().collect::<Vec<_>>();
+        assert_eq!(chunks, [format!("ab{}", astronaut), "cd".into()]);
+        let chunks = grapheme_chunks_bytes(astronaut, 15).unwrap().collect::<Vec<_>>();
+        assert_eq!(chunks, [astronaut]);
+        let src = format!("{}{}ab", astronaut, astronaut);
+        let chunks = grapheme_chunks_bytes(&src, 30).unwrap().collect::<Vec<_>>();
+        assert_eq!(chunks, [format!("{}{}", astronaut, astronaut), "ab".into()]);
+        let chunks = grapheme_chunks_bytes(&src, 16).unwrap().collect::<Vec<_>>();
+        assert_eq!(chunks, [astronaut.into(), format!("{}a", astronaut), "b".into()]);
+        assert_eq!(grapheme_chunks_bytes("", 0).unwrap().next(), None);
+    }
+
+    #[test]
+    fn chunks_bytes_concat_test() {
+        let src = format!("a👍🏽b{}한국어 🇷🇸🇺🇸e\u{301}", FAMILY);
+        for max_bytes in 25..=40 {
+            let chunks = grapheme_chunks_bytes(&src, max_bytes).unwrap();
+            let (low, high) = chunks.size_hint();
+            let chunks = chunks.collect::<Vec<_>>();
+            assert!(low <= chunks.len() && chunks.len() <= high.unwrap());
+            assert_eq!(chunks.concat(), src);
+            for pair in chunks.windows(2) {
+                assert!(pair[0].len() <= max_bytes);
+                // the next cluster didn't fit
+                let next = grapheme_chunks(pair[1], 1).next().unwrap();
+                assert!(pair[0].len() + next.len() > max_bytes);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk size must be non-zero")]
+    fn zero_chunk_size() {
+        grapheme_chunks("头尾都是", 0);
+    }
+}
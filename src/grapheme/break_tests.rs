@@ -0,0 +1,214 @@
+//! The tests of `GraphemeBreakTest.txt` of Unicode 14.0, generated by
+//! `scripts/grapheme_tables.py`.
+
+/// The strings of the tests, split into their grapheme clusters.
+pub(super) const BREAK_TESTS: &[&[&str]] = &[
+    &["\u{20}", "\u{20}"], &["\u{20}\u{308}", "\u{20}"], &["\u{20}", "\u{d}"],
+    &["\u{20}\u{308}", "\u{d}"], &["\u{20}", "\u{a}"], &["\u{20}\u{308}", "\u{a}"],
+    &["\u{20}", "\u{1}"], &["\u{20}\u{308}", "\u{1}"], &["\u{20}\u{34f}"],
+    &["\u{20}\u{308}\u{34f}"], &["\u{20}", "\u{1f1e6}"], &["\u{20}\u{308}", "\u{1f1e6}"],
+    &["\u{20}", "\u{600}"], &["\u{20}\u{308}", "\u{600}"], &["\u{20}\u{903}"],
+    &["\u{20}\u{308}\u{903}"], &["\u{20}", "\u{1100}"], &["\u{20}\u{308}", "\u{1100}"],
+    &["\u{20}", "\u{1160}"], &["\u{20}\u{308}", "\u{1160}"], &["\u{20}", "\u{11a8}"],
+    &["\u{20}\u{308}", "\u{11a8}"], &["\u{20}", "\u{ac00}"], &["\u{20}\u{308}", "\u{ac00}"],
+    &["\u{20}", "\u{ac01}"], &["\u{20}\u{308}", "\u{ac01}"], &["\u{20}", "\u{231a}"],
+    &["\u{20}\u{308}", "\u{231a}"], &["\u{20}\u{300}"], &["\u{20}\u{308}\u{300}"],
+    &["\u{20}\u{200d}"], &["\u{20}\u{308}\u{200d}"], &["\u{20}", "\u{378}"],
+    &["\u{20}\u{308}", "\u{378}"], &["\u{d}", "\u{20}"], &["\u{d}", "\u{308}", "\u{20}"],
+    &["\u{d}", "\u{d}"], &["\u{d}", "\u{308}", "\u{d}"], &["\u{d}\u{a}"],
+    &["\u{d}", "\u{308}", "\u{a}"], &["\u{d}", "\u{1}"], &["\u{d}", "\u{308}", "\u{1}"],
+    &["\u{d}", "\u{34f}"], &["\u{d}", "\u{308}\u{34f}"], &["\u{d}", "\u{1f1e6}"],
+    &["\u{d}", "\u{308}", "\u{1f1e6}"], &["\u{d}", "\u{600}"], &["\u{d}", "\u{308}", "\u{600}"],
+    &["\u{d}", "\u{903}"], &["\u{d}", "\u{308}\u{903}"], &["\u{d}", "\u{1100}"],
+    &["\u{d}", "\u{308}", "\u{1100}"], &["\u{d}", "\u{1160}"], &["\u{d}", "\u{308}", "\u{1160}"],
+    &["\u{d}", "\u{11a8}"], &["\u{d}", "\u{308}", "\u{11a8}"], &["\u{d}", "\u{ac00}"],
+    &["\u{d}", "\u{308}", "\u{ac00}"], &["\u{d}", "\u{ac01}"], &["\u{d}", "\u{308}", "\u{ac01}"],
+    &["\u{d}", "\u{231a}"], &["\u{d}", "\u{308}", "\u{231a}"], &["\u{d}", "\u{300}"],
+    &["\u{d}", "\u{308}\u{300}"], &["\u{d}", "\u{200d}"], &["\u{d}", "\u{308}\u{200d}"],
+    &["\u{d}", "\u{378}"], &["\u{d}", "\u{308}", "\u{378}"], &["\u{a}", "\u{20}"],
+    &["\u{a}", "\u{308}", "\u{20}"], &["\u{a}", "\u{d}"], &["\u{a}", "\u{308}", "\u{d}"],
+    &["\u{a}", "\u{a}"], &["\u{a}", "\u{308}", "\u{a}"], &["\u{a}", "\u{1}"],
+    &["\u{a}", "\u{308}", "\u{1}"], &["\u{a}", "\u{34f}"], &["\u{a}", "\u{308}\u{34f}"],
+    &["\u{a}", "\u{1f1e6}"], &["\u{a}", "\u{308}", "\u{1f1e6}"], &["\u{a}", "\u{600}"],
+    &["\u{a}", "\u{308}", "\u{600}"], &["\u{a}", "\u{903}"], &["\u{a}", "\u{308}\u{903}"],
+    &["\u{a}", "\u{1100}"], &["\u{a}", "\u{308}", "\u{1100}"], &["\u{a}", "\u{1160}"],
+    &["\u{a}", "\u{308}", "\u{1160}"], &["\u{a}", "\u{11a8}"], &["\u{a}", "\u{308}", "\u{11a8}"],
+    &["\u{a}", "\u{ac00}"], &["\u{a}", "\u{308}", "\u{ac00}"], &["\u{a}", "\u{ac01}"],
+    &["\u{a}", "\u{308}", "\u{ac01}"], &["\u{a}", "\u{231a}"], &["\u{a}", "\u{308}", "\u{231a}"],
+    &["\u{a}", "\u{300}"], &["\u{a}", "\u{308}\u{300}"], &["\u{a}", "\u{200d}"],
+    &["\u{a}", "\u{308}\u{200d}"], &["\u{a}", "\u{378}"], &["\u{a}", "\u{308}", "\u{378}"],
+    &["\u{1}", "\u{20}"], &["\u{1}", "\u{308}", "\u{20}"], &["\u{1}", "\u{d}"],
+    &["\u{1}", "\u{308}", "\u{d}"], &["\u{1}", "\u{a}"], &["\u{1}", "\u{308}", "\u{a}"],
+    &["\u{1}", "\u{1}"], &["\u{1}", "\u{308}", "\u{1}"], &["\u{1}", "\u{34f}"],
+    &["\u{1}", "\u{308}\u{34f}"], &["\u{1}", "\u{1f1e6}"], &["\u{1}", "\u{308}", "\u{1f1e6}"],
+    &["\u{1}", "\u{600}"], &["\u{1}", "\u{308}", "\u{600}"], &["\u{1}", "\u{903}"],
+    &["\u{1}", "\u{308}\u{903}"], &["\u{1}", "\u{1100}"], &["\u{1}", "\u{308}", "\u{1100}"],
+    &["\u{1}", "\u{1160}"], &["\u{1}", "\u{308}", "\u{1160}"], &["\u{1}", "\u{11a8}"],
+    &["\u{1}", "\u{308}", "\u{11a8}"], &["\u{1}", "\u{ac00}"], &["\u{1}", "\u{308}", "\u{ac00}"],
+    &["\u{1}", "\u{ac01}"], &["\u{1}", "\u{308}", "\u{ac01}"], &["\u{1}", "\u{231a}"],
+    &["\u{1}", "\u{308}", "\u{231a}"], &["\u{1}", "\u{300}"], &["\u{1}", "\u{308}\u{300}"],
+    &["\u{1}", "\u{200d}"], &["\u{1}", "\u{308}\u{200d}"], &["\u{1}", "\u{378}"],
+    &["\u{1}", "\u{308}", "\u{378}"], &["\u{34f}", "\u{20}"], &["\u{34f}\u{308}", "\u{20}"],
+    &["\u{34f}", "\u{d}"], &["\u{34f}\u{308}", "\u{d}"], &["\u{34f}", "\u{a}"],
+    &["\u{34f}\u{308}", "\u{a}"], &["\u{34f}", "\u{1}"], &["\u{34f}\u{308}", "\u{1}"],
+    &["\u{34f}\u{34f}"], &["\u{34f}\u{308}\u{34f}"], &["\u{34f}", "\u{1f1e6}"],
+    &["\u{34f}\u{308}", "\u{1f1e6}"], &["\u{34f}", "\u{600}"], &["\u{34f}\u{308}", "\u{600}"],
+    &["\u{34f}\u{903}"], &["\u{34f}\u{308}\u{903}"], &["\u{34f}", "\u{1100}"],
+    &["\u{34f}\u{308}", "\u{1100}"], &["\u{34f}", "\u{1160}"], &["\u{34f}\u{308}", "\u{1160}"],
+    &["\u{34f}", "\u{11a8}"], &["\u{34f}\u{308}", "\u{11a8}"], &["\u{34f}", "\u{ac00}"],
+    &["\u{34f}\u{308}", "\u{ac00}"], &["\u{34f}", "\u{ac01}"], &["\u{34f}\u{308}", "\u{ac01}"],
+    &["\u{34f}", "\u{231a}"], &["\u{34f}\u{308}", "\u{231a}"], &["\u{34f}\u{300}"],
+    &["\u{34f}\u{308}\u{300}"], &["\u{34f}\u{200d}"], &["\u{34f}\u{308}\u{200d}"],
+    &["\u{34f}", "\u{378}"], &["\u{34f}\u{308}", "\u{378}"], &["\u{1f1e6}", "\u{20}"],
+    &["\u{1f1e6}\u{308}", "\u{20}"], &["\u{1f1e6}", "\u{d}"], &["\u{1f1e6}\u{308}", "\u{d}"],
+    &["\u{1f1e6}", "\u{a}"], &["\u{1f1e6}\u{308}", "\u{a}"], &["\u{1f1e6}", "\u{1}"],
+    &["\u{1f1e6}\u{308}", "\u{1}"], &["\u{1f1e6}\u{34f}"], &["\u{1f1e6}\u{308}\u{34f}"],
+    &["\u{1f1e6}\u{1f1e6}"], &["\u{1f1e6}\u{308}", "\u{1f1e6}"], &["\u{1f1e6}", "\u{600}"],
+    &["\u{1f1e6}\u{308}", "\u{600}"], &["\u{1f1e6}\u{903}"], &["\u{1f1e6}\u{308}\u{903}"],
+    &["\u{1f1e6}", "\u{1100}"], &["\u{1f1e6}\u{308}", "\u{1100}"], &["\u{1f1e6}", "\u{1160}"],
+    &["\u{1f1e6}\u{308}", "\u{1160}"], &["\u{1f1e6}", "\u{11a8}"],
+    &["\u{1f1e6}\u{308}", "\u{11a8}"], &["\u{1f1e6}", "\u{ac00}"],
+    &["\u{1f1e6}\u{308}", "\u{ac00}"], &["\u{1f1e6}", "\u{ac01}"],
+    &["\u{1f1e6}\u{308}", "\u{ac01}"], &["\u{1f1e6}", "\u{231a}"],
+    &["\u{1f1e6}\u{308}", "\u{231a}"], &["\u{1f1e6}\u{300}"], &["\u{1f1e6}\u{308}\u{300}"],
+    &["\u{1f1e6}\u{200d}"], &["\u{1f1e6}\u{308}\u{200d}"], &["\u{1f1e6}", "\u{378}"],
+    &["\u{1f1e6}\u{308}", "\u{378}"], &["\u{600}\u{20}"], &["\u{600}\u{308}", "\u{20}"],
+    &["\u{600}", "\u{d}"], &["\u{600}\u{308}", "\u{d}"], &["\u{600}", "\u{a}"],
+    &["\u{600}\u{308}", "\u{a}"], &["\u{600}", "\u{1}"], &["\u{600}\u{308}", "\u{1}"],
+    &["\u{600}\u{34f}"], &["\u{600}\u{308}\u{34f}"], &["\u{600}\u{1f1e6}"],
+    &["\u{600}\u{308}", "\u{1f1e6}"], &["\u{600}\u{600}"], &["\u{600}\u{308}", "\u{600}"],
+    &["\u{600}\u{903}"], &["\u{600}\u{308}\u{903}"], &["\u{600}\u{1100}"],
+    &["\u{600}\u{308}", "\u{1100}"], &["\u{600}\u{1160}"], &["\u{600}\u{308}", "\u{1160}"],
+    &["\u{600}\u{11a8}"], &["\u{600}\u{308}", "\u{11a8}"], &["\u{600}\u{ac00}"],
+    &["\u{600}\u{308}", "\u{ac00}"], &["\u{600}\u{ac01}"], &["\u{600}\u{308}", "\u{ac01}"],
+    &["\u{600}\u{231a}"], &["\u{600}\u{308}", "\u{231a}"], &["\u{600}\u{300}"],
+    &["\u{600}\u{308}\u{300}"], &["\u{600}\u{200d}"], &["\u{600}\u{308}\u{200d}"],
+    &["\u{600}\u{378}"], &["\u{600}\u{308}", "\u{378}"], &["\u{903}", "\u{20}"],
+    &["\u{903}\u{308}", "\u{20}"], &["\u{903}", "\u{d}"], &["\u{903}\u{308}", "\u{d}"],
+    &["\u{903}", "\u{a}"], &["\u{903}\u{308}", "\u{a}"], &["\u{903}", "\u{1}"],
+    &["\u{903}\u{308}", "\u{1}"], &["\u{903}\u{34f}"], &["\u{903}\u{308}\u{34f}"],
+    &["\u{903}", "\u{1f1e6}"], &["\u{903}\u{308}", "\u{1f1e6}"], &["\u{903}", "\u{600}"],
+    &["\u{903}\u{308}", "\u{600}"], &["\u{903}\u{903}"], &["\u{903}\u{308}\u{903}"],
+    &["\u{903}", "\u{1100}"], &["\u{903}\u{308}", "\u{1100}"], &["\u{903}", "\u{1160}"],
+    &["\u{903}\u{308}", "\u{1160}"], &["\u{903}", "\u{11a8}"], &["\u{903}\u{308}", "\u{11a8}"],
+    &["\u{903}", "\u{ac00}"], &["\u{903}\u{308}", "\u{ac00}"], &["\u{903}", "\u{ac01}"],
+    &["\u{903}\u{308}", "\u{ac01}"], &["\u{903}", "\u{231a}"], &["\u{903}\u{308}", "\u{231a}"],
+    &["\u{903}\u{300}"], &["\u{903}\u{308}\u{300}"], &["\u{903}\u{200d}"],
+    &["\u{903}\u{308}\u{200d}"], &["\u{903}", "\u{378}"], &["\u{903}\u{308}", "\u{378}"],
+    &["\u{1100}", "\u{20}"], &["\u{1100}\u{308}", "\u{20}"], &["\u{1100}", "\u{d}"],
+    &["\u{1100}\u{308}", "\u{d}"], &["\u{1100}", "\u{a}"], &["\u{1100}\u{308}", "\u{a}"],
+    &["\u{1100}", "\u{1}"], &["\u{1100}\u{308}", "\u{1}"], &["\u{1100}\u{34f}"],
+    &["\u{1100}\u{308}\u{34f}"], &["\u{1100}", "\u{1f1e6}"], &["\u{1100}\u{308}", "\u{1f1e6}"],
+    &["\u{1100}", "\u{600}"], &["\u{1100}\u{308}", "\u{600}"], &["\u{1100}\u{903}"],
+    &["\u{1100}\u{308}\u{903}"], &["\u{1100}\u{1100}"], &["\u{1100}\u{308}", "\u{1100}"],
+    &["\u{1100}\u{1160}"], &["\u{1100}\u{308}", "\u{1160}"], &["\u{1100}", "\u{11a8}"],
+    &["\u{1100}\u{308}", "\u{11a8}"], &["\u{1100}\u{ac00}"], &["\u{1100}\u{308}", "\u{ac00}"],
+    &["\u{1100}\u{ac01}"], &["\u{1100}\u{308}", "\u{ac01}"], &["\u{1100}", "\u{231a}"],
+    &["\u{1100}\u{308}", "\u{231a}"], &["\u{1100}\u{300}"], &["\u{1100}\u{308}\u{300}"],
+    &["\u{1100}\u{200d}"], &["\u{1100}\u{308}\u{200d}"], &["\u{1100}", "\u{378}"],
+    &["\u{1100}\u{308}", "\u{378}"], &["\u{1160}", "\u{20}"], &["\u{1160}\u{308}", "\u{20}"],
+    &["\u{1160}", "\u{d}"], &["\u{1160}\u{308}", "\u{d}"], &["\u{1160}", "\u{a}"],
+    &["\u{1160}\u{308}", "\u{a}"], &["\u{1160}", "\u{1}"], &["\u{1160}\u{308}", "\u{1}"],
+    &["\u{1160}\u{34f}"], &["\u{1160}\u{308}\u{34f}"], &["\u{1160}", "\u{1f1e6}"],
+    &["\u{1160}\u{308}", "\u{1f1e6}"], &["\u{1160}", "\u{600}"], &["\u{1160}\u{308}", "\u{600}"],
+    &["\u{1160}\u{903}"], &["\u{1160}\u{308}\u{903}"], &["\u{1160}", "\u{1100}"],
+    &["\u{1160}\u{308}", "\u{1100}"], &["\u{1160}\u{1160}"], &["\u{1160}\u{308}", "\u{1160}"],
+    &["\u{1160}\u{11a8}"], &["\u{1160}\u{308}", "\u{11a8}"], &["\u{1160}", "\u{ac00}"],
+    &["\u{1160}\u{308}", "\u{ac00}"], &["\u{1160}", "\u{ac01}"], &["\u{1160}\u{308}", "\u{ac01}"],
+    &["\u{1160}", "\u{231a}"], &["\u{1160}\u{308}", "\u{231a}"], &["\u{1160}\u{300}"],
+    &["\u{1160}\u{308}\u{300}"], &["\u{1160}\u{200d}"], &["\u{1160}\u{308}\u{200d}"],
+    &["\u{1160}", "\u{378}"], &["\u{1160}\u{308}", "\u{378}"], &["\u{11a8}", "\u{20}"],
+    &["\u{11a8}\u{308}", "\u{20}"], &["\u{11a8}", "\u{d}"], &["\u{11a8}\u{308}", "\u{d}"],
+    &["\u{11a8}", "\u{a}"], &["\u{11a8}\u{308}", "\u{a}"], &["\u{11a8}", "\u{1}"],
+    &["\u{11a8}\u{308}", "\u{1}"], &["\u{11a8}\u{34f}"], &["\u{11a8}\u{308}\u{34f}"],
+    &["\u{11a8}", "\u{1f1e6}"], &["\u{11a8}\u{308}", "\u{1f1e6}"], &["\u{11a8}", "\u{600}"],
+    &["\u{11a8}\u{308}", "\u{600}"], &["\u{11a8}\u{903}"], &["\u{11a8}\u{308}\u{903}"],
+    &["\u{11a8}", "\u{1100}"], &["\u{11a8}\u{308}", "\u{1100}"], &["\u{11a8}", "\u{1160}"],
+    &["\u{11a8}\u{308}", "\u{1160}"], &["\u{11a8}\u{11a8}"], &["\u{11a8}\u{308}", "\u{11a8}"],
+    &["\u{11a8}", "\u{ac00}"], &["\u{11a8}\u{308}", "\u{ac00}"], &["\u{11a8}", "\u{ac01}"],
+    &["\u{11a8}\u{308}", "\u{ac01}"], &["\u{11a8}", "\u{231a}"], &["\u{11a8}\u{308}", "\u{231a}"],
+    &["\u{11a8}\u{300}"], &["\u{11a8}\u{308}\u{300}"], &["\u{11a8}\u{200d}"],
+    &["\u{11a8}\u{308}\u{200d}"], &["\u{11a8}", "\u{378}"], &["\u{11a8}\u{308}", "\u{378}"],
+    &["\u{ac00}", "\u{20}"], &["\u{ac00}\u{308}", "\u{20}"], &["\u{ac00}", "\u{d}"],
+    &["\u{ac00}\u{308}", "\u{d}"], &["\u{ac00}", "\u{a}"], &["\u{ac00}\u{308}", "\u{a}"],
+    &["\u{ac00}", "\u{1}"], &["\u{ac00}\u{308}", "\u{1}"], &["\u{ac00}\u{34f}"],
+    &["\u{ac00}\u{308}\u{34f}"], &["\u{ac00}", "\u{1f1e6}"], &["\u{ac00}\u{308}", "\u{1f1e6}"],
+    &["\u{ac00}", "\u{600}"], &["\u{ac00}\u{308}", "\u{600}"], &["\u{ac00}\u{903}"],
+    &["\u{ac00}\u{308}\u{903}"], &["\u{ac00}", "\u{1100}"], &["\u{ac00}\u{308}", "\u{1100}"],
+    &["\u{ac00}\u{1160}"], &["\u{ac00}\u{308}", "\u{1160}"], &["\u{ac00}\u{11a8}"],
+    &["\u{ac00}\u{308}", "\u{11a8}"], &["\u{ac00}", "\u{ac00}"], &["\u{ac00}\u{308}", "\u{ac00}"],
+    &["\u{ac00}", "\u{ac01}"], &["\u{ac00}\u{308}", "\u{ac01}"], &["\u{ac00}", "\u{231a}"],
+    &["\u{ac00}\u{308}", "\u{231a}"], &["\u{ac00}\u{300}"], &["\u{ac00}\u{308}\u{300}"],
+    &["\u{ac00}\u{200d}"], &["\u{ac00}\u{308}\u{200d}"], &["\u{ac00}", "\u{378}"],
+    &["\u{ac00}\u{308}", "\u{378}"], &["\u{ac01}", "\u{20}"], &["\u{ac01}\u{308}", "\u{20}"],
+    &["\u{ac01}", "\u{d}"], &["\u{ac01}\u{308}", "\u{d}"], &["\u{ac01}", "\u{a}"],
+    &["\u{ac01}\u{308}", "\u{a}"], &["\u{ac01}", "\u{1}"], &["\u{ac01}\u{308}", "\u{1}"],
+    &["\u{ac01}\u{34f}"], &["\u{ac01}\u{308}\u{34f}"], &["\u{ac01}", "\u{1f1e6}"],
+    &["\u{ac01}\u{308}", "\u{1f1e6}"], &["\u{ac01}", "\u{600}"], &["\u{ac01}\u{308}", "\u{600}"],
+    &["\u{ac01}\u{903}"], &["\u{ac01}\u{308}\u{903}"], &["\u{ac01}", "\u{1100}"],
+    &["\u{ac01}\u{308}", "\u{1100}"], &["\u{ac01}", "\u{1160}"], &["\u{ac01}\u{308}", "\u{1160}"],
+    &["\u{ac01}\u{11a8}"], &["\u{ac01}\u{308}", "\u{11a8}"], &["\u{ac01}", "\u{ac00}"],
+    &["\u{ac01}\u{308}", "\u{ac00}"], &["\u{ac01}", "\u{ac01}"], &["\u{ac01}\u{308}", "\u{ac01}"],
+    &["\u{ac01}", "\u{231a}"], &["\u{ac01}\u{308}", "\u{231a}"], &["\u{ac01}\u{300}"],
+    &["\u{ac01}\u{308}\u{300}"], &["\u{ac01}\u{200d}"], &["\u{ac01}\u{308}\u{200d}"],
+    &["\u{ac01}", "\u{378}"], &["\u{ac01}\u{308}", "\u{378}"], &["\u{231a}", "\u{20}"],
+    &["\u{231a}\u{308}", "\u{20}"], &["\u{231a}", "\u{d}"], &["\u{231a}\u{308}", "\u{d}"],
+    &["\u{231a}", "\u{a}"], &["\u{231a}\u{308}", "\u{a}"], &["\u{231a}", "\u{1}"],
+    &["\u{231a}\u{308}", "\u{1}"], &["\u{231a}\u{34f}"], &["\u{231a}\u{308}\u{34f}"],
+    &["\u{231a}", "\u{1f1e6}"], &["\u{231a}\u{308}", "\u{1f1e6}"], &["\u{231a}", "\u{600}"],
+    &["\u{231a}\u{308}", "\u{600}"], &["\u{231a}\u{903}"], &["\u{231a}\u{308}\u{903}"],
+    &["\u{231a}", "\u{1100}"], &["\u{231a}\u{308}", "\u{1100}"], &["\u{231a}", "\u{1160}"],
+    &["\u{231a}\u{308}", "\u{1160}"], &["\u{231a}", "\u{11a8}"], &["\u{231a}\u{308}", "\u{11a8}"],
+    &["\u{231a}", "\u{ac00}"], &["\u{231a}\u{308}", "\u{ac00}"], &["\u{231a}", "\u{ac01}"],
+    &["\u{231a}\u{308}", "\u{ac01}"], &["\u{231a}", "\u{231a}"], &["\u{231a}\u{308}", "\u{231a}"],
+    &["\u{231a}\u{300}"], &["\u{231a}\u{308}\u{300}"], &["\u{231a}\u{200d}"],
+    &["\u{231a}\u{308}\u{200d}"], &["\u{231a}", "\u{378}"], &["\u{231a}\u{308}", "\u{378}"],
+    &["\u{300}", "\u{20}"], &["\u{300}\u{308}", "\u{20}"], &["\u{300}", "\u{d}"],
+    &["\u{300}\u{308}", "\u{d}"], &["\u{300}", "\u{a}"], &["\u{300}\u{308}", "\u{a}"],
+    &["\u{300}", "\u{1}"], &["\u{300}\u{308}", "\u{1}"], &["\u{300}\u{34f}"],
+    &["\u{300}\u{308}\u{34f}"], &["\u{300}", "\u{1f1e6}"], &["\u{300}\u{308}", "\u{1f1e6}"],
+    &["\u{300}", "\u{600}"], &["\u{300}\u{308}", "\u{600}"], &["\u{300}\u{903}"],
+    &["\u{300}\u{308}\u{903}"], &["\u{300}", "\u{1100}"], &["\u{300}\u{308}", "\u{1100}"],
+    &["\u{300}", "\u{1160}"], &["\u{300}\u{308}", "\u{1160}"], &["\u{300}", "\u{11a8}"],
+    &["\u{300}\u{308}", "\u{11a8}"], &["\u{300}", "\u{ac00}"], &["\u{300}\u{308}", "\u{ac00}"],
+    &["\u{300}", "\u{ac01}"], &["\u{300}\u{308}", "\u{ac01}"], &["\u{300}", "\u{231a}"],
+    &["\u{300}\u{308}", "\u{231a}"], &["\u{300}\u{300}"], &["\u{300}\u{308}\u{300}"],
+    &["\u{300}\u{200d}"], &["\u{300}\u{308}\u{200d}"], &["\u{300}", "\u{378}"],
+    &["\u{300}\u{308}", "\u{378}"], &["\u{200d}", "\u{20}"], &["\u{200d}\u{308}", "\u{20}"],
+    &["\u{200d}", "\u{d}"], &["\u{200d}\u{308}", "\u{d}"], &["\u{200d}", "\u{a}"],
+    &["\u{200d}\u{308}", "\u{a}"], &["\u{200d}", "\u{1}"], &["\u{200d}\u{308}", "\u{1}"],
+    &["\u{200d}\u{34f}"], &["\u{200d}\u{308}\u{34f}"], &["\u{200d}", "\u{1f1e6}"],
+    &["\u{200d}\u{308}", "\u{1f1e6}"], &["\u{200d}", "\u{600}"], &["\u{200d}\u{308}", "\u{600}"],
+    &["\u{200d}\u{903}"], &["\u{200d}\u{308}\u{903}"], &["\u{200d}", "\u{1100}"],
+    &["\u{200d}\u{308}", "\u{1100}"], &["\u{200d}", "\u{1160}"], &["\u{200d}\u{308}", "\u{1160}"],
+    &["\u{200d}", "\u{11a8}"], &["\u{200d}\u{308}", "\u{11a8}"], &["\u{200d}", "\u{ac00}"],
+    &["\u{200d}\u{308}", "\u{ac00}"], &["\u{200d}", "\u{ac01}"], &["\u{200d}\u{308}", "\u{ac01}"],
+    &["\u{200d}", "\u{231a}"], &["\u{200d}\u{308}", "\u{231a}"], &["\u{200d}\u{300}"],
+    &["\u{200d}\u{308}\u{300}"], &["\u{200d}\u{200d}"], &["\u{200d}\u{308}\u{200d}"],
+    &["\u{200d}", "\u{378}"], &["\u{200d}\u{308}", "\u{378}"], &["\u{378}", "\u{20}"],
+    &["\u{378}\u{308}", "\u{20}"], &["\u{378}", "\u{d}"], &["\u{378}\u{308}", "\u{d}"],
+    &["\u{378}", "\u{a}"], &["\u{378}\u{308}", "\u{a}"], &["\u{378}", "\u{1}"],
+    &["\u{378}\u{308}", "\u{1}"], &["\u{378}\u{34f}"], &["\u{378}\u{308}\u{34f}"],
+    &["\u{378}", "\u{1f1e6}"], &["\u{378}\u{308}", "\u{1f1e6}"], &["\u{378}", "\u{600}"],
+    &["\u{378}\u{308}", "\u{600}"], &["\u{378}\u{903}"], &["\u{378}\u{308}\u{903}"],
+    &["\u{378}", "\u{1100}"], &["\u{378}\u{308}", "\u{1100}"], &["\u{378}", "\u{1160}"],
+    &["\u{378}\u{308}", "\u{1160}"], &["\u{378}", "\u{11a8}"], &["\u{378}\u{308}", "\u{11a8}"],
+    &["\u{378}", "\u{ac00}"], &["\u{378}\u{308}", "\u{ac00}"], &["\u{378}", "\u{ac01}"],
+    &["\u{378}\u{308}", "\u{ac01}"], &["\u{378}", "\u{231a}"], &["\u{378}\u{308}", "\u{231a}"],
+    &["\u{378}\u{300}"], &["\u{378}\u{308}\u{300}"], &["\u{378}\u{200d}"],
+    &["\u{378}\u{308}\u{200d}"], &["\u{378}", "\u{378}"], &["\u{378}\u{308}", "\u{378}"],
+    &["\u{d}\u{a}", "\u{61}", "\u{a}", "\u{308}"], &["\u{61}\u{308}"],
+    &["\u{20}\u{200d}", "\u{646}"], &["\u{646}\u{200d}", "\u{20}"], &["\u{1100}\u{1100}"],
+    &["\u{ac00}\u{11a8}", "\u{1100}"], &["\u{ac01}\u{11a8}", "\u{1100}"],
+    &["\u{1f1e6}\u{1f1e7}", "\u{1f1e8}", "\u{62}"],
+    &["\u{61}", "\u{1f1e6}\u{1f1e7}", "\u{1f1e8}", "\u{62}"],
+    &["\u{61}", "\u{1f1e6}\u{1f1e7}\u{200d}", "\u{1f1e8}", "\u{62}"],
+    &["\u{61}", "\u{1f1e6}\u{200d}", "\u{1f1e7}\u{1f1e8}", "\u{62}"],
+    &["\u{61}", "\u{1f1e6}\u{1f1e7}", "\u{1f1e8}\u{1f1e9}", "\u{62}"], &["\u{61}\u{200d}"],
+    &["\u{61}\u{308}", "\u{62}"], &["\u{61}\u{903}", "\u{62}"], &["\u{61}", "\u{600}\u{62}"],
+    &["\u{1f476}\u{1f3ff}", "\u{1f476}"], &["\u{61}\u{1f3ff}", "\u{1f476}"],
+    &["\u{61}\u{1f3ff}", "\u{1f476}\u{200d}\u{1f6d1}"],
+    &["\u{1f476}\u{1f3ff}\u{308}\u{200d}\u{1f476}\u{1f3ff}"], &["\u{1f6d1}\u{200d}\u{1f6d1}"],
+    &["\u{61}\u{200d}", "\u{1f6d1}"], &["\u{2701}\u{200d}\u{2701}"],
+    &["\u{61}\u{200d}", "\u{2701}"],
+];
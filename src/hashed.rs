@@ -0,0 +1,224 @@
+use std::fmt;
+use std::iter::FusedIterator;
+
+use {str_windows_strict, StrWindowsNonZero};
+
+/// Returns the windows of `size` chars of [`str_windows`] with a rolling
+/// hash of each window, e.g. to find repeated windows as Rabin–Karp does.
+///
+/// The hash of a window of the chars `c0, c1, …, cn` is the polynomial
+/// `c0 * base^n + c1 * base^(n - 1) + … + cn` modulo `modulus`, taking each
+/// char as its code point. It only depends on the chars of the window, so
+/// equal windows have equal hashes wherever they are in the input. The hash
+/// of each window is updated from the previous one, by removing the char
+/// leaving it and adding the char entering it, rather than hashing its
+/// `size` chars again.
+///
+/// The base defaults to `0x110001`, one more than the greatest code point,
+/// and the modulus to the prime `2^61 - 1`, which can be changed with
+/// [`base`](StrWindowsHashed::base) and
+/// [`modulus`](StrWindowsHashed::modulus).
+///
+/// # Panics
+///
+/// Panics if `size` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::str_windows_hashed;
+///
+/// let windows = str_windows_hashed("头尾头尾", 2).collect::<Vec<_>>();
+/// assert_eq!(windows[0].1, "头尾");
+/// assert_eq!(windows[0].0, windows[2].0);
+/// assert_ne!(windows[0].0, windows[1].0);
+///
+/// let hashes = str_windows_hashed("abc", 2).base(10).modulus(10_000).map(|x| x.0);
+/// assert_eq!(hashes.collect::<Vec<_>>(), [97 * 10 + 98, 98 * 10 + 99]);
+/// ```
+///
+/// [`str_windows`]: crate::str_windows
+pub fn str_windows_hashed(input: &str, size: usize) -> StrWindowsHashed<'_> {
+    StrWindowsHashed {
+        inner: str_windows_strict(input, size),
+        base: 0x11_0001,
+        modulus: (1 << 61) - 1,
+        power: 0,
+        last: None,
+    }
+}
+
+/// An iterator over the windows of `size` chars of a `&str` and their
+/// rolling hashes.
+///
+/// This struct is created by [`str_windows_hashed`].
+#[derive(Clone)]
+pub struct StrWindowsHashed<'a> {
+    inner: StrWindowsNonZero<'a>,
+    base: u64,
+    modulus: u64,
+    /// `base^(size - 1)` modulo `modulus`, the factor of the first char.
+    power: u64,
+    /// The hash and the first char of the last window yielded, if the next
+    /// hash can be updated from it.
+    last: Option<(u64, char)>,
+}
+
+impl<'a> StrWindowsHashed<'a> {
+    /// Sets the base of the hash polynomial. Defaults to `0x110001`.
+    ///
+    /// The next hashes are computed with it, so it's best set before the
+    /// first window.
+    pub fn base(mut self, base: u64) -> Self {
+        self.base = base;
+        self.last = None;
+        self
+    }
+
+    /// Sets the modulus of the hashes. Defaults to `2^61 - 1`.
+    ///
+    /// The next hashes are computed with it, so it's best set before the
+    /// first window.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is 0.
+    pub fn modulus(mut self, modulus: u64) -> Self {
+        assert!(modulus != 0, "modulus must be non-zero");
+        self.modulus = modulus;
+        self.last = None;
+        self
+    }
+
+    /// Returns `a * b` modulo the modulus.
+    fn mul(&self, a: u64, b: u64) -> u64 {
+        (a as u128 * b as u128 % self.modulus as u128) as u64
+    }
+
+    /// Returns `a + b` modulo the modulus.
+    fn add(&self, a: u64, b: u64) -> u64 {
+        ((a as u128 + b as u128) % self.modulus as u128) as u64
+    }
+
+    /// Returns `c` as a value below the modulus.
+    fn value(&self, c: char) -> u64 {
+        c as u64 % self.modulus
+    }
+
+    /// Hashes the chars of `window`, and computes the factor of its first
+    /// char for the next windows.
+    fn hash(&mut self, window: &str) -> u64 {
+        let base = self.base % self.modulus;
+        self.power = 1 % self.modulus;
+        let mut hash = 0;
+        for (i, c) in window.chars().enumerate() {
+            if i != 0 {
+                self.power = self.mul(self.power, base);
+            }
+            hash = self.add(self.mul(hash, base), self.value(c));
+        }
+        hash
+    }
+}
+
+impl<'a> fmt::Debug for StrWindowsHashed<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StrWindowsHashed")
+            .field("inner", &self.inner)
+            .field("base", &self.base)
+            .field("modulus", &self.modulus)
+            .finish()
+    }
+}
+
+impl<'a> FusedIterator for StrWindowsHashed<'a> { }
+
+impl<'a> Iterator for StrWindowsHashed<'a> {
+    type Item = (u64, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let window = self.inner.next()?;
+        let hash = match self.last {
+            Some((hash, leaving)) => {
+                // windows aren't empty
+                let entering = window.chars().next_back().unwrap();
+                let removed = self.modulus - self.mul(self.value(leaving), self.power);
+                let hash = self.add(hash, removed);
+                self.add(self.mul(hash, self.base % self.modulus), self.value(entering))
+            }
+            None => self.hash(window),
+        };
+        self.last = Some((hash, window.chars().next().unwrap()));
+        Some((hash, window))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a> ExactSizeIterator for StrWindowsHashed<'a> { }
+
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::str_windows_hashed;
+
+    /// Hashes `window` from scratch.
+    fn hash(window: &str, base: u64, modulus: u64) -> u64 {
+        let (base, modulus) = (base as u128, modulus as u128);
+        let hash = window.chars().fold(0, |hash, c| (hash * base + c as u128) % modulus);
+        hash as u64
+    }
+
+    #[test]
+    fn hashed_test() {
+        for &src in &["hello, 你好, hi", "头尾都是", "头部是!", ": 尾部是", "s 😀😁", ""] {
+            for size in 1..=6 {
+                let iter = str_windows_hashed(src, size);
+                assert_eq!(iter.len(), (src.chars().count() + 1).saturating_sub(size));
+                for (h, window) in iter {
+                    assert_eq!(h, hash(window, 0x11_0001, (1 << 61) - 1));
+                }
+                for (h, window) in str_windows_hashed(src, size).base(31).modulus(1_000_007) {
+                    assert_eq!(h, hash(window, 31, 1_000_007));
+                }
+                for (h, window) in str_windows_hashed(src, size).base(u64::MAX).modulus(97) {
+                    assert_eq!(h, hash(window, u64::MAX % 97, 97));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn equal_windows_test() {
+        let src = "abc头😀abc头😀ab头";
+        let mut hashes = HashMap::new();
+        for (h, window) in str_windows_hashed(src, 3) {
+            // equal windows collide, and the default hash has no collisions here
+            assert_eq!(*hashes.entry(window).or_insert(h), h);
+        }
+        let mut windows = hashes.iter().map(|(&window, &h)| (h, window)).collect::<Vec<_>>();
+        windows.sort();
+        windows.dedup_by_key(|x| x.0);
+        assert_eq!(windows.len(), hashes.len());
+        assert_eq!(hashes.len(), 6);
+    }
+
+    #[test]
+    fn modulus_test() {
+        let mut iter = str_windows_hashed("abcd", 2).modulus(7);
+        assert_eq!(iter.next(), Some((hash("ab", 0x11_0001, 7), "ab")));
+        let mut iter = iter.modulus(10_000).base(10);
+        assert_eq!(iter.next(), Some((98 * 10 + 99, "bc")));
+        assert_eq!(iter.next(), Some((99 * 10 + 100, "cd")));
+    }
+
+    #[test]
+    #[should_panic(expected = "modulus must be non-zero")]
+    fn zero_modulus() {
+        str_windows_hashed("头尾都是", 2).modulus(0);
+    }
+}
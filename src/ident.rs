@@ -0,0 +1,215 @@
+use std::iter::FusedIterator;
+
+/// The class of a char of an identifier segment.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Class {
+    Upper,
+    /// A lowercase letter, or a letter without case.
+    Lower,
+    Digit,
+}
+
+/// Returns the class of `c`, if it can be part of a segment.
+fn class(c: char) -> Option<Class> {
+    if c.is_numeric() {
+        Some(Class::Digit)
+    } else if c.is_uppercase() {
+        Some(Class::Upper)
+    } else if c.is_alphabetic() {
+        Some(Class::Lower)
+    } else {
+        None
+    }
+}
+
+/// Returns the byte range of the first identifier segment of `input` from
+/// `i`.
+///
+/// Segments are split by the chars that aren't letters or digits, like
+/// `'_'`, between a lowercase and an uppercase letter, between letters and
+/// digits, and before the last uppercase letter of an acronym followed by a
+/// lowercase one.
+fn next_segment(input: &str, i: usize) -> Option<(usize, usize)> {
+    let mut chars = input[i..].char_indices().map(|(j, c)| (i + j, class(c)));
+    let (start, mut prev) = chars.find_map(|(j, class)| class.map(|class| (j, class)))?;
+    let mut chars = chars.peekable();
+    while let Some(&(j, cur)) = chars.peek() {
+        chars.next();
+        let splits = match (prev, cur) {
+            (_, None) => true,
+            (Class::Digit, Some(cur)) | (_, Some(cur @ Class::Digit)) => cur != prev,
+            (Class::Lower, Some(Class::Upper)) => true,
+            (Class::Upper, Some(Class::Upper)) => {
+                matches!(chars.peek(), Some(&(_, Some(Class::Lower))))
+            }
+            _ => false,
+        };
+        if splits {
+            return Some((start, j));
+        }
+        prev = cur.unwrap();
+    }
+    Some((start, input.len()))
+}
+
+/// Returns the number of identifier segments of `input`.
+fn segment_count(input: &str) -> usize {
+    let mut count = 0;
+    let mut i = 0;
+    while let Some((_, end)) = next_segment(input, i) {
+        count += 1;
+        i = end;
+    }
+    count
+}
+
+/// Returns the windows of `n` consecutive segments of identifiers, each as
+/// the slice of the input from the start of its first segment to the end of
+/// its last one, with its byte offset, e.g. to index code by the words of
+/// its names.
+///
+/// The segments are the words of identifiers in camelCase, PascalCase,
+/// snake_case, SCREAMING_CASE or kebab-case: chars other than letters and
+/// digits separate them, a lowercase letter followed by an uppercase one
+/// ends a segment, and so do letters followed by digits and digits followed
+/// by letters. An acronym ends before its last uppercase letter if that
+/// starts a lowercase word, so `"HTTPServer"` has the segments `"HTTP"` and
+/// `"Server"`. Letters without case, like ideographs, are treated as
+/// lowercase. An input of fewer than `n` segments yields nothing.
+///
+/// # Panics
+///
+/// Panics if `n` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::ident_segment_windows;
+///
+/// let windows = ident_segment_windows("parseHTTPResponse", 1).collect::<Vec<_>>();
+/// assert_eq!(windows, [(0, "parse"), (5, "HTTP"), (9, "Response")]);
+///
+/// let windows = ident_segment_windows("parse_http_response", 2).collect::<Vec<_>>();
+/// assert_eq!(windows, [(0, "parse_http"), (6, "http_response")]);
+/// ```
+pub fn ident_segment_windows(input: &str, n: usize) -> IdentSegmentWindows<'_> {
+    assert!(n != 0, "window size must be non-zero");
+    let first = next_segment(input, 0);
+    let mut last = first;
+    for _ in 1..n {
+        last = match last {
+            Some((_, end)) => next_segment(input, end),
+            None => break,
+        };
+    }
+    IdentSegmentWindows {
+        input,
+        first: first.unwrap_or((0, 0)),
+        last: last.map_or(0, |x| x.1),
+        len: (segment_count(input) + 1).saturating_sub(n),
+    }
+}
+
+/// An iterator over the windows of `n` consecutive identifier segments of a
+/// `&str` and their byte offsets.
+///
+/// This struct is created by [`ident_segment_windows`].
+#[derive(Clone, Debug)]
+pub struct IdentSegmentWindows<'a> {
+    input: &'a str,
+    /// Byte range of the first segment of the next window.
+    first: (usize, usize),
+    /// End of the last segment of the next window.
+    last: usize,
+    /// Number of windows left to yield.
+    len: usize,
+}
+
+impl<'a> FusedIterator for IdentSegmentWindows<'a> { }
+
+impl<'a> Iterator for IdentSegmentWindows<'a> {
+    type Item = (usize, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        let window = (self.first.0, &self.input[self.first.0..self.last]);
+        if self.len != 0 {
+            // there are segments after both ends
+            self.first = next_segment(self.input, self.first.1).unwrap();
+            self.last = next_segment(self.input, self.last).unwrap().1;
+        }
+        Some(window)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a> ExactSizeIterator for IdentSegmentWindows<'a> { }
+
+
+#[cfg(test)]
+mod tests {
+    use super::ident_segment_windows;
+
+    fn segments(input: &str) -> Vec<&str> {
+        ident_segment_windows(input, 1).map(|x| x.1).collect()
+    }
+
+    #[test]
+    fn cases_test() {
+        assert_eq!(segments("parseHTTPResponse"), ["parse", "HTTP", "Response"]);
+        assert_eq!(segments("parse_http_response"), ["parse", "http", "response"]);
+        assert_eq!(segments("ParseHttpResponse"), ["Parse", "Http", "Response"]);
+        assert_eq!(segments("PARSE_HTTP_RESPONSE"), ["PARSE", "HTTP", "RESPONSE"]);
+        assert_eq!(segments("--parse-http--"), ["parse", "http"]);
+        assert_eq!(segments("utf8Decode2x"), ["utf", "8", "Decode", "2", "x"]);
+        assert_eq!(segments("__"), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn acronyms_test() {
+        assert_eq!(segments("HTTPServer"), ["HTTP", "Server"]);
+        assert_eq!(segments("getURL"), ["get", "URL"]);
+        assert_eq!(segments("XMLHttpRequest"), ["XML", "Http", "Request"]);
+        assert_eq!(segments("ABCs"), ["AB", "Cs"]);
+        assert_eq!(segments("IO"), ["IO"]);
+        assert_eq!(segments("A"), ["A"]);
+    }
+
+    #[test]
+    fn non_ascii_test() {
+        assert_eq!(segments("größeÄnderung"), ["größe", "Änderung"]);
+        assert_eq!(segments("ΣΥΝΟΛΟΚείμενο"), ["ΣΥΝΟΛΟ", "Κείμενο"]);
+        assert_eq!(segments("变量Name_值2"), ["变量", "Name", "值", "2"]);
+        let windows = ident_segment_windows("größeÄnderungStatus", 2).collect::<Vec<_>>();
+        assert_eq!(windows, [(0, "größeÄnderung"), (7, "ÄnderungStatus")]);
+    }
+
+    #[test]
+    fn windows_test() {
+        let src = "fn get_HTTPServer_v2()";
+        let windows = ident_segment_windows(src, 3).collect::<Vec<_>>();
+        assert_eq!(windows[..2], [(0, "fn get_HTTP"), (3, "get_HTTPServer")]);
+        assert_eq!(windows[2..], [(7, "HTTPServer_v"), (11, "Server_v2")]);
+        for n in 1..=6 {
+            let iter = ident_segment_windows(src, n);
+            assert_eq!(iter.len(), 7 - n);
+            for (offset, window) in iter {
+                assert!(src[offset..].starts_with(window));
+            }
+        }
+        assert_eq!(ident_segment_windows(src, 7).next(), None);
+        assert_eq!(ident_segment_windows(src, usize::MAX).next(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "window size must be non-zero")]
+    fn zero_size() {
+        ident_segment_windows("头尾都是", 0);
+    }
+}
@@ -0,0 +1,312 @@
+use std::iter::FusedIterator;
+use std::ops::Range;
+
+use {str_windows, StrWindows};
+
+/// Returns the windows of `size` chars of [`str_windows`] with the byte
+/// offset of each window in the input, like `char_indices` does for chars,
+/// e.g. to map matches found in the windows back to the input.
+///
+/// It supports the same iteration as [`str_windows`], from both ends, and a
+/// `size` of 0 likewise yields `(0, "")` forever.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::str_windows_indices;
+///
+/// let windows = str_windows_indices("a头尾😀", 2).collect::<Vec<_>>();
+/// assert_eq!(windows, [(0, "a头"), (1, "头尾"), (4, "尾😀")]);
+///
+/// let mut iter = str_windows_indices("a头尾😀", 2);
+/// assert_eq!(iter.next_back(), Some((4, "尾😀")));
+/// ```
+///
+/// [`str_windows`]: crate::str_windows
+pub fn str_windows_indices(input: &str, size: usize) -> StrWindowsIndices<'_> {
+    StrWindowsIndices {
+        inner: str_windows(input, size),
+    }
+}
+
+/// An iterator over the windows of `size` chars of a `&str` and their byte
+/// offsets.
+///
+/// This struct is created by [`str_windows_indices`].
+#[derive(Clone, Debug)]
+pub struct StrWindowsIndices<'a> {
+    inner: StrWindows<'a>,
+}
+
+impl<'a> StrWindowsIndices<'a> {
+    /// Pairs `window` with its byte offset in the input.
+    fn with_offset(&self, window: &'a str) -> (usize, &'a str) {
+        // the empty windows of size 0 aren't slices of the input
+        let offset = if self.inner.size == 0 {
+            self.inner.offset()
+        } else {
+            window.as_ptr() as usize - self.inner.source.as_ptr() as usize
+        };
+        (offset, window)
+    }
+}
+
+impl<'a> FusedIterator for StrWindowsIndices<'a> { }
+
+impl<'a> Iterator for StrWindowsIndices<'a> {
+    type Item = (usize, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|window| self.with_offset(window))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.inner.nth(n).map(|window| self.with_offset(window))
+    }
+}
+
+impl<'a> DoubleEndedIterator for StrWindowsIndices<'a> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|window| self.with_offset(window))
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.inner.nth_back(n).map(|window| self.with_offset(window))
+    }
+}
+
+/// Returns the byte ranges of the windows of `size` chars of
+/// [`str_windows`] in the input, e.g. to store windows in an index that
+/// doesn't borrow the input.
+///
+/// The ranges start and end at char boundaries, and slicing the input with
+/// them gives the windows of [`str_windows`], and like it a `size` of 0
+/// yields `0..0` forever.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::str_window_ranges;
+///
+/// let input = "a头尾😀";
+/// let ranges = str_window_ranges(input, 2).collect::<Vec<_>>();
+/// assert_eq!(ranges, [0..4, 1..7, 4..11]);
+/// assert_eq!(&input[ranges[2].clone()], "尾😀");
+/// ```
+///
+/// [`str_windows`]: crate::str_windows
+pub fn str_window_ranges(input: &str, size: usize) -> StrWindowRanges<'_> {
+    StrWindowRanges {
+        inner: str_windows_indices(input, size),
+    }
+}
+
+/// Returns the byte range of a window from its byte offset.
+fn range((offset, window): (usize, &str)) -> Range<usize> {
+    offset..offset + window.len()
+}
+
+/// An iterator over the byte ranges of the windows of `size` chars of a
+/// `&str`.
+///
+/// This struct is created by [`str_window_ranges`].
+#[derive(Clone, Debug)]
+pub struct StrWindowRanges<'a> {
+    inner: StrWindowsIndices<'a>,
+}
+
+impl<'a> FusedIterator for StrWindowRanges<'a> { }
+
+impl<'a> Iterator for StrWindowRanges<'a> {
+    type Item = Range<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(range)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.inner.nth(n).map(range)
+    }
+}
+
+impl<'a> DoubleEndedIterator for StrWindowRanges<'a> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(range)
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.inner.nth_back(n).map(range)
+    }
+}
+
+/// Returns the windows of `size` chars of [`str_windows`] with the char
+/// index of each window in the input, the number of chars before it, e.g.
+/// for APIs that index strings by chars.
+///
+/// The index is counted along with the windows, so skipping windows with
+/// `nth` or taking them from the back doesn't count the chars again. As with
+/// [`str_windows`], a `size` of 0 yields `(0, "")` forever.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::str_windows_char_indices;
+///
+/// let mut iter = str_windows_char_indices("a头尾😀!", 2);
+/// assert_eq!(iter.next(), Some((0, "a头")));
+/// assert_eq!(iter.nth(1), Some((2, "尾😀")));
+/// assert_eq!(iter.next_back(), Some((3, "😀!")));
+/// ```
+///
+/// [`str_windows`]: crate::str_windows
+pub fn str_windows_char_indices(input: &str, size: usize) -> StrWindowsCharIndices<'_> {
+    StrWindowsCharIndices {
+        inner: str_windows(input, size),
+        front: 0,
+    }
+}
+
+/// An iterator over the windows of `size` chars of a `&str` and their char
+/// indices.
+///
+/// This struct is created by [`str_windows_char_indices`].
+#[derive(Clone, Debug)]
+pub struct StrWindowsCharIndices<'a> {
+    inner: StrWindows<'a>,
+    /// Char index of the next window from the front.
+    front: usize,
+}
+
+impl<'a> FusedIterator for StrWindowsCharIndices<'a> { }
+
+impl<'a> Iterator for StrWindowsCharIndices<'a> {
+    type Item = (usize, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.nth(0)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let window = self.inner.nth(n)?;
+        // the empty windows of size 0 don't move
+        if self.inner.size == 0 {
+            return Some((self.front, window));
+        }
+        let index = self.front + n;
+        self.front = index + 1;
+        Some((index, window))
+    }
+}
+
+impl<'a> DoubleEndedIterator for StrWindowsCharIndices<'a> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.nth_back(0)
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let window = self.inner.nth_back(n)?;
+        if self.inner.size == 0 {
+            return Some((self.front, window));
+        }
+        // the windows left are those between the front and this one
+        Some((self.front + self.inner.len, window))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::{str_window_ranges, str_windows_char_indices, str_windows_indices};
+    use str_windows;
+
+    #[test]
+    fn indices_test() {
+        for &src in &["hello, 你好, hi", "头尾都是", "头部是!", ": 尾部是", "s 😀😁"] {
+            for size in 1..=6 {
+                let iter = str_windows_indices(src, size);
+                assert_eq!(iter.size_hint(), str_windows(src, size).size_hint());
+                let windows = iter.clone().map(|x| x.1).collect::<Vec<_>>();
+                assert_eq!(windows, str_windows(src, size).collect::<Vec<_>>());
+                for (i, window) in iter.clone() {
+                    assert_eq!(&src[i..i + window.len()], window);
+                }
+                let mut back = iter.rev().collect::<Vec<_>>();
+                back.reverse();
+                assert_eq!(back, str_windows_indices(src, size).collect::<Vec<_>>());
+            }
+        }
+    }
+
+    #[test]
+    fn nth_test() {
+        let mut iter = str_windows_indices("头尾都是😀", 2);
+        assert_eq!(iter.nth(1), Some((3, "尾都")));
+        assert_eq!(iter.nth_back(1), Some((6, "都是")));
+        assert_eq!(iter.next(), None);
+        let windows = str_windows_indices("头尾", 0).take(2).collect::<Vec<_>>();
+        assert_eq!(windows, [(0, ""), (0, "")]);
+    }
+
+    #[test]
+    fn ranges_test() {
+        for &src in &["hello, 你好, hi", "头尾都是", "头部是!", ": 尾部是", "s 😀😁"] {
+            for size in 1..=6 {
+                let iter = str_window_ranges(src, size);
+                assert_eq!(iter.size_hint(), str_windows(src, size).size_hint());
+                let windows = iter.clone().map(|range| {
+                    assert!(src.is_char_boundary(range.start) && src.is_char_boundary(range.end));
+                    &src[range]
+                });
+                assert!(windows.eq(str_windows(src, size)));
+                assert!(iter.rev().map(|range| &src[range]).eq(str_windows(src, size).rev()));
+            }
+        }
+        assert_eq!(str_window_ranges("头尾", 3).next(), None);
+        assert_eq!(str_window_ranges("头尾", 0).next(), Some(0..0));
+    }
+
+    #[test]
+    fn char_indices_test() {
+        for &src in &["hello, 你好, hi", "头尾都是", "头部是!", ": 尾部是", "s 😀😁"] {
+            let chars = src.char_indices().map(|x| x.0).collect::<Vec<_>>();
+            for size in 1..=6 {
+                let iter = str_windows_char_indices(src, size);
+                assert_eq!(iter.size_hint(), str_windows(src, size).size_hint());
+                for (i, window) in iter.clone() {
+                    assert!(src[chars[i]..].starts_with(window));
+                }
+                let mut back = iter.rev().collect::<Vec<_>>();
+                back.reverse();
+                assert_eq!(back, str_windows_char_indices(src, size).collect::<Vec<_>>());
+            }
+        }
+    }
+
+    #[test]
+    fn char_indices_nth_test() {
+        let src = "头尾都是😀s 😁";
+        let mut iter = str_windows_char_indices(src, 2);
+        assert_eq!(iter.nth(2), Some((2, "都是")));
+        assert_eq!(iter.nth_back(1), Some((5, "s ")));
+        assert_eq!(iter.next(), Some((3, "是😀")));
+        assert_eq!(iter.next_back(), Some((4, "😀s")));
+        assert_eq!(iter.next(), None);
+        let mut iter = str_windows_char_indices(src, 3);
+        assert_eq!(iter.nth(6), None);
+        assert_eq!(iter.next_back(), None);
+        let windows = str_windows_char_indices(src, 0).skip(3).take(2).collect::<Vec<_>>();
+        assert_eq!(windows, [(0, ""), (0, "")]);
+    }
+}
@@ -0,0 +1,147 @@
+use std::iter::FusedIterator;
+use std::ops::Range;
+
+use {str_windows_indices, StrWindowsIndices};
+
+/// A window of [`str_windows_info`] and where it is in the input.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct WindowInfo<'a> {
+    /// The window.
+    pub text: &'a str,
+    /// The byte range of the window in the input.
+    pub byte_range: Range<usize>,
+    /// The char range of the window in the input, the indices of its chars.
+    pub char_range: Range<usize>,
+    /// Whether it's the first window.
+    pub is_first: bool,
+    /// Whether it's the last window.
+    pub is_last: bool,
+}
+
+/// Returns the windows of `size` chars of [`str_windows`] with their byte and
+/// char ranges in the input and whether they are the first or the last one,
+/// e.g. to draw ellipses around a window shown alone.
+///
+/// The iterator stays lazy: knowing whether a window is the last one doesn't
+/// take looking ahead at the next window, since the number of windows is
+/// known up front.
+///
+/// # Panics
+///
+/// Panics if `size` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::str_windows_info;
+///
+/// let infos = str_windows_info("a头尾", 2).collect::<Vec<_>>();
+/// assert_eq!(infos[1].text, "头尾");
+/// assert_eq!(infos[1].byte_range, 1..7);
+/// assert_eq!(infos[1].char_range, 1..3);
+/// assert!(!infos[1].is_first && infos[1].is_last);
+/// ```
+///
+/// [`str_windows`]: crate::str_windows
+pub fn str_windows_info(input: &str, size: usize) -> StrWindowsInfo<'_> {
+    assert!(size != 0, "window size must be non-zero");
+    let inner = str_windows_indices(input, size);
+    StrWindowsInfo {
+        // exact, since the size isn't 0
+        windows: inner.size_hint().0,
+        inner,
+        size,
+        index: 0,
+    }
+}
+
+/// An iterator over the windows of `size` chars of a `&str` and their
+/// [`WindowInfo`].
+///
+/// This struct is created by [`str_windows_info`].
+#[derive(Clone, Debug)]
+pub struct StrWindowsInfo<'a> {
+    inner: StrWindowsIndices<'a>,
+    size: usize,
+    /// Number of windows of the input.
+    windows: usize,
+    /// Index of the next window, also the char index of its first char.
+    index: usize,
+}
+
+impl<'a> FusedIterator for StrWindowsInfo<'a> { }
+
+impl<'a> Iterator for StrWindowsInfo<'a> {
+    type Item = WindowInfo<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (offset, text) = self.inner.next()?;
+        let index = self.index;
+        self.index += 1;
+        Some(WindowInfo {
+            text,
+            byte_range: offset..offset + text.len(),
+            char_range: index..index + self.size,
+            is_first: index == 0,
+            is_last: self.index == self.windows,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a> ExactSizeIterator for StrWindowsInfo<'a> { }
+
+
+#[cfg(test)]
+mod tests {
+    use super::{str_windows_info, WindowInfo};
+
+    #[test]
+    fn info_test() {
+        let infos = str_windows_info("头😀a尾", 2).collect::<Vec<_>>();
+        let expected = [
+            WindowInfo {
+                text: "头😀",
+                byte_range: 0..7,
+                char_range: 0..2,
+                is_first: true,
+                is_last: false,
+            },
+            WindowInfo {
+                text: "😀a",
+                byte_range: 3..8,
+                char_range: 1..3,
+                is_first: false,
+                is_last: false,
+            },
+            WindowInfo {
+                text: "a尾",
+                byte_range: 7..11,
+                char_range: 2..4,
+                is_first: false,
+                is_last: true,
+            },
+        ];
+        assert_eq!(infos, expected);
+    }
+
+    #[test]
+    fn single_test() {
+        let mut iter = str_windows_info("头😀", 2);
+        assert_eq!(iter.len(), 1);
+        let info = iter.next().unwrap();
+        assert_eq!((info.text, info.byte_range, info.char_range), ("头😀", 0..7, 0..2));
+        assert!(info.is_first && info.is_last);
+        assert_eq!(iter.next(), None);
+        assert_eq!(str_windows_info("头😀", 3).next(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "window size must be non-zero")]
+    fn zero_size() {
+        str_windows_info("头尾都是", 0);
+    }
+}
@@ -0,0 +1,167 @@
+use std::collections::VecDeque;
+use std::iter::FusedIterator;
+use std::str::Chars;
+
+/// The compatibility jamo of the leading consonants of the syllables.
+const LEADING: [char; 19] = [
+    'ㄱ', 'ㄲ', 'ㄴ', 'ㄷ', 'ㄸ', 'ㄹ', 'ㅁ', 'ㅂ', 'ㅃ', 'ㅅ', 'ㅆ', 'ㅇ', 'ㅈ', 'ㅉ', 'ㅊ', 'ㅋ', 'ㅌ',
+    'ㅍ', 'ㅎ',
+];
+
+/// The compatibility jamo of the trailing consonants of the syllables.
+const TRAILING: [char; 27] = [
+    'ㄱ', 'ㄲ', 'ㄳ', 'ㄴ', 'ㄵ', 'ㄶ', 'ㄷ', 'ㄹ', 'ㄺ', 'ㄻ', 'ㄼ', 'ㄽ', 'ㄾ', 'ㄿ', 'ㅀ', 'ㅁ', 'ㅂ',
+    'ㅄ', 'ㅅ', 'ㅆ', 'ㅇ', 'ㅈ', 'ㅊ', 'ㅋ', 'ㅌ', 'ㅍ', 'ㅎ',
+];
+
+/// Returns the jamo of `c` if it's a precomposed Hangul syllable, otherwise
+/// `c` itself, and how many of them there are.
+fn decompose(c: char) -> ([char; 3], usize) {
+    let index = match (c as u32).checked_sub(0xac00) {
+        Some(index) if index < 11172 => index,
+        _ => return ([c, c, c], 1),
+    };
+    let leading = LEADING[(index / 588) as usize];
+    // the vowels are contiguous in the compatibility jamo
+    let vowel = char::from_u32(0x314f + index % 588 / 28).unwrap();
+    match index % 28 {
+        0 => ([leading, vowel, vowel], 2),
+        trailing => ([leading, vowel, TRAILING[trailing as usize - 1]], 3),
+    }
+}
+
+/// An iterator over the chars of a `&str`, with the Hangul syllables
+/// decomposed into jamo.
+#[derive(Clone, Debug)]
+struct Units<'a> {
+    chars: Chars<'a>,
+    /// The jamo of the current syllable left to yield, in reverse order.
+    pending: Vec<char>,
+}
+
+impl<'a> Iterator for Units<'a> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(c) = self.pending.pop() {
+            return Some(c);
+        }
+        let (jamo, len) = decompose(self.chars.next()?);
+        self.pending.extend(jamo[1..len].iter().rev());
+        Some(jamo[0])
+    }
+}
+
+/// Returns the windows of `size` units of the input, with its precomposed
+/// Hangul syllables decomposed into their jamo, e.g. to complete Korean text
+/// as it's typed: `"한국"` has the windows of the jamo `ㅎㅏㄴㄱㅜㄱ`.
+///
+/// A syllable is decomposed into the compatibility jamo of its leading
+/// consonant, its vowel and its trailing consonant if it has one, as typed
+/// on a keyboard, by arithmetic on its code point. Other chars, including
+/// jamo, are single units. Since the decomposed windows aren't slices of the
+/// input, each one is collected into a `String`.
+///
+/// This requires the `jamo` feature.
+///
+/// # Panics
+///
+/// Panics if `size` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::jamo_windows;
+///
+/// let windows = jamo_windows("한국", 4).collect::<Vec<_>>();
+/// assert_eq!(windows, ["ㅎㅏㄴㄱ", "ㅏㄴㄱㅜ", "ㄴㄱㅜㄱ"]);
+/// ```
+pub fn jamo_windows(input: &str, size: usize) -> JamoWindows<'_> {
+    assert!(size != 0, "window size must be non-zero");
+    let units = input.chars().map(|c| decompose(c).1).sum::<usize>();
+    JamoWindows {
+        units: Units {
+            chars: input.chars(),
+            pending: Vec::with_capacity(2),
+        },
+        // a window can't be longer than the input
+        window: VecDeque::with_capacity(size.min(units)),
+        size,
+        len: (units + 1).saturating_sub(size),
+    }
+}
+
+/// An iterator over the windows of `size` units of a `&str` with its Hangul
+/// syllables decomposed into jamo.
+///
+/// This struct is created by [`jamo_windows`].
+#[derive(Clone, Debug)]
+pub struct JamoWindows<'a> {
+    units: Units<'a>,
+    /// The units of the last window yielded.
+    window: VecDeque<char>,
+    size: usize,
+    /// Number of windows left to yield.
+    len: usize,
+}
+
+impl<'a> FusedIterator for JamoWindows<'a> { }
+
+impl<'a> Iterator for JamoWindows<'a> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        if self.window.len() == self.size {
+            self.window.pop_front();
+        }
+        while self.window.len() < self.size {
+            // there are enough units left for the window
+            self.window.push_back(self.units.next().unwrap());
+        }
+        Some(self.window.iter().collect())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a> ExactSizeIterator for JamoWindows<'a> { }
+
+
+#[cfg(test)]
+mod tests {
+    use super::jamo_windows;
+
+    #[test]
+    fn decompose_test() {
+        let units = jamo_windows("한국어 ok", 1).collect::<String>();
+        assert_eq!(units, "ㅎㅏㄴㄱㅜㄱㅇㅓ ok");
+        let units = jamo_windows("가힣값ㄱ", 1).collect::<String>();
+        assert_eq!(units, "ㄱㅏㅎㅣㅎㄱㅏㅄㄱ");
+    }
+
+    #[test]
+    fn windows_test() {
+        let windows = jamo_windows("a개b", 3).collect::<Vec<_>>();
+        assert_eq!(windows, ["aㄱㅐ", "ㄱㅐb"]);
+        let windows = jamo_windows("닭a", 4).collect::<Vec<_>>();
+        assert_eq!(windows, ["ㄷㅏㄺa"]);
+        let iter = jamo_windows("서울 Seoul", 3);
+        assert_eq!(iter.len(), 9);
+        assert_eq!(iter.last(), Some("oul".to_string()));
+        assert_eq!(jamo_windows("한", 4).next(), None);
+        assert_eq!(jamo_windows("한국", usize::MAX).next(), None);
+        assert_eq!(jamo_windows("", 1).next(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "window size must be non-zero")]
+    fn zero_size() {
+        jamo_windows("한국", 0);
+    }
+}
@@ -0,0 +1,91 @@
+use std::fmt;
+
+/// Extension methods on the iterators of windows of this crate, or any
+/// iterator of strings, to format them.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::{str_windows, JoinExt};
+///
+/// let joined = str_windows("头尾😀", 2).display_join(", ").to_string();
+/// assert_eq!(joined, "头尾, 尾😀");
+/// ```
+pub trait JoinExt: Iterator + Clone + Sized
+where
+    Self::Item: AsRef<str>,
+{
+    /// Returns a value displaying the items of the iterator separated by
+    /// `separator`, e.g. to print a list of windows.
+    ///
+    /// The items are written directly into the formatter, without collecting
+    /// them, from a clone of the iterator each time the value is formatted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use str_windows::{str_windows, JoinExt};
+    ///
+    /// let windows = str_windows("a头尾😀", 3);
+    /// assert_eq!(format!("[{}]", windows.display_join(" | ")), "[a头尾 | 头尾😀]");
+    /// ```
+    fn display_join(self, separator: &str) -> DisplayJoin<'_, Self> {
+        DisplayJoin {
+            iter: self,
+            separator,
+        }
+    }
+}
+
+impl<I> JoinExt for I
+where
+    I: Iterator + Clone,
+    I::Item: AsRef<str>,
+{
+}
+
+/// A value displaying the items of an iterator separated by a separator.
+///
+/// This struct is created by [`display_join`](JoinExt::display_join).
+#[derive(Clone, Debug)]
+pub struct DisplayJoin<'s, I> {
+    iter: I,
+    separator: &'s str,
+}
+
+impl<'s, I> fmt::Display for DisplayJoin<'s, I>
+where
+    I: Iterator + Clone,
+    I::Item: AsRef<str>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, item) in self.iter.clone().enumerate() {
+            if i != 0 {
+                f.write_str(self.separator)?;
+            }
+            f.write_str(item.as_ref())?;
+        }
+        Ok(())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::JoinExt;
+    use {str_chunks, str_windows, str_windows_owned};
+
+    #[test]
+    fn join_test() {
+        for &src in &["hello, 你好, hi", "头尾都是", "头部是!", ": 尾部是", "s 😀😁", ""] {
+            for size in 1..=6 {
+                let expected = str_windows(src, size).collect::<Vec<_>>().join("、");
+                assert_eq!(str_windows(src, size).display_join("、").to_string(), expected);
+            }
+        }
+        let owned = str_windows_owned("头尾😀".to_string(), 2);
+        assert_eq!(owned.display_join("").to_string(), "头尾尾😀");
+        assert_eq!(format!("<{}>", str_chunks("头尾😀", 2).display_join("|")), "<头尾|😀>");
+        assert_eq!(str_windows("头", 2).display_join(", ").to_string(), "");
+    }
+}
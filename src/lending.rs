@@ -0,0 +1,133 @@
+use std::iter::Fuse;
+
+/// Returns the windows of `size` chars of an iterator of chars, lent as
+/// `&str` views of a buffer, e.g. for chars decoded from a stream without
+/// collecting them into a `String`.
+///
+/// The windows are the same as those of [`str_windows`] over the collected
+/// chars. [`LendingWindows`] isn't an `Iterator`, since each window borrows
+/// the buffer until the next one: it's iterated with
+/// [`next`](LendingWindows::next) in a `while let` loop. The buffer holds a
+/// window and the chars that left it since the buffer was last compacted,
+/// which happens once they take more bytes than the window, so it doesn't
+/// grow past about twice the bytes of the largest window.
+///
+/// This requires the `lending` feature.
+///
+/// # Panics
+///
+/// Panics if `size` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::lending_windows;
+///
+/// let mut windows = lending_windows("a头尾😀".chars().rev(), 2);
+/// let mut lengths = Vec::new();
+/// while let Some(window) = windows.next() {
+///     lengths.push(window.len());
+/// }
+/// assert_eq!(lengths, [7, 6, 4]);
+/// ```
+///
+/// [`str_windows`]: crate::str_windows
+pub fn lending_windows<I>(chars: I, size: usize) -> LendingWindows<I::IntoIter>
+where
+    I: IntoIterator<Item = char>,
+{
+    assert!(size != 0, "window size must be non-zero");
+    LendingWindows {
+        chars: chars.into_iter().fuse(),
+        buffer: String::new(),
+        start: 0,
+        size,
+        started: false,
+    }
+}
+
+/// A lending iterator over the windows of `size` chars of an iterator of
+/// chars.
+///
+/// This struct is created by [`lending_windows`].
+#[derive(Clone, Debug)]
+pub struct LendingWindows<I> {
+    chars: Fuse<I>,
+    /// The chars that left the windows since the last compaction, then the
+    /// last window lent.
+    buffer: String,
+    /// Start of the last window lent in `buffer`.
+    start: usize,
+    size: usize,
+    /// Whether the first window was filled.
+    started: bool,
+}
+
+impl<I: Iterator<Item = char>> LendingWindows<I> {
+    /// Returns the next window, borrowed until the window after it.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<&str> {
+        if !self.started {
+            self.started = true;
+            self.buffer.extend(self.chars.by_ref().take(self.size));
+            if self.buffer.chars().count() < self.size {
+                // there's no window, and there won't be
+                self.buffer.clear();
+                return None;
+            }
+            return Some(&self.buffer);
+        }
+        if self.buffer.is_empty() {
+            return None;
+        }
+        let c = self.chars.next()?;
+        // windows aren't empty
+        let leaving = self.buffer[self.start..].chars().next().unwrap();
+        self.start += leaving.len_utf8();
+        self.buffer.push(c);
+        if self.start > self.buffer.len() - self.start {
+            self.buffer.drain(..self.start);
+            self.start = 0;
+        }
+        Some(&self.buffer[self.start..])
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::lending_windows;
+    use str_windows;
+
+    #[test]
+    fn lending_test() {
+        for &src in &["hello, 你好, hi", "头尾都是", "头部是!", ": 尾部是", "s 😀😁", ""] {
+            for size in 1..=6 {
+                let mut iter = lending_windows(src.chars(), size);
+                let mut windows = Vec::new();
+                while let Some(window) = iter.next() {
+                    windows.push(window.to_string());
+                }
+                assert!(windows.iter().map(String::as_str).eq(str_windows(src, size)));
+                assert_eq!(iter.next(), None);
+            }
+        }
+    }
+
+    #[test]
+    fn buffer_test() {
+        let src = "头a😀".repeat(100);
+        let mut iter = lending_windows(src.chars(), 3);
+        while let Some(window) = iter.next() {
+            assert_eq!(window.len(), 8);
+            assert!(iter.buffer.len() <= 2 * 8 + 4);
+        }
+        assert!(iter.buffer.capacity() < 64);
+    }
+
+    #[test]
+    #[should_panic(expected = "window size must be non-zero")]
+    fn zero_size() {
+        lending_windows("头尾都是".chars(), 0);
+    }
+}
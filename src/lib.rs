@@ -1,4 +1,172 @@
+//! Iterators over windows of chars (as `&str`s) of a `&str`.
+//!
+//! # Window size 0
+//!
+//! A window of 0 chars has no sensible end, so the constructors taking a
+//! window size panic when it's 0, like `slice::windows`, and say so under
+//! `# Panics`. The exceptions are documented next to each of them:
+//!
+//! - [`str_windows`] yields `Some("")` forever, and so do the iterators that
+//!   mirror it window for window: [`str_windows_in`],
+//!   [`str_windows_in_clipped`], [`str_windows_indices`],
+//!   [`str_window_ranges`], [`str_windows_char_indices`], [`Windows`] and the
+//!   grapheme windows.
+//! - [`try_str_windows`] returns a [`ZeroSizeError`].
+//! - [`str_windows_nonzero`] takes a `NonZeroUsize`, so the case can't occur.
+//! - [`StrWindows::builder`] fails to build with a [`BuildError`].
+
+use std::error::Error;
+use std::fmt;
 use std::iter::FusedIterator;
+use std::num::NonZeroUsize;
+use std::ops::Range;
+
+use boundary::{next_char_boundary, nth_char_boundary, nth_char_boundary_back, prev_char_boundary};
+pub use affix::{char_prefixes, char_suffixes, CharPrefixes, CharSuffixes};
+pub use align::{alignments, best_alignment, Alignments};
+pub use array::{char_array_windows, CharArrayWindows};
+#[cfg(feature = "ascii-folding")]
+pub use ascii_fold::{str_windows_ascii_folded, StrWindowsAsciiFolded};
+pub use builder::{BuildError, BuiltWindows, WindowsBuilder};
+pub use bytes::{
+    str_chunks_bytes, str_windows_bytes, CharTooLongError, StrChunksBytes, StrWindowsBytes,
+};
+pub use chunks::{
+    str_chunks, str_chunks_exact, str_rchunks, str_rchunks_exact, StrChunks, StrChunksExact,
+    StrRChunks, StrRChunksExact,
+};
+pub use collapse::{
+    str_windows_collapse_ws, str_windows_collapse_ws_indices, StrWindowsCollapseWs,
+    StrWindowsCollapseWsIndices,
+};
+pub use context::{
+    char_context, char_context_range, char_contexts, str_context_windows, CharContexts,
+    StrContextWindows,
+};
+pub use delta::{str_windows_delta, StrWindowsDelta, WindowDelta};
+pub use edge::{edge_ngrams, EdgeNgrams};
+pub use fold::{str_windows_folded, StrWindowsFolded};
+#[cfg(feature = "graphemes")]
+pub use grapheme::{
+    grapheme_chunks, grapheme_chunks_bytes, grapheme_chunks_exact, grapheme_windows,
+    grapheme_windows_indices, GraphemeBoundaries, GraphemeChunks, GraphemeChunksBytes,
+    GraphemeChunksExact, GraphemeTooLongError, GraphemeWindows, GraphemeWindowsIndices,
+};
+pub use hashed::{str_windows_hashed, StrWindowsHashed};
+pub use ident::{ident_segment_windows, IdentSegmentWindows};
+pub use indices::{
+    str_window_ranges, str_windows_char_indices, str_windows_indices, StrWindowRanges,
+    StrWindowsCharIndices, StrWindowsIndices,
+};
+pub use info::{str_windows_info, StrWindowsInfo, WindowInfo};
+#[cfg(feature = "jamo")]
+pub use jamo::{jamo_windows, JamoWindows};
+pub use join::{DisplayJoin, JoinExt};
+#[cfg(feature = "lending")]
+pub use lending::{lending_windows, LendingWindows};
+pub use lines::{
+    line_chunks, line_windows, paragraph_windows, LineChunks, LineWindows, ParagraphWindows,
+};
+pub use nonzero::StrWindowsNonZero;
+pub use owned::{str_windows_owned, OwnedStrWindows};
+pub use partial::{
+    str_windows_marked, str_windows_padded, str_windows_partial, str_windows_ramp,
+    str_windows_ramp_partial, StrWindowsMarked, StrWindowsPadded, StrWindowsPartial,
+};
+pub use position::{str_windows_positions, Position, StrWindowsPositions};
+pub use predicate::{
+    str_windows_filtered, str_windows_while, StrWindowsFiltered, StrWindowsWhile,
+};
+pub use range::{str_windows_range, StrWindowsRange};
+pub use reversed::{str_windows_reversed, StrWindowsReversed};
+pub use run::{run_windows, run_windows_by_key, RunWindows};
+pub use sample::{sample_window_indices, sample_windows};
+pub use segment::{segment_windows, SegmentWindows, Separator};
+pub use skip::{str_skip_grams, StrSkipGrams};
+pub use sms::{sms_segments, SmsEncoding, SmsSegments};
+pub use step::{
+    str_windows_by, str_windows_byte_step, str_windows_step, StrWindowsBy, StrWindowsByteStep,
+    StrWindowsStep,
+};
+pub use tokens::{token_windows, TokenWindows};
+pub use tuples::{
+    char_pair_indices, char_pairs, char_triple_indices, char_triples, CharPairIndices, CharPairs,
+    CharTripleIndices, CharTriples,
+};
+pub use utf16::{
+    for_each_utf16, truncate_utf16, utf16_chunks, utf16_windows, Utf16Chunks, Utf16TooLongError,
+    Utf16Windows,
+};
+#[cfg(feature = "width")]
+pub use width::{width_chunks, width_windows, WidthChunks, WidthWindows};
+pub use windows::{Boundaries, CharBoundaries, Windows};
+pub use with_next::{str_windows_with_next, StrWindowsWithNext};
+pub use within::{str_windows_within, StrWindowsWithin};
+pub use word::{
+    str_windows_at_word_starts, word_chunks, ws_word_windows, StrWindowsAtWordStarts, WordChunks,
+    WsWordWindows,
+};
+#[cfg(feature = "words")]
+pub use words::{
+    word_windows, word_windows_indices, WordBoundaries, WordWindows, WordWindowsIndices,
+};
+pub use wrap::{str_wrap_chunks, StrWrapChunks};
+pub use wrapping::{str_windows_wrapping, StrWindowsWrapping, WrappingSizeError};
+pub use write::{write_windows_to, Escape};
+
+mod affix;
+mod align;
+mod array;
+#[cfg(feature = "ascii-folding")]
+mod ascii_fold;
+pub mod boundary;
+mod builder;
+mod bytes;
+mod chunks;
+mod collapse;
+mod context;
+mod delta;
+mod edge;
+mod fold;
+#[cfg(feature = "graphemes")]
+mod grapheme;
+mod hashed;
+mod ident;
+mod indices;
+mod info;
+#[cfg(feature = "jamo")]
+mod jamo;
+mod join;
+#[cfg(feature = "lending")]
+mod lending;
+mod lines;
+mod nonzero;
+mod owned;
+mod partial;
+mod position;
+mod predicate;
+mod range;
+mod reversed;
+mod run;
+mod sample;
+mod segment;
+mod skip;
+mod sms;
+mod step;
+mod tokens;
+mod tuples;
+mod utf16;
+#[cfg(feature = "width")]
+mod width;
+mod windows;
+mod with_next;
+mod within;
+mod word;
+#[cfg(feature = "words")]
+mod words;
+mod wrap;
+mod wrapping;
+mod write;
 
 
 /// Returns substrings of length `size`, similar to `slice::windows`.
@@ -14,39 +182,592 @@ use std::iter::FusedIterator;
 /// assert_eq!(iter.next(), Some(" 😀😁"));
 /// assert!(iter.next().is_none());
 /// ```
-pub fn str_windows<'a>(input: &'a str, size: usize) -> impl Iterator<Item=&'a str> {
-    StrWindowsIter {
-        inner: input,
-        end: nth_indice(input, size),
-        size,
+///
+/// Like `slice::windows`, no window is shorter than `size`, so an input of
+/// fewer than `size` chars yields nothing.
+///
+/// The number of windows is computed up front, so the iterator reports an
+/// exact `size_hint`. When `size` is 0 every call yields `Some("")`, so there
+/// is no finite length to report and the iterator isn't an
+/// `ExactSizeIterator`. Use [`str_windows_nonzero`] or [`try_str_windows`]
+/// for one that is.
+///
+/// The defaults of [`StrWindows::builder`] yield the same windows; the
+/// builder combines them with a step, partial windows and direction.
+pub fn str_windows(input: &str, size: usize) -> StrWindows<'_> {
+    StrWindows::builder(input).size(size).plain()
+}
+
+/// Like [`str_windows`], but returns an error instead of an endless iterator
+/// when `size` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::try_str_windows;
+///
+/// let mut iter = try_str_windows("s 😀😁", 3).unwrap();
+/// assert_eq!(iter.next(), Some("s 😀"));
+/// assert!(try_str_windows("s 😀😁", 0).is_err());
+/// ```
+pub fn try_str_windows(
+    input: &str,
+    size: usize,
+) -> Result<StrWindowsNonZero<'_>, ZeroSizeError> {
+    NonZeroUsize::new(size)
+        .map(|size| str_windows_nonzero(input, size))
+        .ok_or(ZeroSizeError)
+}
+
+/// Like [`str_windows`], but the size is a `NonZeroUsize`, so the iterator
+/// always has a finite number of windows and is an `ExactSizeIterator`.
+///
+/// # Examples
+///
+/// ```
+/// use std::num::NonZeroUsize;
+/// use str_windows::str_windows_nonzero;
+///
+/// let size = NonZeroUsize::new(3).unwrap();
+/// let iter = str_windows_nonzero("s 😀😁", size);
+/// assert_eq!(iter.len(), 2);
+/// ```
+pub fn str_windows_nonzero(input: &str, size: NonZeroUsize) -> StrWindowsNonZero<'_> {
+    StrWindowsNonZero::new(input, size)
+}
+
+/// Like [`str_windows`], but panics when `size` is 0, matching
+/// `slice::windows`.
+///
+/// # Panics
+///
+/// Panics if `size` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::str_windows_strict;
+///
+/// let mut iter = str_windows_strict("lorem", 3);
+/// assert_eq!(iter.next().unwrap(), "lor");
+/// assert_eq!(iter.next().unwrap(), "ore");
+/// assert_eq!(iter.next().unwrap(), "rem");
+/// assert!(iter.next().is_none());
+/// ```
+pub fn str_windows_strict(input: &str, size: usize) -> StrWindowsNonZero<'_> {
+    let size = NonZeroUsize::new(size).expect("window size must be non-zero");
+    str_windows_nonzero(input, size)
+}
+
+/// Returns the windows of `size` chars of [`str_windows`] that start within
+/// the chars `char_range` of the input, e.g. a region given in char indices.
+///
+/// The windows may extend past the end of the range, see
+/// [`str_windows_in_clipped`] to keep them inside it. The range is clamped
+/// to the input. The iterator windows a slice of the input, so its
+/// [`offset`](StrWindows::offset) is relative to the start of the range.
+/// A `size` of 0 yields `Some("")` forever, as with [`str_windows`].
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::str_windows_in;
+///
+/// let windows = str_windows_in("头尾都是😀", 1..3, 2).collect::<Vec<_>>();
+/// assert_eq!(windows, ["尾都", "都是"]);
+/// ```
+pub fn str_windows_in(input: &str, char_range: Range<usize>, size: usize) -> StrWindows<'_> {
+    let Range { start, end } = char_range;
+    if start >= end {
+        return str_windows("", size);
+    }
+    let rest = &input[nth_char_boundary(input, start)..];
+    // the last window starts at `end - 1`
+    let chars = (end - start).saturating_add(size).saturating_sub(1);
+    str_windows(&rest[..nth_char_boundary(rest, chars)], size)
+}
+
+/// Like [`str_windows_in`], but only yields the windows that end within
+/// `char_range` too. A `size` of 0 yields `Some("")` forever, as with
+/// [`str_windows`].
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::str_windows_in_clipped;
+///
+/// let windows = str_windows_in_clipped("头尾都是😀", 1..4, 2).collect::<Vec<_>>();
+/// assert_eq!(windows, ["尾都", "都是"]);
+/// ```
+pub fn str_windows_in_clipped(
+    input: &str,
+    char_range: Range<usize>,
+    size: usize,
+) -> StrWindows<'_> {
+    let Range { start, end } = char_range;
+    let rest = &input[nth_char_boundary(input, start)..];
+    str_windows(&rest[..nth_char_boundary(rest, end.saturating_sub(start))], size)
+}
+
+/// Extension methods on `str` for the iterators of this crate, to use them in
+/// method chains.
+///
+/// This trait is sealed and only implemented for `str`.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::StrWindowsExt;
+///
+/// let windows = "s 😀😁".char_windows(3).rev().collect::<Vec<_>>();
+/// assert_eq!(windows, [" 😀😁", "s 😀"]);
+///
+/// let count = "hello world"
+///     .split(' ')
+///     .flat_map(|word| word.char_windows(2))
+///     .filter(|window| window.contains('o'))
+///     .count();
+/// assert_eq!(count, 3);
+/// ```
+pub trait StrWindowsExt: sealed::Sealed {
+    /// Same as [`str_windows`]`(self, size)`.
+    fn char_windows(&self, size: usize) -> StrWindows<'_>;
+
+    /// Same as [`str_windows_step`]`(self, size, step)`.
+    fn char_windows_step(&self, size: usize, step: usize) -> StrWindowsStep<'_>;
+
+    /// Same as [`str_chunks`]`(self, size)`.
+    fn char_chunks(&self, size: usize) -> StrChunks<'_>;
+}
+
+impl StrWindowsExt for str {
+    fn char_windows(&self, size: usize) -> StrWindows<'_> {
+        str_windows(self, size)
+    }
+
+    fn char_windows_step(&self, size: usize, step: usize) -> StrWindowsStep<'_> {
+        str_windows_step(self, size, step)
+    }
+
+    fn char_chunks(&self, size: usize) -> StrChunks<'_> {
+        str_chunks(self, size)
+    }
+}
+
+mod sealed {
+    pub trait Sealed { }
+
+    impl Sealed for str { }
+}
+
+/// The error returned by [`try_str_windows`] when the window size is 0.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ZeroSizeError;
+
+impl fmt::Display for ZeroSizeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("window size must be non-zero")
     }
 }
 
-fn next_indice(s: &str) -> usize {
-    for i in 1..=s.len() {
-        if s.is_char_boundary(i) {
-            return i;
+impl Error for ZeroSizeError { }
+
+/// Returns the number of windows [`str_windows`]`(input, size)` yields,
+/// usable in const contexts.
+///
+/// Returns `usize::MAX` when `size` is 0, standing in for the endless
+/// iterator.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::window_count;
+///
+/// const COUNT: usize = window_count("héllo", 2);
+/// static TABLE: [u8; COUNT] = [0; COUNT];
+/// assert_eq!(TABLE.len(), 4);
+/// ```
+pub const fn window_count(input: &str, size: usize) -> usize {
+    let bytes = input.as_bytes();
+    let mut chars = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        // count every byte that isn't a continuation byte
+        if bytes[i] & 0xC0 != 0x80 {
+            chars += 1;
         }
+        i += 1;
     }
-    return 1;
+    window_len(chars, size)
 }
 
-fn nth_indice(s: &str, n: usize) -> usize {
-    s.char_indices()
-        .nth(n)
-        .map(|x| x.0)
-        .unwrap_or(s.len())
+/// Number of windows of `size` chars over `chars` chars.
+const fn window_len(chars: usize, size: usize) -> usize {
+    if size == 0 {
+        return usize::MAX;
+    }
+    chars.saturating_sub(size - 1)
 }
 
-struct StrWindowsIter<'a> {
+/// An iterator over overlapping windows of `size` chars of a `&str`.
+///
+/// This struct is created by [`str_windows`]. It implements [`Iterator`],
+/// [`DoubleEndedIterator`] and [`FusedIterator`], so windows can be consumed
+/// from either end. As its size may be 0 it isn't an [`ExactSizeIterator`],
+/// unlike the [`StrWindowsNonZero`] of [`str_windows_nonzero`]. It is also [`Clone`], which is
+/// cheap and lets a saved copy resume from the same position later.
+///
+/// The [`Debug`](fmt::Debug) output shows the `remaining` input still covered
+/// by windows, the window `size`, and the byte offset `end` of the next
+/// window within `remaining`.
+///
+/// Being a named type, it can be stored in other structs:
+///
+/// ```
+/// use str_windows::{str_windows, StrWindows};
+///
+/// struct Lexer<'a> {
+///     windows: StrWindows<'a>,
+/// }
+///
+/// let mut lexer = Lexer { windows: str_windows("let x", 3) };
+/// assert_eq!(lexer.windows.next(), Some("let"));
+/// ```
+#[derive(Clone)]
+pub struct StrWindows<'a> {
+    /// The string passed to `str_windows`.
+    source: &'a str,
+    /// The part of the input still covered by the remaining windows.
     inner: &'a str,
+    /// End of the front window, relative to `inner`.
     end: usize,
+    /// Start of the back window, relative to `inner`.
+    start: usize,
+    size: usize,
+    /// Number of windows left to yield.
+    len: usize,
+}
+
+impl<'a> StrWindows<'a> {
+    fn new(input: &'a str, size: usize) -> Self {
+        StrWindows {
+            source: input,
+            inner: input,
+            end: nth_char_boundary(input, size),
+            start: nth_char_boundary_back(input, size),
+            size,
+            len: window_len(input.chars().count(), size),
+        }
+    }
+
+    /// Returns the part of the input not consumed yet, starting at the first
+    /// char of the next window.
+    ///
+    /// Windows taken from the back also trim the end of the returned string.
+    /// Once the iterator is exhausted from the front, this is the tail shorter
+    /// than `size` that no window started at.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use str_windows::str_windows;
+    ///
+    /// let mut iter = str_windows("头尾都是", 2);
+    /// assert_eq!(iter.as_str(), "头尾都是");
+    /// iter.next();
+    /// assert_eq!(iter.as_str(), "尾都是");
+    /// iter.by_ref().for_each(drop);
+    /// assert_eq!(iter.as_str(), "是");
+    /// ```
+    pub fn as_str(&self) -> &'a str {
+        self.inner
+    }
+
+    /// Returns the byte offset, within the string the iterator was created
+    /// from, at which the next window starts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use str_windows::str_windows;
+    ///
+    /// let mut iter = str_windows("头尾都是", 2);
+    /// assert_eq!(iter.offset(), 0);
+    /// iter.next();
+    /// assert_eq!(iter.offset(), "头".len());
+    /// ```
+    pub fn offset(&self) -> usize {
+        self.inner.as_ptr() as usize - self.source.as_ptr() as usize
+    }
+
+    /// Returns the next window without consuming it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use str_windows::str_windows;
+    ///
+    /// let mut iter = str_windows("头尾都是", 2);
+    /// assert_eq!(iter.peek(), Some("头尾"));
+    /// assert_eq!(iter.next(), Some("头尾"));
+    /// ```
+    pub fn peek(&self) -> Option<&'a str> {
+        self.peek_nth(0)
+    }
+
+    /// Returns the `n`th upcoming window (counting from 0) without consuming
+    /// any window.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use str_windows::str_windows;
+    ///
+    /// let iter = str_windows("头尾都是", 2);
+    /// assert_eq!(iter.peek_nth(2), Some("都是"));
+    /// assert_eq!(iter.peek_nth(3), None);
+    /// ```
+    pub fn peek_nth(&self, n: usize) -> Option<&'a str> {
+        self.get(n)
+    }
+
+    /// Returns the remaining window with index `i`, without consuming any
+    /// window. This is the same as `self.clone().nth(i)`.
+    ///
+    /// The boundaries are walked from whichever end of the remaining windows
+    /// is closer, so windows near either end are cheap to get.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use str_windows::str_windows;
+    ///
+    /// let mut iter = str_windows("hello, 你好", 3);
+    /// iter.next();
+    /// assert_eq!(iter.get(0), Some("ell"));
+    /// assert_eq!(iter.get(5), Some(" 你好"));
+    /// assert_eq!(iter.get(6), None);
+    /// ```
+    pub fn get(&self, i: usize) -> Option<&'a str> {
+        // degenerate case
+        if self.size == 0 {
+            return Some("");
+        }
+        if i >= self.len {
+            return None;
+        }
+        let back = self.len - 1 - i;
+        let (start, end) = if i <= back {
+            let start = nth_char_boundary(self.inner, i);
+            (start, self.end + nth_char_boundary(&self.inner[self.end..], i))
+        } else {
+            let start = nth_char_boundary_back(&self.inner[..self.start], back);
+            (start, nth_char_boundary_back(self.inner, back))
+        };
+        Some(&self.inner[start..end])
+    }
+
+    /// Moves the iterator so that the next window starts at the char with
+    /// index `char_index` of the original input.
+    ///
+    /// Windows already taken from the back become available again, as with
+    /// [`reset`](StrWindows::reset). If no window starts at `char_index` the
+    /// iterator is left exhausted. Has no effect when `size` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use str_windows::str_windows;
+    ///
+    /// let mut iter = str_windows("头尾都是", 2);
+    /// iter.advance_to_char(2);
+    /// assert_eq!(iter.next(), Some("都是"));
+    /// iter.advance_to_char(1);
+    /// assert_eq!(iter.next(), Some("尾都"));
+    /// iter.advance_to_char(3);
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    pub fn advance_to_char(&mut self, char_index: usize) {
+        if self.size == 0 {
+            return;
+        }
+        self.reset();
+        let n = char_index.min(self.len);
+        self.advance_front(n);
+    }
+
+    /// Moves the iterator back to the start of the original input, as if it
+    /// was just created.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use str_windows::str_windows;
+    ///
+    /// let mut iter = str_windows("头尾都是", 2);
+    /// iter.next();
+    /// iter.next_back();
+    /// iter.reset();
+    /// assert_eq!(iter.collect::<Vec<_>>(), ["头尾", "尾都", "都是"]);
+    /// ```
+    pub fn reset(&mut self) {
+        *self = StrWindows::new(self.source, self.size);
+    }
+
+    /// Changes the window size, keeping the start of the next window.
+    ///
+    /// The remaining windows are recomputed over [`as_str`](StrWindows::as_str)
+    /// for the new size, so growing the size near the end of the input can
+    /// leave the iterator exhausted. A size of 0 switches to the degenerate
+    /// behavior of yielding `Some("")` forever, as with [`str_windows`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use str_windows::str_windows;
+    ///
+    /// let mut iter = str_windows("头尾都是", 2);
+    /// assert_eq!(iter.next(), Some("头尾"));
+    /// iter.set_size(3);
+    /// assert_eq!(iter.next(), Some("尾都是"));
+    /// assert_eq!(iter.next(), None);
+    /// iter.set_size(1);
+    /// assert_eq!(iter.next(), Some("都"));
+    /// ```
+    pub fn set_size(&mut self, size: usize) {
+        *self = StrWindows {
+            source: self.source,
+            ..StrWindows::new(self.inner, size)
+        };
+    }
+
+    /// Splits the iterator in two: the first yields the next `n` windows, the
+    /// second yields the windows after them.
+    ///
+    /// If fewer than `n` windows remain, the second iterator is exhausted.
+    /// When `size` is 0 both iterators yield `Some("")` forever.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use str_windows::str_windows;
+    ///
+    /// let (first, second) = str_windows("头尾都是", 2).split_at(1);
+    /// assert_eq!(first.collect::<Vec<_>>(), ["头尾"]);
+    /// assert_eq!(second.collect::<Vec<_>>(), ["尾都", "都是"]);
+    /// ```
+    pub fn split_at(self, n: usize) -> (StrWindows<'a>, StrWindows<'a>) {
+        if self.size == 0 {
+            return (self.clone(), self);
+        }
+        let n = n.min(self.len);
+        let mut first = self.clone();
+        first.advance_back(self.len - n);
+        let mut second = self;
+        second.advance_front(n);
+        (first, second)
+    }
+
+    /// Saves the current position of the iterator, to go back to it later
+    /// with [`restore`](StrWindows::restore).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use str_windows::str_windows;
+    ///
+    /// let mut iter = str_windows("头尾都是", 2);
+    /// iter.next();
+    /// let checkpoint = iter.checkpoint();
+    /// assert_eq!(iter.next(), Some("尾都"));
+    /// assert_eq!(iter.next(), Some("都是"));
+    /// iter.restore(checkpoint);
+    /// assert_eq!(iter.next(), Some("尾都"));
+    /// ```
+    pub fn checkpoint(&self) -> Checkpoint {
+        let offset = self.offset();
+        Checkpoint {
+            source: (self.source.as_ptr() as usize, self.source.len()),
+            size: self.size,
+            inner: (offset, offset + self.inner.len()),
+            end: self.end,
+            start: self.start,
+            len: self.len,
+        }
+    }
+
+    /// Moves the iterator back to a position saved by
+    /// [`checkpoint`](StrWindows::checkpoint).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the checkpoint was taken from an iterator over a different
+    /// string or with a different window size.
+    pub fn restore(&mut self, checkpoint: Checkpoint) {
+        assert!(
+            checkpoint.source == (self.source.as_ptr() as usize, self.source.len())
+                && checkpoint.size == self.size,
+            "checkpoint was taken from a different iterator",
+        );
+        self.inner = &self.source[checkpoint.inner.0..checkpoint.inner.1];
+        self.end = checkpoint.end;
+        self.start = checkpoint.start;
+        self.len = checkpoint.len;
+    }
+
+    /// Skips `n` windows from the front, `n` must not exceed `self.len`.
+    fn advance_front(&mut self, n: usize) {
+        if n == 0 {
+            return;
+        }
+        let skip_len = nth_char_boundary(self.inner, n);
+        self.end += nth_char_boundary(&self.inner[self.end..], n);
+        self.end -= skip_len;
+        self.start = self.start.saturating_sub(skip_len);
+        self.inner = &self.inner[skip_len..];
+        self.len -= n;
+    }
+
+    /// Skips `n` windows from the back, `n` must not exceed `self.len`.
+    fn advance_back(&mut self, n: usize) {
+        if n == 0 {
+            return;
+        }
+        self.start = nth_char_boundary_back(&self.inner[..self.start], n);
+        self.inner = &self.inner[..nth_char_boundary_back(self.inner, n)];
+        self.len -= n;
+    }
+}
+
+/// A saved position of a [`StrWindows`] iterator.
+///
+/// This struct is created by [`StrWindows::checkpoint`] and consumed by
+/// [`StrWindows::restore`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Checkpoint {
+    /// Address and length of the source string, to catch misuse.
+    source: (usize, usize),
     size: usize,
+    /// Byte range of `StrWindows::inner` within the source string.
+    inner: (usize, usize),
+    end: usize,
+    start: usize,
+    len: usize,
 }
 
-impl<'a> FusedIterator for StrWindowsIter<'a> { }
+impl<'a> fmt::Debug for StrWindows<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("StrWindows")
+            .field("remaining", &self.inner)
+            .field("size", &self.size)
+            .field("end", &self.end)
+            .finish()
+    }
+}
+
+impl<'a> FusedIterator for StrWindows<'a> { }
 
-impl<'a> Iterator for StrWindowsIter<'a> {
+impl<'a> Iterator for StrWindows<'a> {
     type Item = &'a str;
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -54,13 +775,15 @@ impl<'a> Iterator for StrWindowsIter<'a> {
         if self.size == 0 {
             return Some("");
         }
-        if self.end > self.inner.len() {
+        if self.len == 0 {
             return None;
         }
+        self.len -= 1;
         let inner = &self.inner[..self.end];
-        let skip_len = next_indice(self.inner);
-        self.end += next_indice(&self.inner[self.end..]);
+        let skip_len = next_char_boundary(self.inner, 0);
+        self.end = next_char_boundary(self.inner, self.end);
         self.end -= skip_len;
+        self.start = self.start.saturating_sub(skip_len);
         self.inner = &self.inner[skip_len..];
         Some(inner)
     }
@@ -69,18 +792,150 @@ impl<'a> Iterator for StrWindowsIter<'a> {
         if self.size == 0 {
             return (usize::MAX, None);
         }
-        let (min, max) = self.inner.chars().size_hint();
-        let f = |n: usize|
-            n.checked_sub(self.size - 1)
-                .unwrap_or(0);
-        (f(min), max.map(f))
+        (self.len, Some(self.len))
+    }
+
+    fn count(self) -> usize {
+        // degenerate case, diverges like the default implementation
+        if self.size == 0 {
+            return self.fold(0, |count, _| count + 1);
+        }
+        self.len
+    }
+
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+        where F: FnMut(B, Self::Item) -> B,
+    {
+        let mut acc = init;
+        // degenerate case
+        if self.size == 0 {
+            loop {
+                acc = f(acc, "");
+            }
+        }
+        if self.len == 0 {
+            return acc;
+        }
+        // overriding `try_fold` needs the unstable `Try` trait, so only `fold`
+        // (and with it `for_each`) gets the tight loop, see
+        // `examples/fold_bench.rs`
+        let (mut start, mut end) = (0, self.end);
+        let mut heads = self.inner.chars();
+        let mut tails = self.inner[end..].chars();
+        for _ in 0..self.len {
+            acc = f(acc, &self.inner[start..end]);
+            start += heads.next().map_or(0, char::len_utf8);
+            end += tails.next().map_or(0, char::len_utf8);
+        }
+        acc
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        // degenerate case, diverges like the default implementation
+        if self.size == 0 {
+            return self.fold(None, |_, window| Some(window));
+        }
+        // the back window is already known, no need to walk to it
+        self.next_back()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        // degenerate case
+        if self.size == 0 {
+            return Some("");
+        }
+        if n >= self.len {
+            let len = self.len;
+            self.advance_front(len);
+            return None;
+        }
+        self.advance_front(n);
+        self.next()
+    }
+}
+
+impl<'a> DoubleEndedIterator for StrWindows<'a> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        // degenerate case
+        if self.size == 0 {
+            return Some("");
+        }
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        let inner = &self.inner[self.start..];
+        self.inner = &self.inner[..prev_char_boundary(self.inner, self.inner.len())];
+        self.start = prev_char_boundary(self.inner, self.start);
+        Some(inner)
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        // degenerate case
+        if self.size == 0 {
+            return Some("");
+        }
+        if n >= self.len {
+            let len = self.len;
+            self.advance_back(len);
+            return None;
+        }
+        self.advance_back(n);
+        self.next_back()
+    }
+
+    fn rfold<B, F>(self, init: B, mut f: F) -> B
+        where F: FnMut(B, Self::Item) -> B,
+    {
+        let mut acc = init;
+        // degenerate case
+        if self.size == 0 {
+            loop {
+                acc = f(acc, "");
+            }
+        }
+        if self.len == 0 {
+            return acc;
+        }
+        let (mut start, mut end) = (self.start, self.inner.len());
+        let mut heads = self.inner[..start].chars();
+        let mut tails = self.inner.chars();
+        for _ in 0..self.len {
+            acc = f(acc, &self.inner[start..end]);
+            start -= heads.next_back().map_or(0, char::len_utf8);
+            end -= tails.next_back().map_or(0, char::len_utf8);
+        }
+        acc
     }
 }
 
 
 #[cfg(test)]
 mod tests {
-    use super::str_windows;
+    use std::num::NonZeroUsize;
+    use super::{
+        str_windows, str_windows_in, str_windows_in_clipped, str_windows_nonzero,
+        str_windows_strict, try_str_windows, window_count, CharBoundaries, StrWindowsExt,
+        Windows, ZeroSizeError,
+    };
+
+    /// Checks that `iter` yields `expected`, also through `count`, `last`,
+    /// `nth` and `size_hint`.
+    fn check_windows<'a, I>(iter: I, expected: &[&str])
+        where I: Iterator<Item = &'a str> + Clone,
+    {
+        assert_eq!(iter.size_hint(), (expected.len(), Some(expected.len())));
+        assert_eq!(iter.clone().collect::<Vec<_>>(), expected);
+        assert_eq!(iter.clone().count(), expected.len());
+        assert_eq!(iter.clone().last(), expected.last().cloned());
+        for n in 0..=expected.len() {
+            let mut iter = iter.clone();
+            assert_eq!(iter.nth(n), expected.get(n).cloned());
+            let rest = expected.get(n + 1..).unwrap_or(&[]);
+            assert_eq!(iter.size_hint(), (rest.len(), Some(rest.len())));
+            assert_eq!(iter.collect::<Vec<_>>(), rest);
+        }
+    }
 
     #[test]
     fn it_works() {
@@ -100,6 +955,11 @@ mod tests {
         for _ in 0..100 {
             assert_eq!(iter.next(), Some(""));
         }
+        let mut iter = Windows::new(test_str, 0, CharBoundaries);
+        assert_eq!(iter.size_hint(), (usize::MAX, None));
+        for _ in 0..100 {
+            assert_eq!(iter.next(), Some(""));
+        }
     }
 
     #[test]
@@ -251,10 +1111,41 @@ mod tests {
                     ],
                 ][..]
             ),
+            (
+                "s 😀😁",
+                &[
+                    &[
+                        "s",
+                        " ",
+                        "😀",
+                        "😁",
+                    ][..],
+                    &[
+                        "s ",
+                        " 😀",
+                        "😀😁",
+                    ],
+                    &[
+                        "s 😀",
+                        " 😀😁",
+                    ],
+                    &[
+                        "s 😀😁",
+                    ],
+                ][..]
+            ),
+            ("", &[][..]),
         ];
+        // the windows of `str_windows` and of the generic `Windows` agree
         for (src, tests) in datas {
             for (i, &test) in tests.iter().enumerate() {
-                assert_eq!(str_windows(src, i+1).collect::<Vec<_>>(), test);
+                check_windows(str_windows(src, i+1), test);
+                check_windows(Windows::new(src, i+1, CharBoundaries), test);
+            }
+            let chars = src.chars().count();
+            for &size in &[chars + 1, chars + 2, usize::MAX] {
+                check_windows(str_windows(src, size), &[]);
+                check_windows(Windows::new(src, size, CharBoundaries), &[]);
             }
         }
     }
@@ -263,12 +1154,566 @@ mod tests {
     fn size_hint_test() {
         let src = "abcde";
         assert_eq!(str_windows(src, 0).size_hint(), (usize::MAX, None));
-        assert_eq!(str_windows(src, 1).size_hint(), (2, Some(5)));
-        assert_eq!(str_windows(src, 2).size_hint(), (1, Some(4)));
-        assert_eq!(str_windows(src, 3).size_hint(), (0, Some(3)));
-        assert_eq!(str_windows(src, 4).size_hint(), (0, Some(2)));
-        assert_eq!(str_windows(src, 5).size_hint(), (0, Some(1)));
+        assert_eq!(str_windows(src, 1).size_hint(), (5, Some(5)));
+        assert_eq!(str_windows(src, 2).size_hint(), (4, Some(4)));
+        assert_eq!(str_windows(src, 3).size_hint(), (3, Some(3)));
+        assert_eq!(str_windows(src, 4).size_hint(), (2, Some(2)));
+        assert_eq!(str_windows(src, 5).size_hint(), (1, Some(1)));
         assert_eq!(str_windows(src, 6).size_hint(), (0, Some(0)));
         assert_eq!(str_windows(src, 7).size_hint(), (0, Some(0)));
+        assert_eq!(str_windows("头尾都是", 2).size_hint(), (3, Some(3)));
+    }
+
+    #[test]
+    fn len_test() {
+        let src = "hello, 你好, hi";
+        for size in 1..=5 {
+            let mut iter = str_windows_strict(src, size);
+            let mut expected = str_windows(src, size).count();
+            assert_eq!(iter.len(), expected);
+            while iter.next().is_some() {
+                expected -= 1;
+                assert_eq!(iter.len(), expected);
+                if iter.next_back().is_some() {
+                    expected -= 1;
+                    assert_eq!(iter.len(), expected);
+                }
+            }
+            assert_eq!(iter.len(), 0);
+        }
+    }
+
+    #[test]
+    fn rev_test() {
+        let src = "头尾都是";
+        assert_eq!(str_windows(src, 1).rev().collect::<Vec<_>>(), ["是", "都", "尾", "头"]);
+        assert_eq!(str_windows(src, 2).rev().collect::<Vec<_>>(), ["都是", "尾都", "头尾"]);
+        assert_eq!(str_windows(src, 3).rev().collect::<Vec<_>>(), ["尾都是", "头尾都"]);
+        assert_eq!(str_windows(src, 4).rev().collect::<Vec<_>>(), ["头尾都是"]);
+
+        let src = "hello, 你好, hi";
+        for size in 1..=5 {
+            let mut expected = str_windows(src, size).collect::<Vec<_>>();
+            expected.reverse();
+            assert_eq!(str_windows(src, size).rev().collect::<Vec<_>>(), expected);
+        }
+    }
+
+    #[test]
+    fn mixed_ends_test() {
+        let mut iter = str_windows(": 尾部是", 2);
+        assert_eq!(iter.next(), Some(": "));
+        assert_eq!(iter.next_back(), Some("部是"));
+        assert_eq!(iter.next(), Some(" 尾"));
+        assert_eq!(iter.next_back(), Some("尾部"));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+
+        let mut iter = str_windows("头尾都是", 2);
+        assert_eq!(iter.next_back(), Some("都是"));
+        assert_eq!(iter.next(), Some("头尾"));
+        assert_eq!(iter.next_back(), Some("尾都"));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+
+        let mut iter = str_windows("s 😀😁", 1);
+        assert_eq!(iter.next_back(), Some("😁"));
+        assert_eq!(iter.next_back(), Some("😀"));
+        assert_eq!(iter.next(), Some("s"));
+        assert_eq!(iter.next(), Some(" "));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn degenerate_back() {
+        let mut iter = str_windows("any string", 0);
+        for _ in 0..100 {
+            assert_eq!(iter.next_back(), Some(""));
+        }
+    }
+
+    #[test]
+    fn clone_test() {
+        let mut iter = str_windows("hello, 你好, hi", 3);
+        iter.nth(4);
+        let mut saved = iter.clone();
+        let rest = iter.by_ref().collect::<Vec<_>>();
+        assert_eq!(rest, [", 你", " 你好", "你好,", "好, ", ", h", " hi"]);
+        assert!(iter.next().is_none());
+        assert_eq!(saved.next(), Some(", 你"));
+        assert_eq!(saved.next_back(), Some(" hi"));
+        assert_eq!(saved.collect::<Vec<_>>(), &rest[1..rest.len() - 1]);
+    }
+
+    #[test]
+    fn debug_test() {
+        let mut iter = str_windows("头尾都", 2);
+        assert_eq!(format!("{:?}", iter), r#"StrWindows { remaining: "头尾都", size: 2, end: 6 }"#);
+        iter.next();
+        assert_eq!(format!("{:?}", iter), r#"StrWindows { remaining: "尾都", size: 2, end: 6 }"#);
+    }
+
+    #[test]
+    fn nth_test() {
+        let src = "hello, 你好, hi";
+        for size in 1..=5 {
+            for n in 0..16 {
+                let mut fast = str_windows(src, size);
+                let mut slow = str_windows(src, size);
+                for _ in 0..n {
+                    slow.next();
+                }
+                assert_eq!(fast.nth(n), slow.next());
+                assert_eq!(fast.collect::<Vec<_>>(), slow.collect::<Vec<_>>());
+            }
+        }
+        let mut iter = str_windows("头尾都是", 2);
+        assert_eq!(iter.nth(1), Some("尾都"));
+        assert_eq!(iter.next_back(), Some("都是"));
+        assert_eq!(iter.nth(1), None);
+        assert_eq!(iter.next(), None);
+        assert_eq!(str_windows("头尾都是", 1).skip(2).collect::<Vec<_>>(), ["都", "是"]);
+        assert_eq!(str_windows("头尾都是", 0).nth(100), Some(""));
+    }
+
+    #[test]
+    fn nth_large_test() {
+        let src = "ab你好😀".repeat(400_000);
+        let start = src.char_indices().nth(1_000_000).unwrap().0;
+        let end = src.char_indices().nth(1_000_064).unwrap().0;
+        let mut iter = str_windows(&src, 64);
+        assert_eq!(iter.nth(1_000_000), Some(&src[start..end]));
+        assert_eq!(iter.size_hint().0, 2_000_000 - 63 - 1_000_001);
+    }
+
+    #[test]
+    fn count_test() {
+        for &src in &["hello, 你好, hi", "头尾都是", ": 尾部是", "abc"] {
+            for size in 1..=6 {
+                let mut iter = str_windows(src, size);
+                let mut slow = iter.clone();
+                let mut n = 0;
+                while slow.next().is_some() {
+                    n += 1;
+                }
+                assert_eq!(iter.clone().count(), n);
+                iter.next();
+                assert_eq!(iter.clone().count(), n.saturating_sub(1));
+                iter.next_back();
+                assert_eq!(iter.count(), n.saturating_sub(2));
+            }
+        }
+    }
+
+    #[test]
+    fn last_test() {
+        for &src in &["hello, 你好, hi", "头尾都是", "头部是!", ": 尾部是", "s 😀😁"] {
+            for size in 1..=6 {
+                let mut iter = str_windows(src, size);
+                let mut slow = None;
+                for window in iter.clone() {
+                    slow = Some(window);
+                }
+                assert_eq!(iter.clone().last(), slow);
+                iter.next();
+                assert_eq!(iter.clone().last(), if iter.size_hint().0 == 0 { None } else { slow });
+            }
+        }
+        assert_eq!(str_windows("头尾都是", 3).last(), Some("尾都是"));
+    }
+
+    #[test]
+    fn fold_test() {
+        for &src in &["hello, 你好, hi", "头尾都是", "头部是!", ": 尾部是", "s 😀😁"] {
+            for size in 1..=6 {
+                let mut iter = str_windows(src, size);
+                for _ in 0..3 {
+                    let mut expected = Vec::new();
+                    for window in iter.clone() {
+                        expected.push(window);
+                    }
+                    let folded = iter.clone().fold(Vec::new(), |mut acc, window| {
+                        acc.push(window);
+                        acc
+                    });
+                    assert_eq!(folded, expected);
+                    let mut visited = Vec::new();
+                    iter.clone().for_each(|window| visited.push(window));
+                    assert_eq!(visited, expected);
+                    iter.next();
+                    iter.next_back();
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn nth_back_test() {
+        let src = "hello, 你好, hi";
+        for size in 1..=5 {
+            for front in 0..3 {
+                for n in 0..16 {
+                    let mut fast = str_windows(src, size);
+                    fast.nth(front);
+                    let mut slow = fast.clone();
+                    for _ in 0..n {
+                        slow.next_back();
+                    }
+                    assert_eq!(fast.nth_back(n), slow.next_back());
+                    assert_eq!(fast.next(), slow.next());
+                    assert_eq!(fast.rev().collect::<Vec<_>>(), slow.rev().collect::<Vec<_>>());
+                }
+            }
+        }
+        let mut iter = str_windows("头尾都是", 2);
+        assert_eq!(iter.nth_back(1), Some("尾都"));
+        assert_eq!(iter.next(), Some("头尾"));
+        assert_eq!(iter.nth_back(0), None);
+        assert_eq!(str_windows("头尾都是", 0).nth_back(100), Some(""));
+    }
+
+    #[test]
+    fn rfold_test() {
+        for &src in &["hello, 你好, hi", "头尾都是", "头部是!", ": 尾部是", "s 😀😁"] {
+            for size in 1..=6 {
+                let mut iter = str_windows(src, size);
+                for _ in 0..3 {
+                    let mut expected = Vec::new();
+                    let mut slow = iter.clone();
+                    while let Some(window) = slow.next_back() {
+                        expected.push(window);
+                    }
+                    let folded = iter.clone().rfold(Vec::new(), |mut acc, window| {
+                        acc.push(window);
+                        acc
+                    });
+                    assert_eq!(folded, expected);
+                    iter.next_back();
+                    iter.next();
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn as_str_test() {
+        let mut iter = str_windows("s 😀😁", 2);
+        assert_eq!(iter.as_str(), "s 😀😁");
+        assert_eq!(iter.next(), Some("s "));
+        assert_eq!(iter.as_str(), " 😀😁");
+        assert_eq!(iter.next(), Some(" 😀"));
+        assert_eq!(iter.as_str(), "😀😁");
+        assert_eq!(iter.next(), Some("😀😁"));
+        assert_eq!(iter.as_str(), "😁");
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.as_str(), "😁");
+
+        let mut iter = str_windows("hello, 你好, hi", 3);
+        iter.nth(5);
+        assert_eq!(iter.as_str(), " 你好, hi");
+        iter.next_back();
+        assert_eq!(iter.as_str(), " 你好, h");
+    }
+
+    #[test]
+    fn offset_test() {
+        for &src in &["abcdefg", "头尾都是", "s 😀😁", "你好, hi"] {
+            for size in 1..=3 {
+                let mut iter = str_windows(src, size);
+                loop {
+                    let offset = iter.offset();
+                    match iter.next() {
+                        Some(window) => assert_eq!(src.find(window), Some(offset)),
+                        None => break,
+                    }
+                }
+            }
+        }
+        let mut iter = str_windows("头尾都是", 2);
+        iter.next_back();
+        assert_eq!(iter.offset(), 0);
+        iter.nth(1);
+        assert_eq!(iter.offset(), 6);
+    }
+
+    #[test]
+    fn seek_test() {
+        let src = "hello, 你好, hi";
+        for size in 1..=5 {
+            let expected = str_windows(src, size).collect::<Vec<_>>();
+            let mut iter = str_windows(src, size);
+            for i in (0..20).rev() {
+                iter.advance_to_char(i);
+                assert_eq!(iter.clone().collect::<Vec<_>>(), expected.get(i..).unwrap_or(&[]));
+                iter.next_back();
+            }
+            iter.reset();
+            assert_eq!(iter.collect::<Vec<_>>(), expected);
+        }
+        let mut iter = str_windows("头尾都是", 2);
+        iter.advance_to_char(100);
+        assert_eq!(iter.size_hint().0, 0);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+
+        let mut iter = str_windows("头尾都是", 0);
+        iter.advance_to_char(2);
+        assert_eq!(iter.next(), Some(""));
+        iter.reset();
+        assert_eq!(iter.as_str(), "头尾都是");
+    }
+
+    #[test]
+    fn checkpoint_test() {
+        let src = "hello, 你好, hi";
+        let mut iter = str_windows(src, 3);
+        iter.nth(3);
+        let first = iter.checkpoint();
+        assert_eq!(iter.next(), Some("o, "));
+        iter.next_back();
+        let second = iter.checkpoint();
+        let rest = iter.clone().collect::<Vec<_>>();
+        assert_eq!(rest, [", 你", " 你好", "你好,", "好, ", ", h"]);
+        iter.nth(2);
+        iter.restore(second);
+        assert_eq!(iter.clone().collect::<Vec<_>>(), rest);
+        iter.by_ref().for_each(drop);
+        iter.restore(first);
+        assert_eq!(iter.next(), Some("o, "));
+        assert_eq!(iter.next_back(), Some(" hi"));
+        iter.restore(second);
+        assert_eq!(iter.as_str(), ", 你好, h");
+        assert_eq!(iter.size_hint().0, rest.len());
+    }
+
+    #[test]
+    #[should_panic]
+    fn checkpoint_other_source() {
+        let checkpoint = str_windows("头尾都是", 2).checkpoint();
+        str_windows("头尾都是!", 2).restore(checkpoint);
+    }
+
+    #[test]
+    fn try_str_windows_test() {
+        assert_eq!(try_str_windows("头尾都是", 0).err(), Some(ZeroSizeError));
+        assert_eq!(try_str_windows("", 0).err(), Some(ZeroSizeError));
+        assert_eq!(ZeroSizeError.to_string(), "window size must be non-zero");
+        for &src in &["hello, 你好, hi", "头尾都是", ""] {
+            for size in 1..=6 {
+                assert_eq!(
+                    try_str_windows(src, size).unwrap().collect::<Vec<_>>(),
+                    str_windows(src, size).collect::<Vec<_>>(),
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn strict_test() {
+        for &src in &["hello, 你好, hi", "头尾都是", ""] {
+            for size in 1..=6 {
+                assert_eq!(
+                    str_windows_strict(src, size).collect::<Vec<_>>(),
+                    str_windows(src, size).collect::<Vec<_>>(),
+                );
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "window size must be non-zero")]
+    fn strict_zero() {
+        str_windows_strict("头尾都是", 0);
+    }
+
+    #[test]
+    fn in_range_test() {
+        let windows = str_windows_in("头尾都是😀", 3..10, 2).collect::<Vec<_>>();
+        assert_eq!(windows, ["是😀"]);
+        let windows = str_windows_in_clipped("头尾都是😀", 0..3, 2).collect::<Vec<_>>();
+        assert_eq!(windows, ["头尾", "尾都"]);
+        assert_eq!(str_windows_in("头尾都是", 9..12, 1).next(), None);
+        #[allow(clippy::reversed_empty_ranges)]
+        let empty = str_windows_in("头尾都是", 3..1, 1).next();
+        assert_eq!(empty, None);
+
+        let src = "hello, 你好, 😀 hi";
+        let chars = src.chars().count();
+        let byte = |i: usize| src.char_indices().nth(i).map_or(src.len(), |x| x.0);
+        for start in 0..chars + 2 {
+            for end in start..chars + 2 {
+                for size in 1..=4 {
+                    let expected = str_windows(&src[byte(start)..], size)
+                        .take(end - start)
+                        .collect::<Vec<_>>();
+                    let windows = str_windows_in(src, start..end, size);
+                    assert_eq!(windows.size_hint().0, expected.len());
+                    assert_eq!(windows.collect::<Vec<_>>(), expected);
+                    let expected = str_windows(&src[byte(start)..byte(end)], size).collect::<Vec<_>>();
+                    let windows = str_windows_in_clipped(src, start..end, size).collect::<Vec<_>>();
+                    assert_eq!(windows, expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn oversized() {
+        let none: &[&str] = &[];
+        assert_eq!(str_windows("abc", 4).collect::<Vec<_>>(), none);
+        assert_eq!(str_windows("abc", 100).collect::<Vec<_>>(), none);
+        assert_eq!(str_windows("", 1).collect::<Vec<_>>(), none);
+        assert_eq!(str_windows("头尾都是", 5).collect::<Vec<_>>(), none);
+        assert_eq!(str_windows("头尾都是", 12).collect::<Vec<_>>(), none);
+        assert_eq!(str_windows("s 😀😁", 5).rev().collect::<Vec<_>>(), none);
+        assert_eq!(str_windows("abc", 4).size_hint(), (0, Some(0)));
+        assert_eq!(str_windows("", 1).size_hint(), (0, Some(0)));
+        assert_eq!(str_windows("头尾都是", 5).size_hint(), (0, Some(0)));
+        assert_eq!(str_windows("头尾都是", 5).last(), None);
+        assert_eq!(str_windows("头尾都是", 5).as_str(), "头尾都是");
+        assert_eq!(str_windows("头尾都是", 4).collect::<Vec<_>>(), ["头尾都是"]);
+    }
+
+    #[test]
+    fn ext_test() {
+        for &src in &["hello, 你好, hi", "头尾都是", "s 😀😁", ""] {
+            for size in 0..=6 {
+                assert_eq!(
+                    src.char_windows(size).take(20).collect::<Vec<_>>(),
+                    str_windows(src, size).take(20).collect::<Vec<_>>(),
+                );
+            }
+        }
+        let owned = String::from("s 😀😁");
+        assert_eq!(owned.char_windows(3).collect::<Vec<_>>(), ["s 😀", " 😀😁"]);
+    }
+
+    #[test]
+    fn peek_test() {
+        let src = "hello, 你好, hi";
+        for size in 1..=5 {
+            let mut iter = str_windows(src, size);
+            iter.next_back();
+            loop {
+                for n in 0..16 {
+                    assert_eq!(iter.peek_nth(n), iter.clone().nth(n));
+                }
+                let peeked = iter.peek();
+                assert_eq!(iter.next(), peeked);
+                if peeked.is_none() {
+                    break;
+                }
+            }
+        }
+        let peeked = {
+            let iter = str_windows("头尾都是", 3);
+            iter.peek_nth(1)
+        };
+        assert_eq!(peeked, Some("尾都是"));
+        assert_eq!(str_windows("头尾都是", 0).peek(), Some(""));
+        assert_eq!(str_windows("头尾都是", 0).peek_nth(10), Some(""));
+    }
+
+    #[test]
+    fn get_test() {
+        for &src in &["hello, 你好, hi", "头尾都是", "s 😀😁"] {
+            for size in 0..=5 {
+                let mut iter = str_windows(src, size);
+                for _ in 0..3 {
+                    for i in 0..16 {
+                        assert_eq!(iter.get(i), iter.clone().nth(i));
+                    }
+                    iter.next();
+                    iter.next_back();
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn set_size_test() {
+        let src = "hello, 你好, hi";
+        for size in 1..=5 {
+            for new_size in 1..=5 {
+                for n in 0..14 {
+                    let mut iter = str_windows(src, size);
+                    iter.nth(n);
+                    let rest = iter.as_str();
+                    iter.set_size(new_size);
+                    assert_eq!(iter.clone().collect::<Vec<_>>(), str_windows(rest, new_size).collect::<Vec<_>>());
+                    assert_eq!(iter.offset(), src.len() - rest.len());
+                }
+            }
+        }
+
+        let mut iter = str_windows("头尾都是", 2);
+        iter.nth(1);
+        iter.set_size(4);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.size_hint().0, 0);
+
+        let mut iter = str_windows("头尾都是", 2);
+        iter.next_back();
+        iter.next_back();
+        iter.next_back();
+        assert_eq!(iter.as_str(), "头");
+        iter.set_size(1);
+        assert_eq!(iter.next(), Some("头"));
+
+        let mut iter = str_windows("头尾都是", 2);
+        iter.next();
+        iter.set_size(0);
+        assert_eq!(iter.next(), Some(""));
+        assert_eq!(iter.next_back(), Some(""));
+        iter.set_size(2);
+        assert_eq!(iter.next(), Some("尾都"));
+    }
+
+    #[test]
+    fn nonzero_test() {
+        for &src in &["hello, 你好, hi", "头尾都是", "s 😀😁", ""] {
+            for size in 1..=6 {
+                let nonzero = str_windows_nonzero(src, NonZeroUsize::new(size).unwrap());
+                let plain = str_windows(src, size);
+                assert_eq!(nonzero.size_hint(), plain.size_hint());
+                assert_eq!(nonzero.len(), plain.clone().count());
+                assert_eq!(nonzero.collect::<Vec<_>>(), plain.collect::<Vec<_>>());
+            }
+        }
+    }
+
+    const _: usize = window_count("héllo", 2);
+
+    #[test]
+    fn window_count_test() {
+        const COUNT: usize = window_count("héllo", 2);
+        assert_eq!(COUNT, 4);
+        for &src in &["hello, 你好, hi", "头尾都是", "s 😀😁", "héllo", ""] {
+            for size in 1..=16 {
+                assert_eq!(window_count(src, size), str_windows(src, size).count());
+            }
+        }
+        assert_eq!(window_count("héllo", 0), usize::MAX);
+    }
+
+    #[test]
+    fn split_at_test() {
+        for &src in &["hello, 你好, hi", "头尾都是", ": 尾部是", "s 😀😁"] {
+            for size in 1..=5 {
+                let mut iter = str_windows(src, size);
+                iter.next();
+                let expected = iter.clone().collect::<Vec<_>>();
+                for n in 0..16 {
+                    let (first, second) = iter.clone().split_at(n);
+                    assert_eq!(first.clone().count(), n.min(expected.len()));
+                    assert_eq!(first.chain(second).collect::<Vec<_>>(), expected);
+                }
+            }
+        }
+        let (first, mut second) = str_windows("头尾都是", 2).split_at(5);
+        assert_eq!(first.collect::<Vec<_>>(), ["头尾", "尾都", "都是"]);
+        assert_eq!(second.next(), None);
+        assert_eq!(second.next_back(), None);
     }
 }
@@ -0,0 +1,366 @@
+use std::iter::FusedIterator;
+
+/// Returns the end of the line of `input` starting at `i`, after its line
+/// ending, or `input.len()` for the last line without one.
+fn line_end(input: &str, i: usize) -> usize {
+    input[i..].find('\n').map_or(input.len(), |j| i + j + 1)
+}
+
+/// Returns the byte range of the first paragraph of `input` from the line
+/// starting at `i`, without the line ending of its last line.
+///
+/// A paragraph is a run of lines that aren't blank, a blank line being
+/// empty or only having whitespace.
+fn next_paragraph(input: &str, mut i: usize) -> Option<(usize, usize)> {
+    let mut paragraph: Option<(usize, usize)> = None;
+    while i != input.len() {
+        let end = line_end(input, i);
+        let line = &input[i..end];
+        if !line.trim().is_empty() {
+            let content = line.trim_end_matches('\n').trim_end_matches('\r');
+            paragraph = Some((paragraph.map_or(i, |x| x.0), i + content.len()));
+        } else if paragraph.is_some() {
+            break;
+        }
+        i = end;
+    }
+    paragraph
+}
+
+/// Returns the number of paragraphs of `input`.
+fn paragraph_count(input: &str) -> usize {
+    let mut count = 0;
+    let mut i = 0;
+    while let Some((_, end)) = next_paragraph(input, i) {
+        count += 1;
+        i = end;
+    }
+    count
+}
+
+/// Returns the windows of `n` consecutive paragraphs, each as the slice of
+/// the input from the start of its first paragraph to the end of its last
+/// one, with the blank lines between them, e.g. to split a document into
+/// overlapping passages.
+///
+/// Paragraphs are separated by blank lines, lines that are empty or only
+/// have whitespace, and lines end with `"\n"` or `"\r\n"`. A window starts
+/// at the first line of its first paragraph and stops before the line
+/// ending of its last line, so it never starts or ends with a blank line.
+/// An input of fewer than `n` paragraphs yields nothing.
+///
+/// # Panics
+///
+/// Panics if `n` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::paragraph_windows;
+///
+/// let text = "# Title\n\nFirst\nparagraph.\n\n\nSecond one.\n";
+/// let windows = paragraph_windows(text, 2).collect::<Vec<_>>();
+/// assert_eq!(windows, ["# Title\n\nFirst\nparagraph.", "First\nparagraph.\n\n\nSecond one."]);
+/// ```
+pub fn paragraph_windows(input: &str, n: usize) -> ParagraphWindows<'_> {
+    assert!(n != 0, "window size must be non-zero");
+    let first = next_paragraph(input, 0);
+    let mut last = first;
+    for _ in 1..n {
+        last = match last {
+            Some((_, end)) => next_paragraph(input, end),
+            None => break,
+        };
+    }
+    ParagraphWindows {
+        input,
+        first: first.unwrap_or((0, 0)),
+        last: last.map_or(0, |x| x.1),
+        len: (paragraph_count(input) + 1).saturating_sub(n),
+    }
+}
+
+/// An iterator over the windows of `n` consecutive paragraphs of a `&str`.
+///
+/// This struct is created by [`paragraph_windows`].
+#[derive(Clone, Debug)]
+pub struct ParagraphWindows<'a> {
+    input: &'a str,
+    /// Byte range of the first paragraph of the next window.
+    first: (usize, usize),
+    /// End of the last paragraph of the next window.
+    last: usize,
+    /// Number of windows left to yield.
+    len: usize,
+}
+
+impl<'a> FusedIterator for ParagraphWindows<'a> { }
+
+impl<'a> Iterator for ParagraphWindows<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        let window = &self.input[self.first.0..self.last];
+        if self.len != 0 {
+            // there are paragraphs after both ends
+            self.first = next_paragraph(self.input, self.first.1).unwrap();
+            self.last = next_paragraph(self.input, self.last).unwrap().1;
+        }
+        Some(window)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a> ExactSizeIterator for ParagraphWindows<'a> { }
+
+/// Returns the windows of `n` consecutive lines, each as the slice of the
+/// input from the start of its first line to the end of its last one,
+/// overlapping line by line like [`slice::windows`], e.g. to look for
+/// unusual runs of log lines.
+///
+/// Lines end with `"\n"`, and nothing is stripped: every line of a window
+/// keeps its line ending, `"\r\n"` included, so a window ends with the line
+/// ending of its last line. The last line of the input may not have one,
+/// then the last window doesn't either, and a trailing `"\n"` doesn't start
+/// another line. Empty lines count as lines. An input of fewer than `n`
+/// lines yields nothing.
+///
+/// # Panics
+///
+/// Panics if `n` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::line_windows;
+///
+/// let windows = line_windows("one\ntwo\r\nthree", 2).collect::<Vec<_>>();
+/// assert_eq!(windows, ["one\ntwo\r\n", "two\r\nthree"]);
+/// ```
+pub fn line_windows(input: &str, n: usize) -> LineWindows<'_> {
+    assert!(n != 0, "window size must be non-zero");
+    let lines = input.split_terminator('\n').count();
+    let mut end = 0;
+    for _ in 0..n.min(lines) {
+        end = line_end(input, end);
+    }
+    LineWindows {
+        input,
+        start: 0,
+        end,
+        len: (lines + 1).saturating_sub(n),
+    }
+}
+
+/// An iterator over the windows of `n` consecutive lines of a `&str`.
+///
+/// This struct is created by [`line_windows`].
+#[derive(Clone, Debug)]
+pub struct LineWindows<'a> {
+    input: &'a str,
+    /// Start of the first line of the next window.
+    start: usize,
+    /// End of the last line of the next window.
+    end: usize,
+    /// Number of windows left to yield.
+    len: usize,
+}
+
+impl<'a> FusedIterator for LineWindows<'a> { }
+
+impl<'a> Iterator for LineWindows<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        let window = &self.input[self.start..self.end];
+        self.start = line_end(self.input, self.start);
+        self.end = line_end(self.input, self.end);
+        Some(window)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a> ExactSizeIterator for LineWindows<'a> { }
+
+/// Returns the chunks of `n` consecutive lines, each as a slice of the
+/// input, without overlapping like [`slice::chunks`], e.g. to upload a log
+/// in batches.
+///
+/// The last chunk has fewer than `n` lines if the number of lines isn't a
+/// multiple of `n`. Lines are as with [`line_windows`], so the chunks keep
+/// all the line endings and add up to the input, unless
+/// [`strip_newline`](LineChunks::strip_newline) strips the line ending of
+/// the last line of each chunk, e.g. to display it.
+///
+/// # Panics
+///
+/// Panics if `n` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::line_chunks;
+///
+/// let chunks = line_chunks("a\nb\r\nc\nd", 3).collect::<Vec<_>>();
+/// assert_eq!(chunks, ["a\nb\r\nc\n", "d"]);
+///
+/// let chunks = line_chunks("a\nb\r\nc\nd", 2).strip_newline(true).collect::<Vec<_>>();
+/// assert_eq!(chunks, ["a\nb", "c\nd"]);
+/// ```
+pub fn line_chunks(input: &str, n: usize) -> LineChunks<'_> {
+    assert!(n != 0, "chunk size must be non-zero");
+    let lines = input.split_terminator('\n').count();
+    LineChunks {
+        input,
+        n,
+        strip_newline: false,
+        len: lines.div_ceil(n),
+    }
+}
+
+/// An iterator over the chunks of `n` consecutive lines of a `&str`.
+///
+/// This struct is created by [`line_chunks`].
+#[derive(Clone, Debug)]
+pub struct LineChunks<'a> {
+    input: &'a str,
+    n: usize,
+    strip_newline: bool,
+    /// Number of chunks left to yield.
+    len: usize,
+}
+
+impl<'a> LineChunks<'a> {
+    /// Sets whether to strip the `"\n"` or `"\r\n"` ending the last line of
+    /// each chunk. Defaults to `false`.
+    pub fn strip_newline(mut self, strip_newline: bool) -> Self {
+        self.strip_newline = strip_newline;
+        self
+    }
+}
+
+impl<'a> FusedIterator for LineChunks<'a> { }
+
+impl<'a> Iterator for LineChunks<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        let mut end = 0;
+        for _ in 0..self.n {
+            if end == self.input.len() {
+                break;
+            }
+            end = line_end(self.input, end);
+        }
+        let (chunk, rest) = self.input.split_at(end);
+        self.input = rest;
+        if self.strip_newline {
+            if let Some(chunk) = chunk.strip_suffix('\n') {
+                return Some(chunk.strip_suffix('\r').unwrap_or(chunk));
+            }
+        }
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a> ExactSizeIterator for LineChunks<'a> { }
+
+
+#[cfg(test)]
+mod tests {
+    use super::{line_chunks, line_windows, paragraph_count, paragraph_windows};
+
+    #[test]
+    fn paragraphs_test() {
+        let src = "\n  \none\ntwo\n\t\n\nthree\n \t \nfour";
+        let windows = paragraph_windows(src, 1).collect::<Vec<_>>();
+        assert_eq!(windows, ["one\ntwo", "three", "four"]);
+        let windows = paragraph_windows(src, 2).collect::<Vec<_>>();
+        assert_eq!(windows, ["one\ntwo\n\t\n\nthree", "three\n \t \nfour"]);
+        assert_eq!(paragraph_windows(src, 3).collect::<Vec<_>>(), [&src[4..]]);
+        assert_eq!(paragraph_windows(src, 4).next(), None);
+        assert_eq!(paragraph_windows(src, usize::MAX).next(), None);
+        assert_eq!(paragraph_count("  \n\t\n"), 0);
+        assert_eq!(paragraph_windows("", 1).next(), None);
+    }
+
+    #[test]
+    fn crlf_test() {
+        let src = "a\r\n  b\r\n \r\n\r\nc\r\n\r\n";
+        let windows = paragraph_windows(src, 1).collect::<Vec<_>>();
+        assert_eq!(windows, ["a\r\n  b", "c"]);
+        let iter = paragraph_windows(src, 2);
+        assert_eq!(iter.len(), 1);
+        assert_eq!(iter.collect::<Vec<_>>(), ["a\r\n  b\r\n \r\n\r\nc"]);
+        assert_eq!(paragraph_windows("a\r\nb\r", 1).collect::<Vec<_>>(), ["a\r\nb"]);
+    }
+
+    #[test]
+    fn lines_test() {
+        let src = "a\r\n\r\nb\n\nc\r\n";
+        let windows = line_windows(src, 2).collect::<Vec<_>>();
+        assert_eq!(windows, ["a\r\n\r\n", "\r\nb\n", "b\n\n", "\nc\r\n"]);
+        assert_eq!(line_windows(src, 5).collect::<Vec<_>>(), [src]);
+        assert_eq!(line_windows("x\ny", 1).collect::<Vec<_>>(), ["x\n", "y"]);
+        assert_eq!(line_windows("\n", 1).collect::<Vec<_>>(), ["\n"]);
+        let iter = line_windows("1\n2\n3\n4", 3);
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.collect::<Vec<_>>(), ["1\n2\n3\n", "2\n3\n4"]);
+    }
+
+    #[test]
+    fn few_lines() {
+        assert_eq!(line_windows("a\nb\n", 3).next(), None);
+        assert_eq!(line_windows("a\nb\n", 3).len(), 0);
+        assert_eq!(line_windows("", 1).next(), None);
+    }
+
+    #[test]
+    fn chunks_test() {
+        let src = "a\r\n\r\nb\n\nc\r\n";
+        for n in 1..=6 {
+            let iter = line_chunks(src, n);
+            assert_eq!(iter.len(), 5_usize.div_ceil(n));
+            assert_eq!(iter.collect::<String>(), src);
+        }
+        let chunks = line_chunks(src, 2).collect::<Vec<_>>();
+        assert_eq!(chunks, ["a\r\n\r\n", "b\n\n", "c\r\n"]);
+        let chunks = line_chunks(src, 2).strip_newline(true).collect::<Vec<_>>();
+        assert_eq!(chunks, ["a\r\n", "b\n", "c"]);
+        let chunks = line_chunks("x\ny\nz", 2).collect::<Vec<_>>();
+        assert_eq!(chunks, ["x\ny\n", "z"]);
+        let chunks = line_chunks("x\ny\nz", 2).strip_newline(true).collect::<Vec<_>>();
+        assert_eq!(chunks, ["x\ny", "z"]);
+        assert_eq!(line_chunks("", 1).next(), None);
+        assert_eq!(line_chunks(src, usize::MAX).collect::<Vec<_>>(), [src]);
+        assert_eq!(line_chunks("\n", 3).strip_newline(true).collect::<Vec<_>>(), [""]);
+    }
+
+    #[test]
+    #[should_panic(expected = "window size must be non-zero")]
+    fn zero_size() {
+        paragraph_windows("头尾都是", 0);
+    }
+}
@@ -0,0 +1,195 @@
+use std::iter::FusedIterator;
+use std::num::NonZeroUsize;
+
+use {Checkpoint, StrWindows};
+
+/// An iterator over overlapping windows of `size` chars of a `&str`, where
+/// `size` isn't 0.
+///
+/// This struct is created by [`str_windows_nonzero`], [`try_str_windows`]
+/// and [`str_windows_strict`]. It yields the same windows as [`StrWindows`],
+/// but as its size can't be 0 it always has a finite number of windows, so
+/// it also implements [`ExactSizeIterator`]. It converts into a
+/// [`StrWindows`] with `From`.
+///
+/// # Examples
+///
+/// ```
+/// use std::num::NonZeroUsize;
+/// use str_windows::{str_windows_nonzero, StrWindows};
+///
+/// let mut iter = str_windows_nonzero("头尾都是", NonZeroUsize::new(2).unwrap());
+/// assert_eq!(iter.len(), 3);
+/// assert_eq!(iter.next(), Some("头尾"));
+/// let rest = StrWindows::from(iter);
+/// assert_eq!(rest.collect::<Vec<_>>(), ["尾都", "都是"]);
+/// ```
+///
+/// [`str_windows_nonzero`]: crate::str_windows_nonzero
+/// [`try_str_windows`]: crate::try_str_windows
+/// [`str_windows_strict`]: crate::str_windows_strict
+#[derive(Clone, Debug)]
+pub struct StrWindowsNonZero<'a> {
+    inner: StrWindows<'a>,
+}
+
+impl<'a> StrWindowsNonZero<'a> {
+    pub(crate) fn new(input: &'a str, size: NonZeroUsize) -> Self {
+        StrWindowsNonZero {
+            inner: StrWindows::new(input, size.get()),
+        }
+    }
+
+    /// Same as [`StrWindows::as_str`].
+    pub fn as_str(&self) -> &'a str {
+        self.inner.as_str()
+    }
+
+    /// Same as [`StrWindows::offset`].
+    pub fn offset(&self) -> usize {
+        self.inner.offset()
+    }
+
+    /// Same as [`StrWindows::peek`].
+    pub fn peek(&self) -> Option<&'a str> {
+        self.inner.peek()
+    }
+
+    /// Same as [`StrWindows::peek_nth`].
+    pub fn peek_nth(&self, n: usize) -> Option<&'a str> {
+        self.inner.peek_nth(n)
+    }
+
+    /// Same as [`StrWindows::get`].
+    pub fn get(&self, i: usize) -> Option<&'a str> {
+        self.inner.get(i)
+    }
+
+    /// Same as [`StrWindows::advance_to_char`].
+    pub fn advance_to_char(&mut self, char_index: usize) {
+        self.inner.advance_to_char(char_index);
+    }
+
+    /// Same as [`StrWindows::reset`].
+    pub fn reset(&mut self) {
+        self.inner.reset();
+    }
+
+    /// Same as [`StrWindows::set_size`], with a size that can't be 0.
+    pub fn set_size(&mut self, size: NonZeroUsize) {
+        self.inner.set_size(size.get());
+    }
+
+    /// Same as [`StrWindows::split_at`].
+    pub fn split_at(self, n: usize) -> (StrWindowsNonZero<'a>, StrWindowsNonZero<'a>) {
+        let (first, second) = self.inner.split_at(n);
+        (StrWindowsNonZero { inner: first }, StrWindowsNonZero { inner: second })
+    }
+
+    /// Same as [`StrWindows::checkpoint`].
+    pub fn checkpoint(&self) -> Checkpoint {
+        self.inner.checkpoint()
+    }
+
+    /// Same as [`StrWindows::restore`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the checkpoint was taken from an iterator over a different
+    /// string or with a different window size.
+    pub fn restore(&mut self, checkpoint: Checkpoint) {
+        self.inner.restore(checkpoint);
+    }
+}
+
+impl<'a> From<StrWindowsNonZero<'a>> for StrWindows<'a> {
+    fn from(iter: StrWindowsNonZero<'a>) -> Self {
+        iter.inner
+    }
+}
+
+impl<'a> FusedIterator for StrWindowsNonZero<'a> { }
+
+impl<'a> Iterator for StrWindowsNonZero<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    fn count(self) -> usize {
+        self.inner.len
+    }
+
+    fn fold<B, F>(self, init: B, f: F) -> B
+        where F: FnMut(B, Self::Item) -> B,
+    {
+        self.inner.fold(init, f)
+    }
+
+    fn last(self) -> Option<Self::Item> {
+        self.inner.last()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.inner.nth(n)
+    }
+}
+
+impl<'a> ExactSizeIterator for StrWindowsNonZero<'a> {
+    fn len(&self) -> usize {
+        self.inner.len
+    }
+}
+
+impl<'a> DoubleEndedIterator for StrWindowsNonZero<'a> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.inner.nth_back(n)
+    }
+
+    fn rfold<B, F>(self, init: B, f: F) -> B
+        where F: FnMut(B, Self::Item) -> B,
+    {
+        self.inner.rfold(init, f)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use super::StrWindowsNonZero;
+    use {str_windows_nonzero, StrWindows};
+
+    fn nonzero(src: &str, size: usize) -> StrWindowsNonZero<'_> {
+        str_windows_nonzero(src, NonZeroUsize::new(size).unwrap())
+    }
+
+    #[test]
+    fn methods_test() {
+        let mut iter = nonzero("头尾都是", 2);
+        assert_eq!(iter.peek(), Some("头尾"));
+        iter.advance_to_char(1);
+        assert_eq!(iter.offset(), "头".len());
+        let checkpoint = iter.checkpoint();
+        iter.set_size(NonZeroUsize::new(3).unwrap());
+        assert_eq!(iter.as_str(), "尾都是");
+        assert_eq!(iter.len(), 1);
+        iter.reset();
+        assert_eq!(iter.get(1), Some("尾都是"));
+        let (first, second) = nonzero("头尾都是", 2).split_at(1);
+        assert_eq!((first.len(), second.len()), (1, 2));
+        let mut iter = nonzero("头尾都是", 2);
+        iter.restore(checkpoint);
+        assert_eq!(StrWindows::from(iter).collect::<Vec<_>>(), ["尾都", "都是"]);
+    }
+}
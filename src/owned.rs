@@ -0,0 +1,153 @@
+use std::iter::FusedIterator;
+
+use boundary::{next_char_boundary, nth_char_boundary};
+
+/// Returns the windows of `size` chars of [`str_windows`] over an owned
+/// `String`, e.g. to return the windows of a string built in a function.
+///
+/// The iterator owns the input, so it's `'static` and `Send`, and yields
+/// each window as a new `String`. To look at the windows without allocating,
+/// use [`for_each_window`](OwnedStrWindows::for_each_window).
+///
+/// # Panics
+///
+/// Panics if `size` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::{str_windows_owned, OwnedStrWindows};
+///
+/// fn windows(name: &str) -> OwnedStrWindows {
+///     str_windows_owned(format!("头{}", name), 2)
+/// }
+///
+/// assert_eq!(windows("尾😀").collect::<Vec<_>>(), ["头尾", "尾😀"]);
+/// ```
+///
+/// [`str_windows`]: crate::str_windows
+pub fn str_windows_owned(input: String, size: usize) -> OwnedStrWindows {
+    assert!(size != 0, "window size must be non-zero");
+    let chars = input.chars().count();
+    let end = nth_char_boundary(&input, size - 1);
+    OwnedStrWindows {
+        start: 0,
+        end: if end < input.len() { next_char_boundary(&input, end) } else { end },
+        len: (chars + 1).saturating_sub(size),
+        input,
+    }
+}
+
+/// An iterator over the windows of `size` chars of a `String` it owns.
+///
+/// This struct is created by [`str_windows_owned`].
+#[derive(Clone, Debug)]
+pub struct OwnedStrWindows {
+    input: String,
+    /// Start of the next window.
+    start: usize,
+    /// End of the next window.
+    end: usize,
+    /// Number of windows left to yield.
+    len: usize,
+}
+
+impl OwnedStrWindows {
+    /// Calls `f` on each window left, as a slice of the input, without
+    /// allocating them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use str_windows::str_windows_owned;
+    ///
+    /// let mut windows = Vec::new();
+    /// str_windows_owned("a头尾".to_string(), 2).for_each_window(|window| {
+    ///     windows.push(window.len());
+    /// });
+    /// assert_eq!(windows, [4, 6]);
+    /// ```
+    pub fn for_each_window<F: FnMut(&str)>(&mut self, mut f: F) {
+        while let Some(window) = self.next_window() {
+            f(window);
+        }
+    }
+
+    /// Returns the input given to [`str_windows_owned`].
+    pub fn into_string(self) -> String {
+        self.input
+    }
+
+    fn next_window(&mut self) -> Option<&str> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        let window = self.start..self.end;
+        self.start = next_char_boundary(&self.input, self.start);
+        // there's no char after the last window
+        if self.len != 0 {
+            self.end = next_char_boundary(&self.input, self.end);
+        }
+        Some(&self.input[window])
+    }
+}
+
+impl FusedIterator for OwnedStrWindows { }
+
+impl Iterator for OwnedStrWindows {
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_window().map(str::to_string)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl ExactSizeIterator for OwnedStrWindows { }
+
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use super::{str_windows_owned, OwnedStrWindows};
+    use str_windows;
+
+    #[test]
+    fn owned_test() {
+        for &src in &["hello, 你好, hi", "头尾都是", "头部是!", ": 尾部是", "s 😀😁", ""] {
+            for size in 1..=6 {
+                let iter = str_windows_owned(src.to_string(), size);
+                assert_eq!(iter.len(), str_windows(src, size).count());
+                assert!(iter.clone().eq(str_windows(src, size)));
+                let mut windows = Vec::new();
+                iter.clone().for_each_window(|window| windows.push(window.to_string()));
+                assert!(windows.into_iter().eq(iter));
+            }
+        }
+    }
+
+    fn windows() -> OwnedStrWindows {
+        let input = ["头", "尾", "😀"].concat();
+        str_windows_owned(input, 2)
+    }
+
+    #[test]
+    fn send_test() {
+        let mut iter = windows();
+        assert_eq!(iter.next().as_deref(), Some("头尾"));
+        let windows = thread::spawn(move || iter.collect::<Vec<_>>()).join().unwrap();
+        assert_eq!(windows, ["尾😀"]);
+        assert_eq!(str_windows_owned("头尾".to_string(), 2).into_string(), "头尾");
+    }
+
+    #[test]
+    #[should_panic(expected = "window size must be non-zero")]
+    fn zero_size() {
+        str_windows_owned("头尾都是".to_string(), 0);
+    }
+}
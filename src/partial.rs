@@ -0,0 +1,492 @@
+use std::borrow::Cow;
+use std::iter::FusedIterator;
+
+use boundary::{next_char_boundary, nth_char_boundary};
+
+/// Returns substrings of length `size` like [`str_windows`], followed by the
+/// shorter suffixes of the input down to its last char.
+///
+/// One window starts at every char of the input, so there are as many
+/// windows as chars. An input shorter than `size` yields its suffixes.
+///
+/// # Panics
+///
+/// Panics if `size` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::str_windows_partial;
+///
+/// let mut iter = str_windows_partial("abc", 2);
+/// assert_eq!(iter.next(), Some("ab"));
+/// assert_eq!(iter.next(), Some("bc"));
+/// assert_eq!(iter.next(), Some("c"));
+/// assert!(iter.next().is_none());
+/// ```
+///
+/// [`str_windows`]: crate::str_windows
+pub fn str_windows_partial(input: &str, size: usize) -> StrWindowsPartial<'_> {
+    StrWindowsPartial::new(input, size, false, true)
+}
+
+/// Returns the growing prefixes of the input up to `size - 1` chars,
+/// followed by the substrings of length `size` like [`str_windows`].
+///
+/// One window ends at every char of the input, so there are as many windows
+/// as chars. An input shorter than `size` yields its prefixes.
+///
+/// # Panics
+///
+/// Panics if `size` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::str_windows_ramp;
+///
+/// let mut iter = str_windows_ramp("😀bcd", 3);
+/// assert_eq!(iter.next(), Some("😀"));
+/// assert_eq!(iter.next(), Some("😀b"));
+/// assert_eq!(iter.next(), Some("😀bc"));
+/// assert_eq!(iter.next(), Some("bcd"));
+/// assert!(iter.next().is_none());
+/// ```
+///
+/// [`str_windows`]: crate::str_windows
+pub fn str_windows_ramp(input: &str, size: usize) -> StrWindowsPartial<'_> {
+    StrWindowsPartial::new(input, size, true, false)
+}
+
+/// Combines [`str_windows_ramp`] and [`str_windows_partial`]: the growing
+/// prefixes, the windows of `size` chars, then the shrinking suffixes.
+///
+/// This is as if the input was padded with `size - 1` empty slots on both
+/// sides, so there are `chars + size - 1` windows for a non-empty input, or
+/// `usize::MAX` if there are more. An input shorter than `size` yields its
+/// shorter prefixes, then the whole input `size - chars + 1` times, then its
+/// shorter suffixes.
+///
+/// # Panics
+///
+/// Panics if `size` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::str_windows_ramp_partial;
+///
+/// let windows = str_windows_ramp_partial("abc", 2).collect::<Vec<_>>();
+/// assert_eq!(windows, ["a", "ab", "bc", "c"]);
+/// ```
+pub fn str_windows_ramp_partial(input: &str, size: usize) -> StrWindowsPartial<'_> {
+    StrWindowsPartial::new(input, size, true, true)
+}
+
+/// An iterator over windows of `size` chars of a `&str`, together with
+/// shorter windows at the ends of the input.
+///
+/// This struct is created by [`str_windows_partial`], [`str_windows_ramp`]
+/// and [`str_windows_ramp_partial`].
+#[derive(Clone, Debug)]
+pub struct StrWindowsPartial<'a> {
+    input: &'a str,
+    /// Byte range of the next window.
+    start: usize,
+    end: usize,
+    /// Number of upcoming steps where only the end of the window moves.
+    lead: usize,
+    /// Number of windows left to yield.
+    len: usize,
+}
+
+impl<'a> StrWindowsPartial<'a> {
+    pub(crate) fn new(input: &'a str, size: usize, leading: bool, trailing: bool) -> Self {
+        assert!(size != 0, "window size must be non-zero");
+        let chars = input.chars().count();
+        let lead = if leading { size - 1 } else { 0 };
+        let len = if !trailing {
+            // the leading windows make up for the missing full windows
+            (chars + 1).saturating_sub(size - lead)
+        } else if chars == 0 {
+            0
+        } else {
+            chars.saturating_add(lead)
+        };
+        let end = if leading { 1 } else { size };
+        StrWindowsPartial {
+            input,
+            start: 0,
+            end: nth_char_boundary(input, end),
+            lead,
+            len,
+        }
+    }
+}
+
+impl<'a> FusedIterator for StrWindowsPartial<'a> { }
+
+impl<'a> Iterator for StrWindowsPartial<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        let window = &self.input[self.start..self.end];
+        if self.lead == 0 {
+            self.start = next_char_boundary(self.input, self.start);
+        } else {
+            self.lead -= 1;
+        }
+        self.end = next_char_boundary(self.input, self.end);
+        Some(window)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a> ExactSizeIterator for StrWindowsPartial<'a> { }
+
+/// Returns windows of exactly `size` chars starting at every char of the
+/// input, padding the windows that run past its end with `pad`.
+///
+/// Full windows are borrowed from the input, the last `size - 1` windows are
+/// owned with the padding appended. There are as many windows as chars.
+///
+/// # Panics
+///
+/// Panics if `size` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use std::borrow::Cow;
+/// use str_windows::str_windows_padded;
+///
+/// let mut iter = str_windows_padded("hi", 3, '·');
+/// assert_eq!(iter.next(), Some(Cow::Owned("hi·".to_string())));
+/// assert_eq!(iter.next(), Some(Cow::Owned("i··".to_string())));
+/// assert!(iter.next().is_none());
+/// ```
+pub fn str_windows_padded(input: &str, size: usize, pad: char) -> StrWindowsPadded<'_> {
+    StrWindowsPadded {
+        inner: StrWindowsPartial::new(input, size, false, true),
+        size,
+        pad,
+    }
+}
+
+/// An iterator over windows of `size` chars of a `&str`, padded at its end.
+///
+/// This struct is created by [`str_windows_padded`].
+#[derive(Clone, Debug)]
+pub struct StrWindowsPadded<'a> {
+    inner: StrWindowsPartial<'a>,
+    size: usize,
+    pad: char,
+}
+
+impl<'a> FusedIterator for StrWindowsPadded<'a> { }
+
+impl<'a> Iterator for StrWindowsPadded<'a> {
+    type Item = Cow<'a, str>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let window = self.inner.next()?;
+        // the window starts `len + 1` chars before the end of the input
+        let missing = self.size.saturating_sub(self.inner.len + 1);
+        if missing == 0 {
+            return Some(Cow::Borrowed(window));
+        }
+        let mut padded = String::with_capacity(window.len() + missing * self.pad.len_utf8());
+        padded.push_str(window);
+        padded.extend((0..missing).map(|_| self.pad));
+        Some(Cow::Owned(padded))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a> ExactSizeIterator for StrWindowsPadded<'a> { }
+
+/// Returns the windows of `size` chars of the input surrounded by `size - 1`
+/// `start` markers and `size - 1` `end` markers, as n-gram models pad their
+/// input, e.g. `"^^h"`, `"^hi"`, `"hi$"` and `"i$$"` for trigrams of `"hi"`.
+///
+/// The padded string isn't built: windows inside the input are borrowed
+/// from it, only those with markers are owned. There are `chars + size - 1`
+/// windows, or `usize::MAX` if there are more, so an empty input still
+/// yields the windows of markers only.
+///
+/// # Panics
+///
+/// Panics if `size` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::str_windows_marked;
+///
+/// let windows = str_windows_marked("hi", 3, '^', '$').collect::<Vec<_>>();
+/// assert_eq!(windows, ["^^h", "^hi", "hi$", "i$$"]);
+/// ```
+pub fn str_windows_marked(
+    input: &str,
+    size: usize,
+    start: char,
+    end: char,
+) -> StrWindowsMarked<'_> {
+    assert!(size != 0, "window size must be non-zero");
+    let chars = input.chars().count();
+    StrWindowsMarked {
+        input,
+        start: 0,
+        end: next_char_boundary(input, 0),
+        pos: 0,
+        chars,
+        size,
+        markers: (start, end),
+        len: chars.saturating_add(size - 1),
+    }
+}
+
+/// An iterator over windows of `size` chars of a `&str` padded with start
+/// and end markers.
+///
+/// This struct is created by [`str_windows_marked`].
+#[derive(Clone, Debug)]
+pub struct StrWindowsMarked<'a> {
+    input: &'a str,
+    /// Byte range of the part of the input in the next window.
+    start: usize,
+    end: usize,
+    /// Index of the next window, which has `size - 1 - pos` start markers if
+    /// `pos < size - 1`.
+    pos: usize,
+    chars: usize,
+    size: usize,
+    markers: (char, char),
+    /// Number of windows left to yield.
+    len: usize,
+}
+
+impl<'a> FusedIterator for StrWindowsMarked<'a> { }
+
+impl<'a> Iterator for StrWindowsMarked<'a> {
+    type Item = Cow<'a, str>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        // the window covers the chars `pos + 1 - size..pos + 1` of the input
+        let leading = (self.size - 1).saturating_sub(self.pos);
+        let trailing = (self.pos + 1).saturating_sub(self.chars);
+        let inner = &self.input[self.start..self.end];
+        if self.pos + 1 >= self.size {
+            self.start = next_char_boundary(self.input, self.start);
+        }
+        self.end = next_char_boundary(self.input, self.end);
+        self.pos += 1;
+        if leading == 0 && trailing == 0 {
+            return Some(Cow::Borrowed(inner));
+        }
+        let (start, end) = self.markers;
+        let mut window = String::with_capacity(
+            inner.len() + leading * start.len_utf8() + trailing * end.len_utf8(),
+        );
+        window.extend((0..leading).map(|_| start));
+        window.push_str(inner);
+        window.extend((0..trailing).map(|_| end));
+        Some(Cow::Owned(window))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a> ExactSizeIterator for StrWindowsMarked<'a> { }
+
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+    use super::{
+        str_windows_marked, str_windows_padded, str_windows_partial, str_windows_ramp,
+        str_windows_ramp_partial,
+    };
+    use str_windows;
+
+    #[test]
+    fn trailing_test() {
+        assert_eq!(str_windows_partial("头尾都是", 3).collect::<Vec<_>>(), ["头尾都", "尾都是", "都是", "是"]);
+        assert_eq!(str_windows_partial("头尾", 3).collect::<Vec<_>>(), ["头尾", "尾"]);
+        assert_eq!(str_windows_partial("😀", 1).collect::<Vec<_>>(), ["😀"]);
+        assert_eq!(str_windows_partial("", 2).next(), None);
+
+        for &src in &["hello, 你好, hi", "头尾都是", "头部是!", ": 尾部是", "s 😀😁"] {
+            for size in 1..=6 {
+                let iter = str_windows_partial(src, size);
+                assert_eq!(iter.len(), src.chars().count());
+                let windows = iter.collect::<Vec<_>>();
+                let full = str_windows(src, size).collect::<Vec<_>>();
+                assert_eq!(windows[..full.len()], full[..]);
+                for (i, window) in windows.iter().enumerate() {
+                    assert!(src[src.char_indices().nth(i).unwrap().0..].starts_with(window));
+                    assert_eq!(window.chars().count(), size.min(src.chars().count() - i));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn leading_test() {
+        assert_eq!(str_windows_ramp("头尾都是", 3).collect::<Vec<_>>(), ["头", "头尾", "头尾都", "尾都是"]);
+        assert_eq!(str_windows_ramp("😀😁!", 2).collect::<Vec<_>>(), ["😀", "😀😁", "😁!"]);
+        assert_eq!(str_windows_ramp("头尾", 3).collect::<Vec<_>>(), ["头", "头尾"]);
+        assert_eq!(str_windows_ramp("", 2).next(), None);
+
+        for &src in &["hello, 你好, hi", "头尾都是", "😀部是!", ": 尾部是", "s 😀😁"] {
+            let chars = src.chars().count();
+            for size in 1..=6 {
+                let iter = str_windows_ramp(src, size);
+                assert_eq!(iter.len(), chars);
+                let windows = iter.collect::<Vec<_>>();
+                let full = str_windows(src, size).collect::<Vec<_>>();
+                assert_eq!(windows[chars - full.len()..], full[..]);
+                for (i, window) in windows.iter().enumerate() {
+                    let end = src.char_indices().nth(i + 1).map_or(src.len(), |x| x.0);
+                    assert!(src[..end].ends_with(window));
+                    assert_eq!(window.chars().count(), size.min(i + 1));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn both_test() {
+        assert_eq!(
+            str_windows_ramp_partial("😀尾都是", 3).collect::<Vec<_>>(),
+            ["😀", "😀尾", "😀尾都", "尾都是", "都是", "是"],
+        );
+        assert_eq!(str_windows_ramp_partial("头尾", 3).collect::<Vec<_>>(), ["头", "头尾", "头尾", "尾"]);
+        assert_eq!(str_windows_ramp_partial("头", 1).collect::<Vec<_>>(), ["头"]);
+        assert_eq!(str_windows_ramp_partial("", 3).next(), None);
+
+        for &src in &["hello, 你好, hi", "头尾都是", "s 😀😁"] {
+            for size in 1..=6 {
+                let iter = str_windows_ramp_partial(src, size);
+                assert_eq!(iter.len(), src.chars().count() + size - 1);
+                assert_eq!(iter.count(), src.chars().count() + size - 1);
+            }
+        }
+    }
+
+    #[test]
+    fn whole_input_test() {
+        for size in 2..=6 {
+            let windows = str_windows_ramp_partial("头尾", size).collect::<Vec<_>>();
+            let whole = windows.iter().filter(|&&window| window == "头尾").count();
+            assert_eq!(whole, size - 2 + 1);
+        }
+    }
+
+    #[test]
+    fn large_size_test() {
+        let iter = str_windows_ramp("头😀", usize::MAX);
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.collect::<Vec<_>>(), ["头", "头😀"]);
+        assert_eq!(str_windows_partial("头😀", usize::MAX).collect::<Vec<_>>(), ["头😀", "😀"]);
+        let iter = str_windows_ramp_partial("头😀", usize::MAX);
+        assert_eq!(iter.len(), usize::MAX);
+        assert_eq!(iter.take(3).collect::<Vec<_>>(), ["头", "头😀", "头😀"]);
+    }
+
+    #[test]
+    fn size_hint_test() {
+        let mut iter = str_windows_partial("头尾都", 2);
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+        iter.next();
+        iter.next();
+        assert_eq!(iter.size_hint(), (1, Some(1)));
+        assert_eq!(iter.next(), Some("都"));
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+    }
+
+    #[test]
+    #[should_panic(expected = "window size must be non-zero")]
+    fn zero_size() {
+        str_windows_partial("头尾都是", 0);
+    }
+
+    #[test]
+    fn padded_test() {
+        let windows = str_windows_padded("hi", 3, '·').collect::<Vec<_>>();
+        assert_eq!(windows, ["hi·", "i··"]);
+
+        let windows = str_windows_padded("头尾都是", 3, '😀').collect::<Vec<_>>();
+        assert_eq!(windows, ["头尾都", "尾都是", "都是😀", "是😀😀"]);
+        assert!(matches!(windows[1], Cow::Borrowed(_)));
+        assert!(matches!(windows[2], Cow::Owned(_)));
+
+        assert_eq!(str_windows_padded("", 3, '_').next(), None);
+
+        for &src in &["hello, 你好, hi", "头尾都是", "s 😀😁"] {
+            for size in 1..=6 {
+                let iter = str_windows_padded(src, size, '＿');
+                assert_eq!(iter.len(), src.chars().count());
+                for window in iter {
+                    assert_eq!(window.chars().count(), size);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn marked_test() {
+        let windows = str_windows_marked("头尾", 2, '⟨', '⟩').collect::<Vec<_>>();
+        assert_eq!(windows, ["⟨头", "头尾", "尾⟩"]);
+        assert!(matches!(windows[1], Cow::Borrowed(_)));
+        assert!(matches!(windows[2], Cow::Owned(_)));
+        let windows = str_windows_marked("😀", 3, '^', '$').collect::<Vec<_>>();
+        assert_eq!(windows, ["^^😀", "^😀$", "😀$$"]);
+        let windows = str_windows_marked("", 3, '^', '$').collect::<Vec<_>>();
+        assert_eq!(windows, ["^^$", "^$$"]);
+        assert_eq!(str_windows_marked("", 1, '^', '$').next(), None);
+        let windows = str_windows_marked("ab", 1, '^', '$').collect::<Vec<_>>();
+        assert_eq!(windows, ["a", "b"]);
+
+        for &src in &["hello, 你好, hi", "头尾都是", "s 😀😁", "😀", ""] {
+            for size in 1..=6 {
+                let iter = str_windows_marked(src, size, '^', '😀');
+                assert_eq!(iter.len(), src.chars().count() + size - 1);
+                let markers = "^".repeat(size - 1);
+                let padded = format!("{}{}{}", markers, src, "😀".repeat(size - 1));
+                let expected = str_windows(&padded, size).collect::<Vec<_>>();
+                assert_eq!(iter.collect::<Vec<_>>(), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn marked_large_size_test() {
+        let iter = str_windows_marked("ab", usize::MAX, '^', '$');
+        assert_eq!(iter.len(), usize::MAX);
+        assert_eq!(iter.size_hint(), (usize::MAX, Some(usize::MAX)));
+        assert_eq!(str_windows_marked("", usize::MAX - 1, '^', '$').len(), usize::MAX - 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "window size must be non-zero")]
+    fn marked_zero_size() {
+        str_windows_marked("头尾都是", 0, '^', '$');
+    }
+}
@@ -0,0 +1,154 @@
+use std::iter::FusedIterator;
+
+use {str_windows_strict, StrWindowsNonZero};
+
+/// The position of a char in a text, as reported by editors.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Position {
+    /// The line of the char, starting at 1.
+    pub line: usize,
+    /// The column of the char in its line, starting at 1, counted in chars
+    /// or in UTF-16 code units.
+    pub column: usize,
+    /// The byte offset of the char in the text.
+    pub byte: usize,
+}
+
+/// Returns the windows of `size` chars of [`str_windows`] with the position
+/// of their first char, e.g. to report diagnostics found in the windows.
+///
+/// Lines end with `"\n"`, so a `"\r\n"` ending is part of the line it ends,
+/// and a lone `'\r'` doesn't end a line. Columns count chars by default, or
+/// UTF-16 code units as in the Language Server Protocol with
+/// [`utf16_columns`](StrWindowsPositions::utf16_columns). The positions are
+/// tracked in a single pass, from the first char of each window to the next.
+///
+/// # Panics
+///
+/// Panics if `size` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::{str_windows_positions, Position};
+///
+/// let mut iter = str_windows_positions("ab\r\ncd", 2);
+/// assert_eq!(iter.next(), Some((Position { line: 1, column: 1, byte: 0 }, "ab")));
+/// assert_eq!(iter.last(), Some((Position { line: 2, column: 1, byte: 4 }, "cd")));
+/// ```
+///
+/// [`str_windows`]: crate::str_windows
+pub fn str_windows_positions(input: &str, size: usize) -> StrWindowsPositions<'_> {
+    StrWindowsPositions {
+        inner: str_windows_strict(input, size),
+        position: Position {
+            line: 1,
+            column: 1,
+            byte: 0,
+        },
+        utf16_columns: false,
+    }
+}
+
+/// An iterator over the windows of `size` chars of a `&str` and the
+/// positions of their first chars.
+///
+/// This struct is created by [`str_windows_positions`].
+#[derive(Clone, Debug)]
+pub struct StrWindowsPositions<'a> {
+    inner: StrWindowsNonZero<'a>,
+    /// The position of the first char of the next window.
+    position: Position,
+    utf16_columns: bool,
+}
+
+impl<'a> StrWindowsPositions<'a> {
+    /// Sets whether to count columns in UTF-16 code units instead of chars.
+    /// Defaults to `false`.
+    pub fn utf16_columns(mut self, utf16_columns: bool) -> Self {
+        self.utf16_columns = utf16_columns;
+        self
+    }
+}
+
+impl<'a> FusedIterator for StrWindowsPositions<'a> { }
+
+impl<'a> Iterator for StrWindowsPositions<'a> {
+    type Item = (Position, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let window = self.inner.next()?;
+        let position = self.position;
+        // windows aren't empty
+        let c = window.chars().next().unwrap();
+        self.position.byte += c.len_utf8();
+        if c == '\n' {
+            self.position.line += 1;
+            self.position.column = 1;
+        } else if self.utf16_columns {
+            self.position.column += c.len_utf16();
+        } else {
+            self.position.column += 1;
+        }
+        Some((position, window))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a> ExactSizeIterator for StrWindowsPositions<'a> { }
+
+
+#[cfg(test)]
+mod tests {
+    use super::{str_windows_positions, Position};
+
+    fn position(line: usize, column: usize, byte: usize) -> Position {
+        Position { line, column, byte }
+    }
+
+    #[test]
+    fn positions_test() {
+        let windows = str_windows_positions("ab\ncd\n\ne", 3).collect::<Vec<_>>();
+        let expected = [
+            (position(1, 1, 0), "ab\n"),
+            (position(1, 2, 1), "b\nc"),
+            (position(1, 3, 2), "\ncd"),
+            (position(2, 1, 3), "cd\n"),
+            (position(2, 2, 4), "d\n\n"),
+            (position(2, 3, 5), "\n\ne"),
+        ];
+        assert_eq!(windows, expected);
+        assert_eq!(str_windows_positions("ab", 3).next(), None);
+    }
+
+    #[test]
+    fn crlf_test() {
+        let src = "头\r\n😀x\r\n";
+        let windows = str_windows_positions(src, 2).collect::<Vec<_>>();
+        let expected = [
+            (position(1, 1, 0), "头\r"),
+            (position(1, 2, 3), "\r\n"),
+            (position(1, 3, 4), "\n😀"),
+            (position(2, 1, 5), "😀x"),
+            (position(2, 2, 9), "x\r"),
+            (position(2, 3, 10), "\r\n"),
+        ];
+        assert_eq!(windows, expected);
+        let columns = str_windows_positions(src, 1).utf16_columns(true).map(|x| x.0.column);
+        assert_eq!(columns.collect::<Vec<_>>(), [1, 2, 3, 1, 3, 4, 5]);
+        let iter = str_windows_positions(src, 1);
+        assert_eq!(iter.len(), 7);
+        for (position, window) in iter {
+            assert!(src[position.byte..].starts_with(window));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "window size must be non-zero")]
+    fn zero_size() {
+        str_windows_positions("头尾都是", 0);
+    }
+}
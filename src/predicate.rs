@@ -0,0 +1,285 @@
+use std::borrow::Cow;
+use std::fmt;
+use std::iter::FusedIterator;
+
+use boundary::next_char_boundary;
+
+/// Returns, at every char of the input, the longest window of at most `max`
+/// chars that all satisfy `pred`, e.g. identifier or number runs with
+/// `char::is_alphanumeric`.
+///
+/// Positions whose first char fails `pred` yield nothing, so every window is
+/// non-empty. The windows overlap like those of [`str_windows`]: a run of
+/// passing chars yields one window per char, the last ones getting shorter.
+///
+/// # Panics
+///
+/// Panics if `max` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::str_windows_while;
+///
+/// let windows = str_windows_while("ab+1", 5, char::is_alphanumeric).collect::<Vec<_>>();
+/// assert_eq!(windows, ["ab", "b", "1"]);
+/// ```
+///
+/// [`str_windows`]: crate::str_windows
+pub fn str_windows_while<P>(input: &str, max: usize, pred: P) -> StrWindowsWhile<'_, P>
+where
+    P: Fn(char) -> bool,
+{
+    assert!(max != 0, "window size must be non-zero");
+    StrWindowsWhile {
+        inner: input,
+        max,
+        pred,
+    }
+}
+
+/// An iterator over the longest windows of chars satisfying a predicate at
+/// every char of a `&str`.
+///
+/// This struct is created by [`str_windows_while`].
+#[derive(Clone)]
+pub struct StrWindowsWhile<'a, P> {
+    /// The input from the next position.
+    inner: &'a str,
+    max: usize,
+    pred: P,
+}
+
+impl<'a, P> fmt::Debug for StrWindowsWhile<'a, P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("StrWindowsWhile")
+            .field("remaining", &self.inner)
+            .field("max", &self.max)
+            .finish()
+    }
+}
+
+impl<'a, P: Fn(char) -> bool> FusedIterator for StrWindowsWhile<'a, P> { }
+
+impl<'a, P: Fn(char) -> bool> Iterator for StrWindowsWhile<'a, P> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let pred = &self.pred;
+        let start = self.inner.find(pred)?;
+        let rest = &self.inner[start..];
+        let end = rest.char_indices()
+            .take(self.max)
+            .take_while(|&(_, c)| pred(c))
+            .last()
+            .map_or(0, |(i, c)| i + c.len_utf8());
+        self.inner = &rest[next_char_boundary(rest, 0)..];
+        Some(&rest[..end])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.inner.len()))
+    }
+}
+
+/// Returns the byte range of the first char of `input` from `from` that
+/// satisfies `keep`.
+fn next_kept<P: Fn(char) -> bool>(input: &str, from: usize, keep: &P) -> Option<(usize, usize)> {
+    input[from..].char_indices()
+        .find(|&(_, c)| keep(c))
+        .map(|(i, c)| (from + i, from + i + c.len_utf8()))
+}
+
+/// Returns the windows of `size` chars of the input with the chars failing
+/// `keep` removed, together with the byte offset of each window in the
+/// input, e.g. n-grams of letters ignoring punctuation and spaces.
+///
+/// Each offset is where the first char of the window is in the input. A
+/// window is borrowed from the input when its chars are next to each other
+/// there, i.e. when no char was removed between them, otherwise they are
+/// collected into a `String`.
+///
+/// # Panics
+///
+/// Panics if `size` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use std::borrow::Cow;
+/// use str_windows::str_windows_filtered;
+///
+/// let mut iter = str_windows_filtered("ab, c", 2, char::is_alphabetic);
+/// assert_eq!(iter.next(), Some((0, Cow::Borrowed("ab"))));
+/// assert_eq!(iter.next(), Some((1, Cow::Owned("bc".to_string()))));
+/// assert!(iter.next().is_none());
+/// ```
+pub fn str_windows_filtered<P>(input: &str, size: usize, keep: P) -> StrWindowsFiltered<'_, P>
+where
+    P: Fn(char) -> bool,
+{
+    assert!(size != 0, "window size must be non-zero");
+    let mut window = next_kept(input, 0, &keep);
+    for _ in 1..size {
+        window = match window {
+            Some((start, end)) => next_kept(input, end, &keep).map(|(_, end)| (start, end)),
+            None => break,
+        };
+    }
+    StrWindowsFiltered {
+        input,
+        window,
+        size,
+        keep,
+    }
+}
+
+/// An iterator over the windows of `size` chars of a `&str` satisfying a
+/// predicate, and their byte offsets.
+///
+/// This struct is created by [`str_windows_filtered`].
+#[derive(Clone)]
+pub struct StrWindowsFiltered<'a, P> {
+    input: &'a str,
+    /// Byte range of the next window, from its first char to its last one.
+    window: Option<(usize, usize)>,
+    size: usize,
+    keep: P,
+}
+
+impl<'a, P> fmt::Debug for StrWindowsFiltered<'a, P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let remaining = self.window.map_or("", |(start, _)| &self.input[start..]);
+        f.debug_struct("StrWindowsFiltered")
+            .field("remaining", &remaining)
+            .field("size", &self.size)
+            .finish()
+    }
+}
+
+impl<'a, P: Fn(char) -> bool> FusedIterator for StrWindowsFiltered<'a, P> { }
+
+impl<'a, P: Fn(char) -> bool> Iterator for StrWindowsFiltered<'a, P> {
+    type Item = (usize, Cow<'a, str>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (start, end) = self.window?;
+        let input = self.input;
+        let keep = &self.keep;
+        self.window = next_kept(input, end, keep).map(|(_, end)| {
+            // the window has a kept char after its first one
+            let (start, _) = next_kept(input, next_char_boundary(input, start), keep).unwrap();
+            (start, end)
+        });
+        let span = &input[start..end];
+        let window = if span.chars().all(keep) {
+            Cow::Borrowed(span)
+        } else {
+            Cow::Owned(span.chars().filter(|&c| keep(c)).collect())
+        };
+        Some((start, window))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.window {
+            Some((_, end)) => (1, Some(self.input[end..].len() + 1)),
+            None => (0, Some(0)),
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+    use super::{str_windows_filtered, str_windows_while};
+    use str_windows;
+
+    #[test]
+    fn while_test() {
+        let windows = str_windows_while("x_1 变量2", 3, |c| c.is_alphanumeric() || c == '_')
+            .collect::<Vec<_>>();
+        assert_eq!(windows, ["x_1", "_1", "1", "变量2", "量2", "2"]);
+        let windows = str_windows_while("-42.5", 2, |c| c.is_ascii_digit()).collect::<Vec<_>>();
+        assert_eq!(windows, ["42", "2", "5"]);
+        let windows = str_windows_while("😀😁a😂", 9, |c| !c.is_ascii()).collect::<Vec<_>>();
+        assert_eq!(windows, ["😀😁", "😁", "😂"]);
+        assert_eq!(str_windows_while("头尾", 2, |_| false).next(), None);
+        assert_eq!(str_windows_while("", 2, |_| true).next(), None);
+    }
+
+    #[test]
+    fn naive_test() {
+        for &src in &["hello, 你好, hi", "ab12 c3 😀d", "头尾都是", ""] {
+            for max in 1..=5 {
+                let pred = |c: char| c.is_alphanumeric();
+                let expected = src.char_indices()
+                    .filter(|&(_, c)| pred(c))
+                    .map(|(i, _)| {
+                        let len = src[i..].chars()
+                            .take(max)
+                            .take_while(|&c| pred(c))
+                            .map(char::len_utf8)
+                            .sum::<usize>();
+                        &src[i..i + len]
+                    })
+                    .collect::<Vec<_>>();
+                assert_eq!(str_windows_while(src, max, pred).collect::<Vec<_>>(), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn filtered_test() {
+        let src = "Hi, 你好! (ok)";
+        let windows = str_windows_filtered(src, 3, char::is_alphabetic).collect::<Vec<_>>();
+        assert_eq!(windows, [
+            (0, Cow::Owned("Hi你".into())),
+            (1, "i你好".into()),
+            (4, "你好o".into()),
+            (7, "好ok".into()),
+        ]);
+        assert!(matches!(windows[0].1, Cow::Owned(_)));
+        let windows = str_windows_filtered("😀🙂 😁", 2, |c| !c.is_whitespace())
+            .collect::<Vec<_>>();
+        assert_eq!(windows, [(0, Cow::Borrowed("😀🙂")), (4, Cow::Owned("🙂😁".into()))]);
+        assert!(matches!(windows[0].1, Cow::Borrowed(_)));
+        assert!(matches!(windows[1].1, Cow::Owned(_)));
+        assert_eq!(str_windows_filtered("ab,c", 4, char::is_alphabetic).next(), None);
+        assert_eq!(str_windows_filtered("ab,c", usize::MAX, char::is_alphabetic).next(), None);
+    }
+
+    #[test]
+    fn filtered_out_test() {
+        let mut iter = str_windows_filtered("... ,;!", 1, char::is_alphabetic);
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+        assert_eq!(iter.next(), None);
+        assert_eq!(str_windows_filtered("", 1, |_| true).next(), None);
+    }
+
+    #[test]
+    fn filtered_naive_test() {
+        for &src in &["hello, 你好, hi", "a-b-c-d", "头尾都是", " x ", ""] {
+            for size in 1..=5 {
+                let keep = |c: char| c.is_alphanumeric();
+                let kept = src.chars().filter(|&c| keep(c)).collect::<String>();
+                let offsets = src.char_indices().filter(|&(_, c)| keep(c)).map(|x| x.0);
+                let expected = offsets.zip(str_windows(&kept, size)).collect::<Vec<_>>();
+                let iter = str_windows_filtered(src, size, keep);
+                let (low, high) = iter.size_hint();
+                let windows = iter.collect::<Vec<_>>();
+                assert!(low <= windows.len() && windows.len() <= high.unwrap());
+                assert_eq!(windows.len(), expected.len());
+                for ((i, window), (j, expected)) in windows.into_iter().zip(expected) {
+                    assert_eq!((i, &*window), (j, expected));
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "window size must be non-zero")]
+    fn zero_size() {
+        str_windows_while("头尾都是", 0, |_| true);
+    }
+}
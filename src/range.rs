@@ -0,0 +1,147 @@
+use std::iter::FusedIterator;
+use std::ops::RangeInclusive;
+
+use boundary::{next_char_boundary, nth_char_boundary};
+
+/// Returns the windows of every size in `sizes`, e.g. the unigrams through
+/// 5-grams of the input for `1..=5`.
+///
+/// Windows are grouped by position: all the windows starting at the first
+/// char come first, from the smallest size to the largest, then those
+/// starting at the second char, and so on. Sizes that don't fit at a position
+/// are skipped. The boundaries are walked once, shared between the sizes.
+///
+/// An empty range yields nothing.
+///
+/// # Panics
+///
+/// Panics if `sizes` contains 0.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::str_windows_range;
+///
+/// let windows = str_windows_range("头尾都", 1..=2).collect::<Vec<_>>();
+/// assert_eq!(windows, ["头", "头尾", "尾", "尾都", "都"]);
+/// ```
+pub fn str_windows_range(input: &str, sizes: RangeInclusive<usize>) -> StrWindowsRange<'_> {
+    let (min, max) = (*sizes.start(), *sizes.end());
+    assert!(sizes.is_empty() || min != 0, "window size must be non-zero");
+    let chars = input.chars().count();
+    let len = if sizes.is_empty() {
+        0
+    } else {
+        (min..=max.min(chars)).map(|size| chars + 1 - size).sum()
+    };
+    let min_end = nth_char_boundary(input, min);
+    StrWindowsRange {
+        input,
+        start: 0,
+        min_end,
+        end: min_end,
+        size: min,
+        min,
+        max,
+        len,
+    }
+}
+
+/// An iterator over the windows of every size in a range of a `&str`.
+///
+/// This struct is created by [`str_windows_range`].
+#[derive(Clone, Debug)]
+pub struct StrWindowsRange<'a> {
+    input: &'a str,
+    /// Start of the next window.
+    start: usize,
+    /// End of the window of `min` chars at `start`.
+    min_end: usize,
+    /// End of the next window.
+    end: usize,
+    /// Size of the next window.
+    size: usize,
+    min: usize,
+    max: usize,
+    /// Number of windows left to yield.
+    len: usize,
+}
+
+impl<'a> FusedIterator for StrWindowsRange<'a> { }
+
+impl<'a> Iterator for StrWindowsRange<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        let window = &self.input[self.start..self.end];
+        if self.size < self.max && self.end < self.input.len() {
+            self.size += 1;
+            self.end = next_char_boundary(self.input, self.end);
+        } else {
+            self.start = next_char_boundary(self.input, self.start);
+            self.min_end = next_char_boundary(self.input, self.min_end);
+            self.end = self.min_end;
+            self.size = self.min;
+        }
+        Some(window)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a> ExactSizeIterator for StrWindowsRange<'a> { }
+
+
+#[cfg(test)]
+mod tests {
+    use super::str_windows_range;
+    use str_windows;
+
+    #[test]
+    fn range_test() {
+        let windows = str_windows_range("s 😀😁", 2..=3).collect::<Vec<_>>();
+        assert_eq!(windows, ["s ", "s 😀", " 😀", " 😀😁", "😀😁"]);
+        let windows = str_windows_range("头尾", 1..=5).collect::<Vec<_>>();
+        assert_eq!(windows, ["头", "头尾", "尾"]);
+        assert_eq!(str_windows_range("头尾", 3..=5).next(), None);
+        assert_eq!(str_windows_range("", 1..=5).next(), None);
+    }
+
+    #[test]
+    fn union_test() {
+        for &src in &["hello, 你好, hi", "头尾都是", "s 😀😁", ""] {
+            for min in 1..=6 {
+                for max in min..=7 {
+                    let iter = str_windows_range(src, min..=max);
+                    let mut expected = (min..=max)
+                        .flat_map(|size| str_windows(src, size).enumerate())
+                        .collect::<Vec<_>>();
+                    expected.sort_by_key(|&(i, window)| (i, window.len()));
+                    let expected = expected.into_iter().map(|x| x.1).collect::<Vec<_>>();
+                    assert_eq!(iter.len(), expected.len());
+                    assert_eq!(iter.collect::<Vec<_>>(), expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn empty_range() {
+        assert_eq!(str_windows_range("头尾都是", 3..=2).len(), 0);
+        assert_eq!(str_windows_range("头尾都是", 3..=2).next(), None);
+        assert_eq!(str_windows_range("头尾都是", 1..=0).next(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "window size must be non-zero")]
+    fn zero_size() {
+        str_windows_range("头尾都是", 0..=2);
+    }
+}
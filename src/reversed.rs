@@ -0,0 +1,132 @@
+use std::iter::FusedIterator;
+
+use {str_windows_strict, StrWindowsNonZero};
+
+/// Returns the windows of [`str_windows`] with their chars in reverse order,
+/// e.g. `"cba"` and `"dcb"` for the windows of 3 chars of `"abcd"`.
+///
+/// The windows still advance from left to right, only the chars within each
+/// window are reversed. The reversal is per char, so a combining mark ends
+/// up before the char it was applied to.
+///
+/// A reversed window can't borrow from the input, so the windows are
+/// `String`s. [`for_each_str`](StrWindowsReversed::for_each_str) reuses a
+/// single buffer instead.
+///
+/// # Panics
+///
+/// Panics if `size` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::str_windows_reversed;
+///
+/// let windows = str_windows_reversed("abcd", 3).collect::<Vec<_>>();
+/// assert_eq!(windows, ["cba", "dcb"]);
+/// ```
+///
+/// [`str_windows`]: crate::str_windows
+pub fn str_windows_reversed(input: &str, size: usize) -> StrWindowsReversed<'_> {
+    StrWindowsReversed {
+        inner: str_windows_strict(input, size),
+    }
+}
+
+/// An iterator over the windows of `size` chars of a `&str`, each with its
+/// chars reversed.
+///
+/// This struct is created by [`str_windows_reversed`].
+#[derive(Clone, Debug)]
+pub struct StrWindowsReversed<'a> {
+    inner: StrWindowsNonZero<'a>,
+}
+
+impl<'a> StrWindowsReversed<'a> {
+    /// Calls `f` on every remaining reversed window, writing all of them in
+    /// the same buffer rather than allocating a `String` per window.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use str_windows::str_windows_reversed;
+    ///
+    /// let mut windows = Vec::new();
+    /// str_windows_reversed("头尾都", 2).for_each_str(|w| windows.push(w.to_string()));
+    /// assert_eq!(windows, ["尾头", "都尾"]);
+    /// ```
+    pub fn for_each_str<F: FnMut(&str)>(self, mut f: F) {
+        let mut buf = String::new();
+        for window in self.inner {
+            buf.clear();
+            buf.extend(window.chars().rev());
+            f(&buf);
+        }
+    }
+}
+
+impl<'a> FusedIterator for StrWindowsReversed<'a> { }
+
+impl<'a> Iterator for StrWindowsReversed<'a> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|window| window.chars().rev().collect())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.inner.nth(n).map(|window| window.chars().rev().collect())
+    }
+}
+
+impl<'a> DoubleEndedIterator for StrWindowsReversed<'a> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|window| window.chars().rev().collect())
+    }
+}
+
+impl<'a> ExactSizeIterator for StrWindowsReversed<'a> { }
+
+
+#[cfg(test)]
+mod tests {
+    use super::str_windows_reversed;
+    use str_windows;
+
+    #[test]
+    fn reversed_test() {
+        let windows = str_windows_reversed("a😀b尾", 2).collect::<Vec<_>>();
+        assert_eq!(windows, ["😀a", "b😀", "尾b"]);
+        // "e\u{301}" is "é", reversed the accent comes first
+        let windows = str_windows_reversed("e\u{301}x", 2).collect::<Vec<_>>();
+        assert_eq!(windows, ["\u{301}e", "x\u{301}"]);
+        assert_eq!(str_windows_reversed("头尾", 3).next(), None);
+        let windows = str_windows_reversed("头尾都", 2).rev().collect::<Vec<_>>();
+        assert_eq!(windows, ["都尾", "尾头"]);
+    }
+
+    #[test]
+    fn for_each_test() {
+        for &src in &["hello, 你好, hi", "头尾都是", "s 😀😁", ""] {
+            for size in 1..=5 {
+                let mut windows = Vec::new();
+                str_windows_reversed(src, size).for_each_str(|w| windows.push(w.to_string()));
+                let expected = str_windows(src, size)
+                    .map(|w| w.chars().rev().collect::<String>())
+                    .collect::<Vec<_>>();
+                assert_eq!(windows, expected);
+                assert_eq!(str_windows_reversed(src, size).collect::<Vec<_>>(), expected);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "window size must be non-zero")]
+    fn zero_size() {
+        str_windows_reversed("头尾都是", 0);
+    }
+}
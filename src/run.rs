@@ -0,0 +1,177 @@
+use std::fmt;
+use std::iter::FusedIterator;
+
+/// Returns the byte range of the run of `input` starting at `i`, the chars
+/// with the same key as the one at `i`.
+fn next_run<K, F>(input: &str, i: usize, key: &F) -> Option<(usize, usize)>
+where
+    K: PartialEq,
+    F: Fn(char) -> K,
+{
+    let mut chars = input[i..].chars();
+    let first = key(chars.next()?);
+    let rest = chars.as_str().trim_start_matches(|c| key(c) == first);
+    Some((i, input.len() - rest.len()))
+}
+
+/// Returns the windows of `n` consecutive runs of the same char, each as the
+/// slice of the input covering those runs, e.g. `"oooo c"` for the windows
+/// of 3 runs of `"soooo cool"`.
+///
+/// A run is a longest sequence of one repeated char, and the windows of one
+/// run are the runs themselves. An input of fewer than `n` runs yields
+/// nothing. [`run_windows_by_key`] gathers chars in runs by some other
+/// property, like their class.
+///
+/// # Panics
+///
+/// Panics if `n` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::run_windows;
+///
+/// let runs = run_windows("soooo cooool!!!", 1).collect::<Vec<_>>();
+/// assert_eq!(runs, ["s", "oooo", " ", "c", "oooo", "l", "!!!"]);
+///
+/// let windows = run_windows("soooo cooool!!!", 2).collect::<Vec<_>>();
+/// assert_eq!(windows[..3], ["soooo", "oooo ", " c"]);
+/// ```
+pub fn run_windows(input: &str, n: usize) -> RunWindows<'_, fn(char) -> char> {
+    fn identity(c: char) -> char {
+        c
+    }
+    run_windows_by_key(input, n, identity)
+}
+
+/// Returns the windows of `n` consecutive runs of chars with the same key,
+/// each as the slice of the input covering those runs, like [`run_windows`]
+/// with the runs of the chars `key` maps to equal values.
+///
+/// # Panics
+///
+/// Panics if `n` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::run_windows_by_key;
+///
+/// let windows = run_windows_by_key("ab12c3", 2, char::is_numeric).collect::<Vec<_>>();
+/// assert_eq!(windows, ["ab12", "12c", "c3"]);
+/// ```
+pub fn run_windows_by_key<K, F>(input: &str, n: usize, key: F) -> RunWindows<'_, F>
+where
+    K: PartialEq,
+    F: Fn(char) -> K,
+{
+    assert!(n != 0, "window size must be non-zero");
+    let mut runs: usize = 0;
+    let mut i = 0;
+    while let Some((_, end)) = next_run(input, i, &key) {
+        runs += 1;
+        i = end;
+    }
+    let first = next_run(input, 0, &key);
+    let mut last = first;
+    for _ in 1..n {
+        last = match last {
+            Some((_, end)) => next_run(input, end, &key),
+            None => break,
+        };
+    }
+    RunWindows {
+        input,
+        first: first.unwrap_or((0, 0)),
+        last: last.map_or(0, |x| x.1),
+        key,
+        len: (runs + 1).saturating_sub(n),
+    }
+}
+
+/// An iterator over the windows of `n` consecutive runs of a `&str`.
+///
+/// This struct is created by [`run_windows`] and [`run_windows_by_key`].
+#[derive(Clone)]
+pub struct RunWindows<'a, F> {
+    input: &'a str,
+    /// Byte range of the first run of the next window.
+    first: (usize, usize),
+    /// End of the last run of the next window.
+    last: usize,
+    key: F,
+    /// Number of windows left to yield.
+    len: usize,
+}
+
+impl<'a, F> fmt::Debug for RunWindows<'a, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("RunWindows")
+            .field("remaining", &&self.input[self.first.0..])
+            .field("len", &self.len)
+            .finish()
+    }
+}
+
+impl<'a, K: PartialEq, F: Fn(char) -> K> FusedIterator for RunWindows<'a, F> { }
+
+impl<'a, K: PartialEq, F: Fn(char) -> K> Iterator for RunWindows<'a, F> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        let window = &self.input[self.first.0..self.last];
+        if self.len != 0 {
+            // there are runs after both ends
+            self.first = next_run(self.input, self.first.1, &self.key).unwrap();
+            self.last = next_run(self.input, self.last, &self.key).unwrap().1;
+        }
+        Some(window)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, K: PartialEq, F: Fn(char) -> K> ExactSizeIterator for RunWindows<'a, F> { }
+
+
+#[cfg(test)]
+mod tests {
+    use super::{run_windows, run_windows_by_key};
+
+    #[test]
+    fn runs_test() {
+        let runs = run_windows("头头尾😀😀😀尾", 1).collect::<Vec<_>>();
+        assert_eq!(runs, ["头头", "尾", "😀😀😀", "尾"]);
+        let windows = run_windows("头头尾😀😀😀尾", 3).collect::<Vec<_>>();
+        assert_eq!(windows, ["头头尾😀😀😀", "尾😀😀😀尾"]);
+        let iter = run_windows("aabbcc", 2);
+        assert_eq!(iter.len(), 2);
+        assert_eq!(run_windows("aabbcc", 4).next(), None);
+        assert_eq!(run_windows("aabbcc", usize::MAX).next(), None);
+        assert_eq!(run_windows("", 1).next(), None);
+    }
+
+    #[test]
+    fn key_test() {
+        let src = "v1.2.10-rc3";
+        let runs = run_windows_by_key(src, 1, char::is_numeric).collect::<Vec<_>>();
+        assert_eq!(runs, ["v", "1", ".", "2", ".", "10", "-rc", "3"]);
+        let windows = run_windows_by_key(src, 7, char::is_numeric).collect::<Vec<_>>();
+        assert_eq!(windows, ["v1.2.10-rc", "1.2.10-rc3"]);
+        let runs = run_windows_by_key("Ünïcode 2021", 1, char::is_alphabetic).collect::<Vec<_>>();
+        assert_eq!(runs, ["Ünïcode", " 2021"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "window size must be non-zero")]
+    fn zero_size() {
+        run_windows("头尾都是", 0);
+    }
+}
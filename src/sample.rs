@@ -0,0 +1,147 @@
+use str_windows_strict;
+
+/// Returns `k` windows of `size` chars chosen uniformly at random, in one
+/// pass over the input and without collecting all the windows (reservoir
+/// sampling).
+///
+/// `random(n)` must return a uniformly distributed integer in `0..n`, e.g.
+/// `|n| rng.gen_range(0..n)` with the `rand` crate, so the sample is as
+/// reproducible as the source of randomness. The windows are returned in
+/// input order. If there are at most `k` windows, they are all returned.
+///
+/// # Panics
+///
+/// Panics if `size` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::sample_windows;
+///
+/// // a "random" source that always replaces the first window of the sample
+/// let sample = sample_windows("头尾都是", 2, 2, |_| 0);
+/// assert_eq!(sample, ["尾都", "都是"]);
+/// ```
+pub fn sample_windows<F>(input: &str, size: usize, k: usize, random: F) -> Vec<&str>
+where
+    F: FnMut(usize) -> usize,
+{
+    sample_window_indices(input, size, k, random)
+        .into_iter()
+        .map(|x| x.1)
+        .collect()
+}
+
+/// Like [`sample_windows`], but also returns the byte offset of each window
+/// in the input, like `char_indices`.
+///
+/// # Panics
+///
+/// Panics if `size` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::sample_window_indices;
+///
+/// let sample = sample_window_indices("头尾都是", 2, 2, |_| 0);
+/// assert_eq!(sample, [(3, "尾都"), (6, "都是")]);
+/// ```
+pub fn sample_window_indices<F>(
+    input: &str,
+    size: usize,
+    k: usize,
+    mut random: F,
+) -> Vec<(usize, &str)>
+where
+    F: FnMut(usize) -> usize,
+{
+    let mut sample = Vec::new();
+    if k == 0 {
+        return sample;
+    }
+    for (i, window) in str_windows_strict(input, size).enumerate() {
+        let offset = window.as_ptr() as usize - input.as_ptr() as usize;
+        if i < k {
+            sample.push((offset, window));
+        } else {
+            let j = random(i + 1);
+            if j < k {
+                sample[j] = (offset, window);
+            }
+        }
+    }
+    sample.sort_unstable_by_key(|x| x.0);
+    sample
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::{sample_window_indices, sample_windows};
+    use str_windows;
+
+    /// A small xorshift generator, for reproducible samples.
+    fn xorshift(mut state: u64) -> impl FnMut(usize) -> usize {
+        move |n| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state % n as u64) as usize
+        }
+    }
+
+    const SRC: &str = "hello, 你好, hi 😀 头尾都是 s";
+
+    #[test]
+    fn sample_test() {
+        let windows = str_windows(SRC, 3).collect::<Vec<_>>();
+        for k in 1..10 {
+            let sample = sample_windows(SRC, 3, k, xorshift(42));
+            assert_eq!(sample, sample_windows(SRC, 3, k, xorshift(42)));
+            assert_eq!(sample.len(), k);
+            let mut positions = sample.iter().map(|w| windows.iter().position(|x| x == w).unwrap());
+            let mut last = positions.next().unwrap();
+            for position in positions {
+                assert!(position > last);
+                last = position;
+            }
+        }
+        assert_ne!(sample_windows(SRC, 3, 4, xorshift(1)), sample_windows(SRC, 3, 4, xorshift(2)));
+    }
+
+    #[test]
+    fn edge_cases_test() {
+        assert!(sample_windows(SRC, 2, 0, |_| unreachable!()).is_empty());
+        let all = str_windows(SRC, 4).collect::<Vec<_>>();
+        assert_eq!(sample_windows(SRC, 4, all.len(), |_| unreachable!()), all);
+        assert_eq!(sample_windows(SRC, 4, 1000, |_| unreachable!()), all);
+        assert!(sample_windows("头尾", 3, 2, |_| unreachable!()).is_empty());
+    }
+
+    #[test]
+    fn indices_test() {
+        for (offset, window) in sample_window_indices(SRC, 3, 5, xorshift(7)) {
+            assert!(SRC[offset..].starts_with(window));
+        }
+    }
+
+    #[test]
+    fn uniform_test() {
+        // every window is as likely to be sampled
+        let mut counts = [0; 4];
+        let mut random = xorshift(3);
+        for _ in 0..4000 {
+            for (offset, _) in sample_window_indices("abcde", 2, 1, &mut random) {
+                counts[offset] += 1;
+            }
+        }
+        assert!(counts.iter().all(|&count| 900 < count && count < 1100), "{:?}", counts);
+    }
+
+    #[test]
+    #[should_panic(expected = "window size must be non-zero")]
+    fn zero_size() {
+        sample_windows("头尾都是", 0, 1, |_| 0);
+    }
+}
@@ -0,0 +1,204 @@
+use std::fmt;
+use std::iter::FusedIterator;
+
+/// A separator splitting a `&str` into segments for [`segment_windows`], like
+/// the patterns of [`str::split`].
+///
+/// It's implemented for `char`, `&str`, `&[char]` and closures of
+/// `Fn(char) -> bool`. Searching for an empty `&str` panics.
+pub trait Separator {
+    /// Returns the byte range of the first separator in `haystack`.
+    fn find_in(&self, haystack: &str) -> Option<(usize, usize)>;
+}
+
+impl Separator for char {
+    fn find_in(&self, haystack: &str) -> Option<(usize, usize)> {
+        haystack.find(*self).map(|i| (i, i + self.len_utf8()))
+    }
+}
+
+impl Separator for &str {
+    fn find_in(&self, haystack: &str) -> Option<(usize, usize)> {
+        assert!(!self.is_empty(), "separator must be non-empty");
+        haystack.find(*self).map(|i| (i, i + self.len()))
+    }
+}
+
+impl Separator for &[char] {
+    fn find_in(&self, haystack: &str) -> Option<(usize, usize)> {
+        let (i, c) = haystack.char_indices().find(|x| self.contains(&x.1))?;
+        Some((i, i + c.len_utf8()))
+    }
+}
+
+impl<F: Fn(char) -> bool> Separator for F {
+    fn find_in(&self, haystack: &str) -> Option<(usize, usize)> {
+        let (i, c) = haystack.char_indices().find(|x| self(x.1))?;
+        Some((i, i + c.len_utf8()))
+    }
+}
+
+/// Returns the end of the segment of `input` starting at `start`, and the
+/// start of the next segment if there is one.
+fn segment_end<S: Separator>(input: &str, start: usize, separator: &S) -> (usize, Option<usize>) {
+    match separator.find_in(&input[start..]) {
+        Some((sep_start, sep_end)) => (start + sep_start, Some(start + sep_end)),
+        None => (input.len(), None),
+    }
+}
+
+/// Returns the windows of `n` consecutive segments of the input split by
+/// `separator`, each as the slice of the input from the start of its first
+/// segment to the end of its last one, with the separators between them,
+/// e.g. to look at consecutive fields of a CSV line or components of a
+/// path.
+///
+/// The segments are those of [`str::split`]: separators next to each other
+/// or at the ends of the input delimit empty segments, and an input without
+/// separators is a single segment, even if it's empty. The separator can be
+/// a `char`, a non-empty `&str`, a `&[char]` of chars to split on or a
+/// closure. An input of fewer than `n` segments yields nothing.
+///
+/// # Panics
+///
+/// Panics if `n` is 0, or if `separator` is an empty `&str`.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::segment_windows;
+///
+/// let windows = segment_windows("usr/local/bin", 2, '/').collect::<Vec<_>>();
+/// assert_eq!(windows, ["usr/local", "local/bin"]);
+///
+/// let windows = segment_windows("a, b,, c", 2, ", ").collect::<Vec<_>>();
+/// assert_eq!(windows, ["a, b,", "b,, c"]);
+///
+/// let windows = segment_windows("1+2-3", 2, |c: char| !c.is_numeric()).collect::<Vec<_>>();
+/// assert_eq!(windows, ["1+2", "2-3"]);
+/// ```
+pub fn segment_windows<S>(input: &str, n: usize, separator: S) -> SegmentWindows<'_, S>
+where
+    S: Separator,
+{
+    assert!(n != 0, "window size must be non-zero");
+    let mut segments = 1;
+    let mut next = segment_end(input, 0, &separator).1;
+    while let Some(start) = next {
+        segments += 1;
+        next = segment_end(input, start, &separator).1;
+    }
+    let front_next = segment_end(input, 0, &separator).1;
+    let (mut end, mut back_next) = (0, Some(0));
+    for _ in 0..n.min(segments) {
+        let (segment_end, next) = segment_end(input, back_next.unwrap(), &separator);
+        end = segment_end;
+        back_next = next;
+    }
+    SegmentWindows {
+        input,
+        start: 0,
+        front_next,
+        end,
+        back_next,
+        separator,
+        len: (segments + 1).saturating_sub(n),
+    }
+}
+
+/// An iterator over the windows of `n` consecutive segments of a `&str`
+/// split by a separator.
+///
+/// This struct is created by [`segment_windows`].
+#[derive(Clone)]
+pub struct SegmentWindows<'a, S> {
+    input: &'a str,
+    /// Start of the first segment of the next window.
+    start: usize,
+    /// Start of the segment after the first one of the next window.
+    front_next: Option<usize>,
+    /// End of the last segment of the next window.
+    end: usize,
+    /// Start of the segment after the next window.
+    back_next: Option<usize>,
+    separator: S,
+    /// Number of windows left to yield.
+    len: usize,
+}
+
+impl<'a, S> fmt::Debug for SegmentWindows<'a, S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SegmentWindows")
+            .field("remaining", &&self.input[self.start..])
+            .field("len", &self.len)
+            .finish()
+    }
+}
+
+impl<'a, S: Separator> FusedIterator for SegmentWindows<'a, S> { }
+
+impl<'a, S: Separator> Iterator for SegmentWindows<'a, S> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        let window = &self.input[self.start..self.end];
+        if self.len != 0 {
+            // there are segments after both ends
+            self.start = self.front_next.unwrap();
+            self.front_next = segment_end(self.input, self.start, &self.separator).1;
+            let next = segment_end(self.input, self.back_next.unwrap(), &self.separator);
+            self.end = next.0;
+            self.back_next = next.1;
+        }
+        Some(window)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, S: Separator> ExactSizeIterator for SegmentWindows<'a, S> { }
+
+
+#[cfg(test)]
+mod tests {
+    use super::segment_windows;
+
+    #[test]
+    fn segments_test() {
+        let src = "/a//b/";
+        let windows = segment_windows(src, 1, '/').collect::<Vec<_>>();
+        assert_eq!(windows, src.split('/').collect::<Vec<_>>());
+        let windows = segment_windows(src, 2, '/').collect::<Vec<_>>();
+        assert_eq!(windows, ["/a", "a/", "/b", "b/"]);
+        let windows = segment_windows(src, 5, '/').collect::<Vec<_>>();
+        assert_eq!(windows, [src]);
+        assert_eq!(segment_windows(src, 6, '/').len(), 0);
+        assert_eq!(segment_windows("", 1, '/').collect::<Vec<_>>(), [""]);
+        assert_eq!(segment_windows("", 2, '/').next(), None);
+    }
+
+    #[test]
+    fn separators_test() {
+        let src = "::头::尾:::都::";
+        let windows = segment_windows(src, 1, "::").collect::<Vec<_>>();
+        assert_eq!(windows, src.split("::").collect::<Vec<_>>());
+        let windows = segment_windows(src, 3, "::").collect::<Vec<_>>();
+        assert_eq!(windows, ["::头::尾", "头::尾:::都", "尾:::都::"]);
+        let windows = segment_windows("a;b,c", 2, &[',', ';'][..]).collect::<Vec<_>>();
+        assert_eq!(windows, ["a;b", "b,c"]);
+        let windows = segment_windows("ab1cd2", 2, char::is_numeric).collect::<Vec<_>>();
+        assert_eq!(windows, ["ab1cd", "cd2"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "separator must be non-empty")]
+    fn empty_separator() {
+        segment_windows("头尾", 1, "");
+    }
+}
@@ -0,0 +1,190 @@
+use std::iter::FusedIterator;
+
+/// Returns the k-skip-n-grams of the input: the sequences of `n` chars taken
+/// in order from the input, skipping at most `k` chars in total between them.
+///
+/// The chars of a skip-gram aren't contiguous, so they are yielded as
+/// `String`s. Skip-grams are ordered by the position of their first char, then
+/// by the positions of their following chars, so for `k = 0` they are exactly
+/// the windows of [`str_windows`].
+///
+/// The number of skip-grams grows quickly with `n` and `k`. It's computed up
+/// front and reported by `size_hint`, unless it overflows a `usize`.
+///
+/// # Panics
+///
+/// Panics if `n` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::str_skip_grams;
+///
+/// let grams = str_skip_grams("abcd", 2, 1).collect::<Vec<_>>();
+/// assert_eq!(grams, ["ab", "ac", "bc", "bd", "cd"]);
+/// ```
+///
+/// [`str_windows`]: crate::str_windows
+pub fn str_skip_grams(input: &str, n: usize, k: usize) -> StrSkipGrams {
+    assert!(n != 0, "window size must be non-zero");
+    let chars = input.chars().collect::<Vec<_>>();
+    let len = skip_gram_count(chars.len(), n, k);
+    let positions = if chars.len() < n { Vec::new() } else { (0..n).collect() };
+    StrSkipGrams {
+        chars,
+        positions,
+        k,
+        len,
+    }
+}
+
+/// Returns the binomial coefficient `m` choose `r`, or `None` on overflow.
+fn binomial(m: usize, r: usize) -> Option<usize> {
+    if r > m {
+        return Some(0);
+    }
+    let r = r.min(m - r);
+    // each partial product is itself a binomial coefficient, so the divisions
+    // are exact
+    (0..r).try_fold(1usize, |acc, i| Some(acc.checked_mul(m - i)? / (i + 1)))
+}
+
+/// Returns the number of k-skip-n-grams of `chars` chars, or `None` on
+/// overflow.
+fn skip_gram_count(chars: usize, n: usize, k: usize) -> Option<usize> {
+    if n == 1 {
+        return Some(chars);
+    }
+    // a skip-gram skipping `s` chars spans `n + s` chars and distributes the
+    // skips over its `n - 1` gaps
+    (0..=k)
+        .take_while(|&s| n + s <= chars)
+        .try_fold(0usize, |acc, s| {
+            let gaps = binomial(s + n - 2, n - 2)?;
+            acc.checked_add(gaps.checked_mul(chars - n - s + 1)?)
+        })
+}
+
+/// An iterator over the k-skip-n-grams of a `&str`.
+///
+/// This struct is created by [`str_skip_grams`].
+#[derive(Clone, Debug)]
+pub struct StrSkipGrams {
+    chars: Vec<char>,
+    /// Char indices of the next skip-gram, empty once exhausted.
+    positions: Vec<usize>,
+    k: usize,
+    /// Number of skip-grams left to yield, `None` if it overflowed.
+    len: Option<usize>,
+}
+
+impl StrSkipGrams {
+    /// Moves `positions` to the next skip-gram, or clears it.
+    fn advance(&mut self) {
+        let n = self.positions.len();
+        let chars = self.chars.len();
+        let first = self.positions[0];
+        // increment the last position that can be, with the following ones
+        // right after it
+        for j in (1..n).rev() {
+            let last = self.positions[j] + n - j;
+            if last < chars && last - first - (n - 1) <= self.k {
+                self.positions[j] += 1;
+                for i in j + 1..n {
+                    self.positions[i] = self.positions[i - 1] + 1;
+                }
+                return;
+            }
+        }
+        if first + n < chars {
+            for (i, position) in self.positions.iter_mut().enumerate() {
+                *position = first + 1 + i;
+            }
+        } else {
+            self.positions.clear();
+        }
+    }
+}
+
+impl FusedIterator for StrSkipGrams { }
+
+impl Iterator for StrSkipGrams {
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.positions.is_empty() {
+            return None;
+        }
+        let gram = self.positions.iter().map(|&i| self.chars[i]).collect();
+        self.len = self.len.map(|len| len - 1);
+        self.advance();
+        Some(gram)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.len {
+            Some(len) => (len, Some(len)),
+            None => (0, None),
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::{binomial, skip_gram_count, str_skip_grams};
+    use str_windows;
+
+    #[test]
+    fn skip_grams_test() {
+        let grams = str_skip_grams("头尾都是", 2, 2).collect::<Vec<_>>();
+        assert_eq!(grams, ["头尾", "头都", "头是", "尾都", "尾是", "都是"]);
+        let grams = str_skip_grams("a😀bc", 3, 1).collect::<Vec<_>>();
+        assert_eq!(grams, ["a😀b", "a😀c", "abc", "😀bc"]);
+        let grams = str_skip_grams("abcde", 3, 2).collect::<Vec<_>>();
+        assert_eq!(grams, [
+            "abc", "abd", "abe", "acd", "ace", "ade",
+            "bcd", "bce", "bde",
+            "cde",
+        ]);
+        assert_eq!(str_skip_grams("😀😁", 1, 3).collect::<Vec<_>>(), ["😀", "😁"]);
+        assert_eq!(str_skip_grams("头尾", 3, 3).next(), None);
+        assert_eq!(str_skip_grams("", 1, 0).next(), None);
+    }
+
+    #[test]
+    fn zero_skip_test() {
+        for &src in &["hello, 你好, hi", "头尾都是", "s 😀😁", ""] {
+            for n in 1..=6 {
+                let grams = str_skip_grams(src, n, 0).collect::<Vec<_>>();
+                assert_eq!(grams, str_windows(src, n).collect::<Vec<_>>());
+            }
+        }
+    }
+
+    #[test]
+    fn count_test() {
+        for &src in &["hello, 你好, hi", "头尾都是", "s 😀😁", ""] {
+            for n in 1..=5 {
+                for k in 0..=5 {
+                    let iter = str_skip_grams(src, n, k);
+                    let len = iter.size_hint().0;
+                    assert_eq!(iter.size_hint().1, Some(len));
+                    let grams = iter.collect::<Vec<_>>();
+                    assert_eq!(grams.len(), len, "{:?} {} {}", src, n, k);
+                    assert!(grams.iter().all(|gram| gram.chars().count() == n));
+                }
+            }
+        }
+        assert_eq!(binomial(5, 2), Some(10));
+        assert_eq!(binomial(2, 5), Some(0));
+        assert_eq!(skip_gram_count(200, 100, 100), None);
+        assert_eq!(str_skip_grams(&"a".repeat(200), 100, 100).size_hint(), (0, None));
+    }
+
+    #[test]
+    #[should_panic(expected = "window size must be non-zero")]
+    fn zero_size() {
+        str_skip_grams("头尾都是", 0, 1);
+    }
+}
@@ -0,0 +1,205 @@
+use std::iter::FusedIterator;
+
+/// The encoding of an SMS message, see [`sms_segments`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SmsEncoding {
+    /// The GSM 03.38 7-bit default alphabet, with its extension table.
+    Gsm7,
+    /// UCS-2, i.e. UTF-16 code units, for messages with other chars.
+    Ucs2,
+}
+
+impl SmsEncoding {
+    /// Returns the number of septets (GSM-7) or code units (UCS-2) of a
+    /// single-part message.
+    pub fn single_part_len(self) -> usize {
+        match self {
+            SmsEncoding::Gsm7 => 160,
+            SmsEncoding::Ucs2 => 70,
+        }
+    }
+
+    /// Returns the number of septets (GSM-7) or code units (UCS-2) of each
+    /// part of a concatenated message, less than a single part since each one
+    /// carries a header.
+    pub fn multi_part_len(self) -> usize {
+        match self {
+            SmsEncoding::Gsm7 => 153,
+            SmsEncoding::Ucs2 => 67,
+        }
+    }
+
+    /// Returns the cost of `c` in this encoding, or `None` if it can't be
+    /// encoded.
+    fn cost(self, c: char) -> Option<usize> {
+        match self {
+            SmsEncoding::Gsm7 => gsm7_septets(c),
+            SmsEncoding::Ucs2 => Some(c.len_utf16()),
+        }
+    }
+}
+
+/// Returns the number of septets of `c` in GSM-7: 1 for the default
+/// alphabet, 2 for the extension table (an escape and the char), or `None`.
+fn gsm7_septets(c: char) -> Option<usize> {
+    match c {
+        'A'..='Z' | 'a'..='z' | '0'..='9' => Some(1),
+        '@' | '£' | '$' | '¥' | 'è' | 'é' | 'ù' | 'ì' | 'ò' | 'Ç' | '\n' | 'Ø' | 'ø' | '\r'
+        | 'Å' | 'å' | 'Δ' | '_' | 'Φ' | 'Γ' | 'Λ' | 'Ω' | 'Π' | 'Ψ' | 'Σ' | 'Θ' | 'Ξ' | 'Æ'
+        | 'æ' | 'ß' | 'É' | ' ' | '!' | '"' | '#' | '¤' | '%' | '&' | '\'' | '(' | ')'
+        | '*' | '+' | ',' | '-' | '.' | '/' | ':' | ';' | '<' | '=' | '>' | '?' | '¡' | 'Ä'
+        | 'Ö' | 'Ñ' | 'Ü' | '§' | '¿' | 'ä' | 'ö' | 'ñ' | 'ü' | 'à' => Some(1),
+        '\x0C' | '^' | '{' | '}' | '\\' | '[' | '~' | ']' | '|' | '€' => Some(2),
+        _ => None,
+    }
+}
+
+/// Splits an SMS message into the parts it's sent as.
+///
+/// The message is encoded in GSM-7 if all its chars can be, otherwise in
+/// UCS-2. A message fitting in a single part (160 septets or 70 code units)
+/// is yielded whole, a longer one is split into parts of 153 septets or 67
+/// code units, leaving room for the concatenation headers. Parts never split
+/// a char, so neither a GSM-7 escape sequence nor a UTF-16 surrogate pair.
+///
+/// An empty message yields no parts.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::{sms_segments, SmsEncoding};
+///
+/// let segments = sms_segments("Hello!");
+/// assert_eq!(segments.encoding(), SmsEncoding::Gsm7);
+/// assert_eq!(segments.collect::<Vec<_>>(), ["Hello!"]);
+///
+/// // 33 emoji, 66 code units, per part
+/// let message = "😀".repeat(40);
+/// let segments = sms_segments(&message);
+/// assert_eq!(segments.encoding(), SmsEncoding::Ucs2);
+/// assert_eq!(segments.len(), 2);
+/// ```
+pub fn sms_segments(input: &str) -> SmsSegments<'_> {
+    let (encoding, total) = match input.chars().map(gsm7_septets).sum::<Option<usize>>() {
+        Some(septets) => (SmsEncoding::Gsm7, septets),
+        None => (SmsEncoding::Ucs2, input.encode_utf16().count()),
+    };
+    let budget = if total <= encoding.single_part_len() {
+        encoding.single_part_len()
+    } else {
+        encoding.multi_part_len()
+    };
+    let mut segments = SmsSegments {
+        rest: input,
+        encoding,
+        budget,
+        len: 0,
+    };
+    segments.len = segments.clone().fold(0, |len, _| len + 1);
+    segments
+}
+
+/// An iterator over the parts of an SMS message.
+///
+/// This struct is created by [`sms_segments`].
+#[derive(Clone, Debug)]
+pub struct SmsSegments<'a> {
+    rest: &'a str,
+    encoding: SmsEncoding,
+    /// Septets or code units per part.
+    budget: usize,
+    /// Number of parts left to yield.
+    len: usize,
+}
+
+impl<'a> SmsSegments<'a> {
+    /// Returns the encoding of the message.
+    pub fn encoding(&self) -> SmsEncoding {
+        self.encoding
+    }
+}
+
+impl<'a> FusedIterator for SmsSegments<'a> { }
+
+impl<'a> Iterator for SmsSegments<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rest.is_empty() {
+            return None;
+        }
+        let mut cost = 0;
+        let end = self.rest.char_indices()
+            .find(|&(_, c)| {
+                // all the chars are encodable in the chosen encoding
+                cost += self.encoding.cost(c).unwrap_or(1);
+                cost > self.budget
+            })
+            .map_or(self.rest.len(), |x| x.0);
+        let (part, rest) = self.rest.split_at(end);
+        self.rest = rest;
+        self.len = self.len.saturating_sub(1);
+        Some(part)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a> ExactSizeIterator for SmsSegments<'a> { }
+
+
+#[cfg(test)]
+mod tests {
+    use super::{sms_segments, SmsEncoding};
+
+    #[test]
+    fn gsm7_test() {
+        let message = "a".repeat(160);
+        let segments = sms_segments(&message);
+        assert_eq!(segments.encoding(), SmsEncoding::Gsm7);
+        assert_eq!(segments.collect::<Vec<_>>(), [&message[..]]);
+
+        let message = "a".repeat(161);
+        let parts = sms_segments(&message).collect::<Vec<_>>();
+        assert_eq!(parts.iter().map(|part| part.len()).collect::<Vec<_>>(), [153, 8]);
+
+        // the extension chars take two septets
+        let message = "€".repeat(80);
+        assert_eq!(sms_segments(&message).len(), 1);
+        let message = format!("{}{{{}", "a".repeat(152), "b".repeat(10));
+        let parts = sms_segments(&message).collect::<Vec<_>>();
+        assert_eq!(parts, [&message[..152], &message[152..]]);
+        assert_eq!(sms_segments("Ça coûte 5€").encoding(), SmsEncoding::Ucs2);
+        assert_eq!(sms_segments("Ça coute 5€").encoding(), SmsEncoding::Gsm7);
+    }
+
+    #[test]
+    fn ucs2_test() {
+        let message = format!("{}😀", "a".repeat(68));
+        let segments = sms_segments(&message);
+        assert_eq!(segments.encoding(), SmsEncoding::Ucs2);
+        assert_eq!(segments.len(), 1);
+
+        // the emoji doesn't fit in the first part
+        let message = format!("{}😀头头头", "头".repeat(66));
+        let parts = sms_segments(&message).collect::<Vec<_>>();
+        assert_eq!(parts, ["头".repeat(66), "😀头头头".to_string()]);
+
+        let message = "😀".repeat(100);
+        let parts = sms_segments(&message).collect::<Vec<_>>();
+        assert_eq!(parts.len(), 4);
+        assert_eq!(parts.concat(), message);
+        for part in parts {
+            assert!(part.encode_utf16().count() <= 67);
+        }
+    }
+
+    #[test]
+    fn empty_test() {
+        let segments = sms_segments("");
+        assert_eq!(segments.len(), 0);
+        assert_eq!(segments.encoding(), SmsEncoding::Gsm7);
+    }
+}
@@ -0,0 +1,395 @@
+use std::fmt;
+use std::iter::FusedIterator;
+
+use boundary::{next_char_boundary, nth_char_boundary};
+
+/// Returns substrings of length `size` whose starts are `step` chars apart.
+///
+/// This yields the same windows as `str_windows(input, size).step_by(step)`,
+/// but only computes the boundaries of the windows it yields. A `step` of at
+/// least `size` gives non-overlapping samples.
+///
+/// # Panics
+///
+/// Panics if `size` or `step` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::str_windows_step;
+///
+/// let mut iter = str_windows_step("s 😀😁!", 2, 2);
+/// assert_eq!(iter.next(), Some("s "));
+/// assert_eq!(iter.next(), Some("😀😁"));
+/// assert!(iter.next().is_none());
+/// ```
+pub fn str_windows_step(input: &str, size: usize, step: usize) -> StrWindowsStep<'_> {
+    assert!(size != 0, "window size must be non-zero");
+    assert!(step != 0, "step must be non-zero");
+    let chars = input.chars().count();
+    let len = if chars < size {
+        0
+    } else {
+        (chars - size) / step + 1
+    };
+    StrWindowsStep {
+        inner: input,
+        end: nth_char_boundary(input, size),
+        size,
+        step,
+        len,
+    }
+}
+
+/// An iterator over windows of `size` chars of a `&str`, starting `step`
+/// chars apart.
+///
+/// This struct is created by [`str_windows_step`]. Its [`Debug`](fmt::Debug)
+/// output shows the `remaining` input, the window `size` and the `step`.
+#[derive(Clone)]
+pub struct StrWindowsStep<'a> {
+    /// The input starting at the next window.
+    inner: &'a str,
+    /// End of the next window, relative to `inner`.
+    end: usize,
+    size: usize,
+    step: usize,
+    /// Number of windows left to yield.
+    len: usize,
+}
+
+impl<'a> fmt::Debug for StrWindowsStep<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("StrWindowsStep")
+            .field("remaining", &self.inner)
+            .field("size", &self.size)
+            .field("step", &self.step)
+            .finish()
+    }
+}
+
+impl<'a> FusedIterator for StrWindowsStep<'a> { }
+
+impl<'a> Iterator for StrWindowsStep<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        let inner = &self.inner[..self.end];
+        if self.len != 0 {
+            let skip_len = nth_char_boundary(self.inner, self.step);
+            self.end += nth_char_boundary(&self.inner[self.end..], self.step);
+            self.end -= skip_len;
+            self.inner = &self.inner[skip_len..];
+        }
+        Some(inner)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a> ExactSizeIterator for StrWindowsStep<'a> { }
+
+/// Returns substrings of length `size` whose starts are about `byte_step`
+/// bytes apart, e.g. to sample windows out of a huge input at a fixed rate.
+///
+/// After each window, the start moves `byte_step` bytes forward and then to
+/// the next char boundary if it isn't on one, so consecutive starts are at
+/// least `byte_step` bytes apart. Only the yielded windows are walked, the
+/// cost is proportional to the number of windows rather than to the length
+/// of the input. The iteration ends at the first start with fewer than
+/// `size` chars after it.
+///
+/// # Panics
+///
+/// Panics if `size` or `byte_step` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::str_windows_byte_step;
+///
+/// // the second start lands inside "头" and moves to "尾"
+/// let windows = str_windows_byte_step("ab头尾都", 2, 3).collect::<Vec<_>>();
+/// assert_eq!(windows, ["ab", "尾都"]);
+/// ```
+pub fn str_windows_byte_step(
+    input: &str,
+    size: usize,
+    byte_step: usize,
+) -> StrWindowsByteStep<'_> {
+    assert!(size != 0, "window size must be non-zero");
+    assert!(byte_step != 0, "step must be non-zero");
+    StrWindowsByteStep {
+        inner: input,
+        size,
+        byte_step,
+    }
+}
+
+/// An iterator over windows of `size` chars of a `&str`, starting about
+/// `byte_step` bytes apart.
+///
+/// This struct is created by [`str_windows_byte_step`]. Its
+/// [`Debug`](fmt::Debug) output shows the `remaining` input, the window
+/// `size` and the `byte_step`.
+#[derive(Clone)]
+pub struct StrWindowsByteStep<'a> {
+    /// The input starting at the next window.
+    inner: &'a str,
+    size: usize,
+    byte_step: usize,
+}
+
+impl<'a> fmt::Debug for StrWindowsByteStep<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("StrWindowsByteStep")
+            .field("remaining", &self.inner)
+            .field("size", &self.size)
+            .field("byte_step", &self.byte_step)
+            .finish()
+    }
+}
+
+impl<'a> FusedIterator for StrWindowsByteStep<'a> { }
+
+impl<'a> Iterator for StrWindowsByteStep<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let end = nth_char_boundary(self.inner, self.size);
+        // `inner` is only walked up to `end`, so this takes `O(size)`
+        if end == self.inner.len() && self.inner.chars().count() < self.size {
+            self.inner = "";
+            return None;
+        }
+        let window = &self.inner[..end];
+        // the smallest boundary at or after `byte_step`
+        let start = next_char_boundary(self.inner, self.byte_step - 1);
+        self.inner = &self.inner[start..];
+        Some(window)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.inner.is_empty() {
+            (0, Some(0))
+        } else {
+            (0, Some((self.inner.len() - 1) / self.byte_step + 1))
+        }
+    }
+}
+
+/// Returns substrings of length `size`, where `advance` decides how many
+/// chars the start moves after each window, e.g. to skip whole escape
+/// sequences.
+///
+/// `advance` receives the window just yielded. [`str_windows`] is the case
+/// of `|_| 1`, and a constant closure gives [`str_windows_step`]. Advancing
+/// past the last window that fits ends the iteration.
+///
+/// # Panics
+///
+/// Panics if `size` is 0, or when `advance` returns 0, since the iterator
+/// would yield the same window forever.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::str_windows_by;
+///
+/// // skip the rest of an escape sequence
+/// let windows = str_windows_by(r"a\nbc", 2, |w| if w.starts_with('\\') { 2 } else { 1 });
+/// assert_eq!(windows.collect::<Vec<_>>(), ["a\\", r"\n", "bc"]);
+/// ```
+///
+/// [`str_windows`]: crate::str_windows
+pub fn str_windows_by<F>(input: &str, size: usize, advance: F) -> StrWindowsBy<'_, F>
+where
+    F: FnMut(&str) -> usize,
+{
+    assert!(size != 0, "window size must be non-zero");
+    StrWindowsBy {
+        inner: input,
+        end: nth_char_boundary(input, size),
+        chars: input.chars().count(),
+        size,
+        advance,
+    }
+}
+
+/// An iterator over windows of `size` chars of a `&str`, with starts moved by
+/// a closure.
+///
+/// This struct is created by [`str_windows_by`]. Its [`Debug`](fmt::Debug)
+/// output shows the `remaining` input and the window `size`.
+#[derive(Clone)]
+pub struct StrWindowsBy<'a, F> {
+    /// The input starting at the next window.
+    inner: &'a str,
+    /// End of the next window, relative to `inner`.
+    end: usize,
+    /// Number of chars of `inner`.
+    chars: usize,
+    size: usize,
+    advance: F,
+}
+
+impl<'a, F> fmt::Debug for StrWindowsBy<'a, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("StrWindowsBy")
+            .field("remaining", &self.inner)
+            .field("size", &self.size)
+            .finish()
+    }
+}
+
+impl<'a, F: FnMut(&str) -> usize> FusedIterator for StrWindowsBy<'a, F> { }
+
+impl<'a, F: FnMut(&str) -> usize> Iterator for StrWindowsBy<'a, F> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.chars < self.size {
+            return None;
+        }
+        let window = &self.inner[..self.end];
+        let step = (self.advance)(window);
+        assert!(step != 0, "step must be non-zero");
+        self.chars = self.chars.saturating_sub(step);
+        if self.chars >= self.size {
+            let skip_len = nth_char_boundary(self.inner, step);
+            self.end += nth_char_boundary(&self.inner[self.end..], step);
+            self.end -= skip_len;
+            self.inner = &self.inner[skip_len..];
+        }
+        Some(window)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.chars + 1).saturating_sub(self.size);
+        (len.min(1), Some(len))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::{str_windows_by, str_windows_byte_step, str_windows_step};
+    use str_windows;
+
+    #[test]
+    fn step_by_test() {
+        for &src in &["hello, 你好, hi", "头尾都是", "头部是!", ": 尾部是", "s 😀😁", ""] {
+            for size in 1..=6 {
+                for step in 1..=6 {
+                    let iter = str_windows_step(src, size, step);
+                    let expected = str_windows(src, size).step_by(step).collect::<Vec<_>>();
+                    assert_eq!(iter.len(), expected.len());
+                    assert_eq!(iter.collect::<Vec<_>>(), expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn byte_step_test() {
+        // every raw step of 2 bytes lands inside a 3-byte char
+        let windows = str_windows_byte_step("头尾都是头", 2, 2).collect::<Vec<_>>();
+        assert_eq!(windows, ["头尾", "尾都", "都是", "是头"]);
+        let windows = str_windows_byte_step("😀a😁b😂", 1, 5).collect::<Vec<_>>();
+        assert_eq!(windows, ["😀", "😁", "😂"]);
+        assert_eq!(str_windows_byte_step("头尾", 3, 1).next(), None);
+        assert_eq!(str_windows_byte_step("", 1, 1).size_hint(), (0, Some(0)));
+
+        let src = "hello, 你好, hi 😀😁 é";
+        for size in 1..=6 {
+            for byte_step in 1..=9 {
+                let iter = str_windows_byte_step(src, size, byte_step);
+                let (_, upper) = iter.size_hint();
+                let windows = iter.collect::<Vec<_>>();
+                assert!(windows.len() <= upper.unwrap());
+                let snap = |i: usize| (i..).find(|&i| i >= src.len() || src.is_char_boundary(i));
+                let mut last = None;
+                for window in &windows {
+                    assert_eq!(window.chars().count(), size);
+                    let start = window.as_ptr() as usize - src.as_ptr() as usize;
+                    assert!(src.is_char_boundary(start));
+                    if let Some(last) = last {
+                        assert_eq!(Some(start), snap(last + byte_step));
+                    }
+                    last = Some(start);
+                }
+                // only stops when the next start has fewer than `size` chars
+                let next = last.map_or(Some(0), |last| snap(last + byte_step)).unwrap();
+                let next = next.min(src.len());
+                assert!(src[next..].chars().count() < size);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "step must be non-zero")]
+    fn zero_byte_step() {
+        str_windows_byte_step("头尾都是", 2, 0);
+    }
+
+    #[test]
+    fn size_hint_test() {
+        let mut iter = str_windows_step("头尾都是头尾都", 2, 3);
+        assert_eq!(iter.size_hint(), (2, Some(2)));
+        assert_eq!(iter.next(), Some("头尾"));
+        assert_eq!(iter.size_hint(), (1, Some(1)));
+        assert_eq!(iter.next(), Some("是头"));
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "step must be non-zero")]
+    fn zero_step() {
+        str_windows_step("头尾都是", 2, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "window size must be non-zero")]
+    fn zero_size() {
+        str_windows_step("头尾都是", 0, 1);
+    }
+
+    #[test]
+    fn by_test() {
+        let mut steps = vec![1, 3, 2, 1].into_iter();
+        let windows = str_windows_by("头尾都是😀a😁b", 2, |_| steps.next().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(windows, ["头尾", "尾都", "😀a", "😁b"]);
+        let windows = str_windows_by("头尾都是", 3, |_| 100).collect::<Vec<_>>();
+        assert_eq!(windows, ["头尾都"]);
+        let windows = str_windows_by("头尾都是", 2, |w| w.chars().count()).collect::<Vec<_>>();
+        assert_eq!(windows, ["头尾", "都是"]);
+        assert_eq!(str_windows_by("头尾", 3, |_| 1).next(), None);
+        for &src in &["hello, 你好, hi", "头尾都是", "s 😀😁", ""] {
+            for size in 1..=6 {
+                let windows = str_windows_by(src, size, |_| 1).collect::<Vec<_>>();
+                assert_eq!(windows, str_windows(src, size).collect::<Vec<_>>());
+                for step in 1..=4 {
+                    let iter = str_windows_by(src, size, |_| step);
+                    let (lower, upper) = iter.size_hint();
+                    let windows = iter.collect::<Vec<_>>();
+                    assert_eq!(windows, str_windows_step(src, size, step).collect::<Vec<_>>());
+                    assert!(lower <= windows.len() && windows.len() <= upper.unwrap());
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "step must be non-zero")]
+    fn by_zero_step() {
+        str_windows_by("头尾都是", 2, |_| 0).next();
+    }
+}
@@ -0,0 +1,173 @@
+use std::borrow::Cow;
+use std::iter::FusedIterator;
+use std::slice::Windows;
+
+/// Returns the n-grams of tokens: windows of `n` consecutive tokens, either
+/// borrowed from `source` when the tokens are next to each other in it, or
+/// joined with `sep`.
+///
+/// A window is borrowed when its tokens are slices of `source` and each one
+/// starts right where the previous one ends, as with tokens split off an
+/// input without separators. The check compares addresses, not contents.
+/// Other windows, including those with a token that isn't a slice of
+/// `source`, are joined into a `String`.
+///
+/// Taking `source` keeps the borrowed windows slices of an actual string,
+/// since adjacent addresses could belong to two unrelated strings.
+///
+/// # Panics
+///
+/// Panics if `n` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use std::borrow::Cow;
+/// use str_windows::token_windows;
+///
+/// let source = "foo.bar(baz)";
+/// let tokens = [&source[..3], &source[3..4], &source[4..7], &source[8..11]];
+/// let mut iter = token_windows(source, &tokens, 2, " ");
+/// assert_eq!(iter.next(), Some(Cow::Borrowed("foo.")));
+/// assert_eq!(iter.next(), Some(Cow::Borrowed(".bar")));
+/// assert_eq!(iter.next(), Some(Cow::Owned("bar baz".to_string())));
+/// assert!(iter.next().is_none());
+/// ```
+pub fn token_windows<'a, 'b>(
+    source: &'a str,
+    tokens: &'b [&'a str],
+    n: usize,
+    sep: &'b str,
+) -> TokenWindows<'a, 'b> {
+    assert!(n != 0, "window size must be non-zero");
+    TokenWindows {
+        source,
+        windows: tokens.windows(n),
+        sep,
+    }
+}
+
+/// An iterator over the windows of `n` consecutive tokens.
+///
+/// This struct is created by [`token_windows`].
+#[derive(Clone, Debug)]
+pub struct TokenWindows<'a, 'b> {
+    source: &'a str,
+    windows: Windows<'b, &'a str>,
+    sep: &'b str,
+}
+
+impl<'a, 'b> TokenWindows<'a, 'b> {
+    /// Returns the byte range of `token` in `source`, if it's a slice of it.
+    fn range_in_source(&self, token: &str) -> Option<(usize, usize)> {
+        let source = self.source.as_ptr() as usize;
+        let start = (token.as_ptr() as usize).checked_sub(source)?;
+        let end = start + token.len();
+        if end <= self.source.len() {
+            Some((start, end))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the slice of `source` covering `tokens`, if they are next to
+    /// each other in it.
+    fn covering(&self, tokens: &[&str]) -> Option<&'a str> {
+        let (start, mut end) = self.range_in_source(tokens[0])?;
+        for token in &tokens[1..] {
+            let (token_start, token_end) = self.range_in_source(token)?;
+            if token_start != end {
+                return None;
+            }
+            end = token_end;
+        }
+        Some(&self.source[start..end])
+    }
+
+    fn window(&self, tokens: &[&'a str]) -> Cow<'a, str> {
+        match self.covering(tokens) {
+            Some(window) => Cow::Borrowed(window),
+            None => Cow::Owned(tokens.join(self.sep)),
+        }
+    }
+}
+
+impl<'a, 'b> FusedIterator for TokenWindows<'a, 'b> { }
+
+impl<'a, 'b> Iterator for TokenWindows<'a, 'b> {
+    type Item = Cow<'a, str>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let tokens = self.windows.next()?;
+        Some(self.window(tokens))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.windows.size_hint()
+    }
+}
+
+impl<'a, 'b> DoubleEndedIterator for TokenWindows<'a, 'b> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let tokens = self.windows.next_back()?;
+        Some(self.window(tokens))
+    }
+}
+
+impl<'a, 'b> ExactSizeIterator for TokenWindows<'a, 'b> { }
+
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+    use super::token_windows;
+
+    #[test]
+    fn contiguous_test() {
+        let source = "头尾😀都是";
+        let tokens = source.char_indices()
+            .map(|(i, c)| &source[i..i + c.len_utf8()])
+            .collect::<Vec<_>>();
+        let windows = token_windows(source, &tokens, 3, "+").collect::<Vec<_>>();
+        assert_eq!(windows, ["头尾😀", "尾😀都", "😀都是"]);
+        assert!(windows.iter().all(|w| matches!(w, Cow::Borrowed(_))));
+        let windows = token_windows(source, &tokens, 2, "").rev().collect::<Vec<_>>();
+        assert_eq!(windows, ["都是", "😀都", "尾😀", "头尾"]);
+    }
+
+    #[test]
+    fn joined_test() {
+        let source = "the quick  brown fox";
+        let tokens = source.split_whitespace().collect::<Vec<_>>();
+        let windows = token_windows(source, &tokens, 2, " ").collect::<Vec<_>>();
+        assert_eq!(windows, ["the quick", "quick brown", "brown fox"]);
+        assert!(windows.iter().all(|w| matches!(w, Cow::Owned(_))));
+
+        // tokens out of order, or not from the source
+        let tokens = [&source[4..9], &source[..4]];
+        let window = token_windows(source, &tokens, 2, "_").next();
+        assert_eq!(window, Some(Cow::Owned("quick_the ".into())));
+        let other = String::from("the quick");
+        let tokens = [&other[..4], &other[4..]];
+        let window = token_windows(source, &tokens, 2, "|").next();
+        assert_eq!(window, Some(Cow::Owned("the |quick".into())));
+    }
+
+    #[test]
+    fn mixed_test() {
+        let source = "a😀b c";
+        let tokens = [&source[..1], &source[1..5], &source[5..6], &source[7..]];
+        let windows = token_windows(source, &tokens, 2, "·").collect::<Vec<_>>();
+        assert_eq!(windows, ["a😀", "😀b", "b·c"]);
+        assert!(matches!(windows[1], Cow::Borrowed(_)));
+        assert!(matches!(windows[2], Cow::Owned(_)));
+        assert_eq!(token_windows(source, &tokens, 5, "").len(), 0);
+        assert_eq!(token_windows(source, &tokens[..1], 1, "").next(), Some(Cow::Borrowed("a")));
+    }
+
+    #[test]
+    #[should_panic(expected = "window size must be non-zero")]
+    fn zero_size() {
+        token_windows("头尾", &["头"], 0, "");
+    }
+}
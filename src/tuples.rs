@@ -0,0 +1,275 @@
+use std::iter::FusedIterator;
+use std::str::CharIndices;
+
+/// An iterator over the windows of `N` chars of a `&str` with their byte
+/// offsets, what the tuple windows are made from.
+#[derive(Clone, Debug)]
+struct Tuples<'a, const N: usize> {
+    chars: CharIndices<'a>,
+    /// The last window yielded, if any.
+    window: Option<[(usize, char); N]>,
+    /// Number of windows left to yield.
+    len: usize,
+}
+
+impl<'a, const N: usize> Tuples<'a, N> {
+    fn new(input: &'a str) -> Self {
+        Tuples {
+            chars: input.char_indices(),
+            window: None,
+            len: (input.chars().count() + 1).saturating_sub(N),
+        }
+    }
+}
+
+impl<'a, const N: usize> Iterator for Tuples<'a, N> {
+    type Item = [(usize, char); N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        // there are enough chars left for the window
+        let window = match self.window.as_mut() {
+            Some(window) => {
+                window.copy_within(1.., 0);
+                window[N - 1] = self.chars.next().unwrap();
+                *window
+            }
+            None => {
+                let mut window = [(0, '\0'); N];
+                for slot in &mut window {
+                    *slot = self.chars.next().unwrap();
+                }
+                *self.window.insert(window)
+            }
+        };
+        Some(window)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+/// Returns the pairs of consecutive chars of the input, the windows of 2
+/// chars of [`str_windows`] as tuples, e.g. to count bigrams.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::char_pairs;
+///
+/// let pairs = char_pairs("a头😀").collect::<Vec<_>>();
+/// assert_eq!(pairs, [('a', '头'), ('头', '😀')]);
+/// ```
+///
+/// [`str_windows`]: crate::str_windows
+pub fn char_pairs(input: &str) -> CharPairs<'_> {
+    CharPairs {
+        inner: Tuples::new(input),
+    }
+}
+
+/// An iterator over the pairs of consecutive chars of a `&str`.
+///
+/// This struct is created by [`char_pairs`].
+#[derive(Clone, Debug)]
+pub struct CharPairs<'a> {
+    inner: Tuples<'a, 2>,
+}
+
+impl<'a> FusedIterator for CharPairs<'a> { }
+
+impl<'a> Iterator for CharPairs<'a> {
+    type Item = (char, char);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|[(_, a), (_, b)]| (a, b))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a> ExactSizeIterator for CharPairs<'a> { }
+
+/// Returns the pairs of consecutive chars of the input like [`char_pairs`],
+/// with the byte offset of the first char of each pair.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::char_pair_indices;
+///
+/// let pairs = char_pair_indices("a头😀").collect::<Vec<_>>();
+/// assert_eq!(pairs, [(0, ('a', '头')), (1, ('头', '😀'))]);
+/// ```
+pub fn char_pair_indices(input: &str) -> CharPairIndices<'_> {
+    CharPairIndices {
+        inner: Tuples::new(input),
+    }
+}
+
+/// An iterator over the pairs of consecutive chars of a `&str` and their
+/// byte offsets.
+///
+/// This struct is created by [`char_pair_indices`].
+#[derive(Clone, Debug)]
+pub struct CharPairIndices<'a> {
+    inner: Tuples<'a, 2>,
+}
+
+impl<'a> FusedIterator for CharPairIndices<'a> { }
+
+impl<'a> Iterator for CharPairIndices<'a> {
+    type Item = (usize, (char, char));
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|[(i, a), (_, b)]| (i, (a, b)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a> ExactSizeIterator for CharPairIndices<'a> { }
+
+/// Returns the triples of consecutive chars of the input, the windows of 3
+/// chars of [`str_windows`] as tuples, e.g. to count trigrams.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::char_triples;
+///
+/// let triples = char_triples("a头😀!").collect::<Vec<_>>();
+/// assert_eq!(triples, [('a', '头', '😀'), ('头', '😀', '!')]);
+/// ```
+///
+/// [`str_windows`]: crate::str_windows
+pub fn char_triples(input: &str) -> CharTriples<'_> {
+    CharTriples {
+        inner: Tuples::new(input),
+    }
+}
+
+/// An iterator over the triples of consecutive chars of a `&str`.
+///
+/// This struct is created by [`char_triples`].
+#[derive(Clone, Debug)]
+pub struct CharTriples<'a> {
+    inner: Tuples<'a, 3>,
+}
+
+impl<'a> FusedIterator for CharTriples<'a> { }
+
+impl<'a> Iterator for CharTriples<'a> {
+    type Item = (char, char, char);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|[(_, a), (_, b), (_, c)]| (a, b, c))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a> ExactSizeIterator for CharTriples<'a> { }
+
+/// Returns the triples of consecutive chars of the input like
+/// [`char_triples`], with the byte offset of the first char of each triple.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::char_triple_indices;
+///
+/// let triples = char_triple_indices("a头😀!").collect::<Vec<_>>();
+/// assert_eq!(triples, [(0, ('a', '头', '😀')), (1, ('头', '😀', '!'))]);
+/// ```
+pub fn char_triple_indices(input: &str) -> CharTripleIndices<'_> {
+    CharTripleIndices {
+        inner: Tuples::new(input),
+    }
+}
+
+/// An iterator over the triples of consecutive chars of a `&str` and their
+/// byte offsets.
+///
+/// This struct is created by [`char_triple_indices`].
+#[derive(Clone, Debug)]
+pub struct CharTripleIndices<'a> {
+    inner: Tuples<'a, 3>,
+}
+
+impl<'a> FusedIterator for CharTripleIndices<'a> { }
+
+impl<'a> Iterator for CharTripleIndices<'a> {
+    type Item = (usize, (char, char, char));
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|[(i, a), (_, b), (_, c)]| (i, (a, b, c)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a> ExactSizeIterator for CharTripleIndices<'a> { }
+
+
+#[cfg(test)]
+mod tests {
+    use super::{char_pair_indices, char_pairs, char_triple_indices, char_triples};
+    use str_windows_indices;
+
+    const SOURCES: [&str; 7] = ["hello, 你好, hi", "头尾都是", "头部是!", ": 尾部是", "s 😀😁", "😀", ""];
+
+    /// The chars of the windows of `size` chars with their byte offsets.
+    fn windows(src: &str, size: usize) -> Vec<(usize, Vec<char>)> {
+        let windows = str_windows_indices(src, size);
+        windows.map(|(i, window)| (i, window.chars().collect())).collect()
+    }
+
+    #[test]
+    fn pairs_test() {
+        for &src in &SOURCES {
+            let iter = char_pair_indices(src);
+            assert_eq!(iter.len(), windows(src, 2).len());
+            assert_eq!(char_pairs(src).len(), iter.len());
+            let pairs = iter.map(|(i, (a, b))| (i, vec![a, b])).collect::<Vec<_>>();
+            assert_eq!(pairs, windows(src, 2));
+            assert!(char_pairs(src).eq(char_pair_indices(src).map(|x| x.1)));
+        }
+    }
+
+    #[test]
+    fn triples_test() {
+        for &src in &SOURCES {
+            let iter = char_triple_indices(src);
+            assert_eq!(iter.len(), windows(src, 3).len());
+            assert_eq!(char_triples(src).len(), iter.len());
+            let triples = iter.map(|(i, (a, b, c))| (i, vec![a, b, c])).collect::<Vec<_>>();
+            assert_eq!(triples, windows(src, 3));
+            assert!(char_triples(src).eq(char_triple_indices(src).map(|x| x.1)));
+        }
+    }
+
+    #[test]
+    fn short_test() {
+        assert_eq!(char_pairs("😀").next(), None);
+        assert_eq!(char_triples("头😀").next(), None);
+        assert_eq!(char_triple_indices("头😀").len(), 0);
+        let mut iter = char_triple_indices("头😀尾");
+        assert_eq!(iter.len(), 1);
+        assert_eq!(iter.next(), Some((0, ('头', '😀', '尾'))));
+        assert_eq!(iter.len(), 0);
+        assert_eq!(iter.next(), None);
+    }
+}
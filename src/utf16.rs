@@ -0,0 +1,358 @@
+use std::error::Error;
+use std::fmt;
+use std::iter::FusedIterator;
+
+use str_windows_delta;
+
+/// Returns the longest prefix of `s` that encodes to at most `max_units`
+/// UTF-16 code units.
+fn utf16_prefix(s: &str, max_units: usize) -> &str {
+    let mut units = 0;
+    for (i, c) in s.char_indices() {
+        units += c.len_utf16();
+        if units > max_units {
+            return &s[..i];
+        }
+    }
+    s
+}
+
+/// Returns windows bounded by a number of UTF-16 code units instead of
+/// chars: at every char of the input, the longest substring starting there
+/// that encodes to at most `max_units` code units, e.g. for the string
+/// lengths of JavaScript.
+///
+/// Chars outside the Basic Multilingual Plane, like most emoji, encode to a
+/// surrogate pair of 2 code units, and the others to 1. As with
+/// [`str_windows_bytes`], a char that doesn't fit, an astral char when
+/// `max_units` is 1, yields an empty window at its position, so there is
+/// always one window per char. The windows are found without encoding them,
+/// by adding the length of a char entering a window and subtracting the
+/// length of a char leaving it.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::utf16_windows;
+///
+/// let windows = utf16_windows("a😀好", 3).collect::<Vec<_>>();
+/// assert_eq!(windows, ["a😀", "😀好", "好"]);
+///
+/// let windows = utf16_windows("a😀好", 1).collect::<Vec<_>>();
+/// assert_eq!(windows, ["a", "", "好"]);
+/// ```
+///
+/// [`str_windows_bytes`]: crate::str_windows_bytes
+pub fn utf16_windows(input: &str, max_units: usize) -> Utf16Windows<'_> {
+    Utf16Windows {
+        input,
+        start: 0,
+        end: 0,
+        units: 0,
+        max_units,
+        len: input.chars().count(),
+    }
+}
+
+/// An iterator over the longest windows of at most `max_units` UTF-16 code
+/// units at every char of a `&str`.
+///
+/// This struct is created by [`utf16_windows`].
+#[derive(Clone, Debug)]
+pub struct Utf16Windows<'a> {
+    input: &'a str,
+    /// Start of the next window.
+    start: usize,
+    /// End of the chars after `start` known to fit.
+    end: usize,
+    /// Number of code units of the chars from `start` to `end`.
+    units: usize,
+    max_units: usize,
+    /// Number of windows left to yield.
+    len: usize,
+}
+
+impl<'a> FusedIterator for Utf16Windows<'a> { }
+
+impl<'a> Iterator for Utf16Windows<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = self.input[self.start..].chars().next()?;
+        self.len -= 1;
+        for c in self.input[self.end..].chars() {
+            let units = self.units + c.len_utf16();
+            if units > self.max_units {
+                break;
+            }
+            self.units = units;
+            self.end += c.len_utf8();
+        }
+        let window = &self.input[self.start..self.end];
+        self.start += first.len_utf8();
+        if self.end < self.start {
+            // the first char didn't fit
+            self.end = self.start;
+        } else {
+            self.units -= first.len_utf16();
+        }
+        Some(window)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a> ExactSizeIterator for Utf16Windows<'a> { }
+
+/// Splits the input into consecutive substrings that encode to at most
+/// `max_units` UTF-16 code units, each as long as possible without splitting
+/// a char, e.g. to store it in columns limited to a number of UTF-16 code
+/// units.
+///
+/// The chunks concatenate back to `input`, and since a char is never split,
+/// neither is a surrogate pair. Only the last chunk can be shorter than
+/// needed to fit the next char.
+///
+/// Returns an error if a char of the input is longer than `max_units`, an
+/// astral char when `max_units` is 1, since no chunk could hold it.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::utf16_chunks;
+///
+/// let chunks = utf16_chunks("ab😀c", 3).unwrap().collect::<Vec<_>>();
+/// assert_eq!(chunks, ["ab", "😀c"]);
+///
+/// assert!(utf16_chunks("ab😀c", 1).is_err());
+/// ```
+pub fn utf16_chunks(
+    input: &str,
+    max_units: usize,
+) -> Result<Utf16Chunks<'_>, Utf16TooLongError> {
+    if let Some((offset, c)) = input.char_indices().find(|x| x.1.len_utf16() > max_units) {
+        return Err(Utf16TooLongError { c, offset, max_units });
+    }
+    Ok(Utf16Chunks {
+        inner: input,
+        max_units,
+    })
+}
+
+/// Returns the longest prefix of the input that encodes to at most
+/// `max_units` UTF-16 code units, the first chunk of [`utf16_chunks`]
+/// except that it's empty if the first char doesn't fit.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::truncate_utf16;
+///
+/// assert_eq!(truncate_utf16("ab😀c", 4), "ab😀");
+/// assert_eq!(truncate_utf16("ab😀c", 3), "ab");
+/// assert_eq!(truncate_utf16("😀", 1), "");
+/// ```
+pub fn truncate_utf16(input: &str, max_units: usize) -> &str {
+    utf16_prefix(input, max_units)
+}
+
+/// Calls `f` on the windows of `size` chars of [`str_windows`] encoded in
+/// UTF-16, e.g. to pass the windows to APIs taking UTF-16 strings.
+///
+/// The windows are encoded into a single buffer, which is updated as the
+/// windows slide by encoding only the char entering each window. The
+/// encoded windows are lent to `f`, since each one is only valid until the
+/// next.
+///
+/// # Panics
+///
+/// Panics if `size` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::for_each_utf16;
+///
+/// let mut windows = Vec::new();
+/// for_each_utf16("a😀头", 2, |window| windows.push(window.to_vec()));
+/// assert_eq!(windows, [vec![0x61, 0xd83d, 0xde00], vec![0xd83d, 0xde00, 0x5934]]);
+/// ```
+///
+/// [`str_windows`]: crate::str_windows
+pub fn for_each_utf16<F: FnMut(&[u16])>(input: &str, size: usize, mut f: F) {
+    let mut buffer = Vec::new();
+    // start of the current window in the buffer
+    let mut start = 0;
+    for delta in str_windows_delta(input, size) {
+        match delta.left {
+            Some(left) => {
+                start += left.len_utf16();
+                buffer.extend_from_slice(delta.entered.encode_utf16(&mut [0; 2]));
+                // drop the chars that left once they outweigh the window
+                if start > buffer.len() - start {
+                    buffer.drain(..start);
+                    start = 0;
+                }
+            }
+            None => buffer.extend(delta.window.encode_utf16()),
+        }
+        f(&buffer[start..]);
+    }
+}
+
+/// The error returned by [`utf16_chunks`] when a char doesn't fit in the
+/// budget of UTF-16 code units.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Utf16TooLongError {
+    c: char,
+    offset: usize,
+    max_units: usize,
+}
+
+impl Utf16TooLongError {
+    /// Returns the first char longer than the budget.
+    pub fn char(&self) -> char {
+        self.c
+    }
+
+    /// Returns the byte offset of the char in the input.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl fmt::Display for Utf16TooLongError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "char {:?} at byte {} is longer than {} UTF-16 code units",
+            self.c, self.offset, self.max_units,
+        )
+    }
+}
+
+impl Error for Utf16TooLongError { }
+
+/// An iterator over consecutive chunks of at most `max_units` UTF-16 code
+/// units of a `&str`.
+///
+/// This struct is created by [`utf16_chunks`].
+#[derive(Clone, Debug)]
+pub struct Utf16Chunks<'a> {
+    inner: &'a str,
+    max_units: usize,
+}
+
+impl<'a> FusedIterator for Utf16Chunks<'a> { }
+
+impl<'a> Iterator for Utf16Chunks<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.inner.is_empty() {
+            return None;
+        }
+        let chunk = utf16_prefix(self.inner, self.max_units);
+        self.inner = &self.inner[chunk.len()..];
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // a chunk holds at least one byte and at most 3 bytes per code unit
+        let len = self.inner.len();
+        (len.div_ceil(self.max_units.max(1) * 3), Some(len))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::{for_each_utf16, truncate_utf16, utf16_chunks, utf16_windows};
+    use str_windows;
+
+    #[test]
+    fn windows_test() {
+        let windows = utf16_windows("漢😀字😁", 3).collect::<Vec<_>>();
+        assert_eq!(windows, ["漢😀", "😀字", "字😁", "😁"]);
+        let windows = utf16_windows("漢😀字😁", 2).collect::<Vec<_>>();
+        assert_eq!(windows, ["漢", "😀", "字", "😁"]);
+        let windows = utf16_windows("漢😀字😁", 1).collect::<Vec<_>>();
+        assert_eq!(windows, ["漢", "", "字", ""]);
+        assert_eq!(utf16_windows("头尾", 0).collect::<Vec<_>>(), ["", ""]);
+        assert_eq!(utf16_windows("", 4).next(), None);
+    }
+
+    #[test]
+    fn longest_test() {
+        for &src in &["hello, 你好, hi", "头尾都是", "s 😀😁é", "😀a😁bc😂"] {
+            for max_units in 0..=8 {
+                let iter = utf16_windows(src, max_units);
+                assert_eq!(iter.len(), src.chars().count());
+                for ((i, _), window) in src.char_indices().zip(iter) {
+                    let rest = &src[i..];
+                    let units = window.encode_utf16().count();
+                    assert!(rest.starts_with(window));
+                    assert!(units <= max_units);
+                    if let Some(c) = rest[window.len()..].chars().next() {
+                        assert!(units + c.len_utf16() > max_units);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn chunks_test() {
+        let src = "ab😀c漢😁";
+        let chunks = utf16_chunks(src, 4).unwrap().collect::<Vec<_>>();
+        assert_eq!(chunks, ["ab😀", "c漢😁"]);
+        let chunks = utf16_chunks(src, 3).unwrap().collect::<Vec<_>>();
+        assert_eq!(chunks, ["ab", "😀c", "漢😁"]);
+        let chunks = utf16_chunks(src, 2).unwrap().collect::<Vec<_>>();
+        assert_eq!(chunks, ["ab", "😀", "c漢", "😁"]);
+        for max_units in 2..=10 {
+            let iter = utf16_chunks(src, max_units).unwrap();
+            let (lower, upper) = iter.size_hint();
+            let chunks = iter.collect::<Vec<_>>();
+            assert!(lower <= chunks.len() && chunks.len() <= upper.unwrap());
+            assert_eq!(chunks.concat(), src);
+            for pair in chunks.windows(2) {
+                let units = pair[0].encode_utf16().count();
+                assert!(units <= max_units);
+                assert!(units + pair[1].chars().next().unwrap().len_utf16() > max_units);
+            }
+        }
+        assert_eq!(utf16_chunks("", 0).unwrap().next(), None);
+
+        let err = utf16_chunks("a头😀", 1).unwrap_err();
+        assert_eq!((err.char(), err.offset()), ('😀', 4));
+        assert_eq!(err.to_string(), "char '😀' at byte 4 is longer than 1 UTF-16 code units");
+    }
+
+    #[test]
+    fn truncate_test() {
+        assert_eq!(truncate_utf16("漢😀", 3), "漢😀");
+        assert_eq!(truncate_utf16("漢😀", 2), "漢");
+        assert_eq!(truncate_utf16("漢😀", 0), "");
+        assert_eq!(truncate_utf16("", 5), "");
+    }
+    #[test]
+    fn for_each_test() {
+        for &src in &["hello, 你好, hi", "头尾都是", "s 😀😁é", "😀a😁bc😂", "😀😁😂", ""] {
+            for size in 1..=6 {
+                let mut windows = Vec::new();
+                for_each_utf16(src, size, |window| windows.push(window.to_vec()));
+                let expected = str_windows(src, size).map(|w| w.encode_utf16().collect::<Vec<_>>());
+                assert!(windows.into_iter().eq(expected));
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "window size must be non-zero")]
+    fn for_each_zero_size() {
+        for_each_utf16("头尾都是", 0, |_| ());
+    }
+}
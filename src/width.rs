@@ -0,0 +1,304 @@
+use std::cmp::Ordering;
+use std::iter::FusedIterator;
+
+mod tables;
+
+/// Returns the number of columns `c` takes in a terminal.
+///
+/// Control chars, combining marks, format chars and the medial and final
+/// Hangul jamo are zero width, the chars whose East Asian width is wide or
+/// fullwidth take 2 columns, and all the others 1.
+fn char_width(c: char) -> usize {
+    if c.is_control() {
+        return 0;
+    }
+    let table = tables::WIDTHS;
+    match table.binary_search_by(|&(start, end, _)| {
+        if end < c {
+            Ordering::Less
+        } else if start > c {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }) {
+        Ok(i) => table[i].2 as usize,
+        Err(_) => 1,
+    }
+}
+
+/// Returns a window starting at each char of the input, the longest slice
+/// of the input from that char that takes at most `columns` columns in a
+/// terminal, e.g. to scroll a line horizontally through a fixed width view.
+///
+/// Wide chars, like most ideographs and emoji, take 2 columns, as in
+/// UAX #11. Control chars, combining marks and other zero width chars take
+/// none, so a window keeps the marks following its last char. A char wider
+/// than `columns`, e.g. an ideograph when `columns` is 1, doesn't fit in any
+/// window: its window is empty, and the windows before it stop before it.
+///
+/// The widths are built in, derived from Unicode 14.0 like the
+/// `unicode-width` crate, except that control chars are zero width. This
+/// requires the `width` feature.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::width_windows;
+///
+/// let windows = width_windows("ab漢字c", 3).collect::<Vec<_>>();
+/// assert_eq!(windows, ["ab", "b漢", "漢", "字c", "c"]);
+/// ```
+pub fn width_windows(input: &str, columns: usize) -> WidthWindows<'_> {
+    WidthWindows {
+        input,
+        start: 0,
+        end: 0,
+        width: 0,
+        columns,
+        len: input.chars().count(),
+    }
+}
+
+/// An iterator over the longest slices of a `&str` starting at each char
+/// that fit in a number of columns.
+///
+/// This struct is created by [`width_windows`].
+#[derive(Clone, Debug)]
+pub struct WidthWindows<'a> {
+    input: &'a str,
+    /// Start of the next window.
+    start: usize,
+    /// End of the chars after `start` known to fit.
+    end: usize,
+    /// Width of the chars from `start` to `end`.
+    width: usize,
+    columns: usize,
+    /// Number of windows left to yield.
+    len: usize,
+}
+
+impl<'a> FusedIterator for WidthWindows<'a> { }
+
+impl<'a> Iterator for WidthWindows<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = self.input[self.start..].chars().next()?;
+        self.len -= 1;
+        for c in self.input[self.end..].chars() {
+            let width = self.width + char_width(c);
+            if width > self.columns {
+                break;
+            }
+            self.width = width;
+            self.end += c.len_utf8();
+        }
+        let window = &self.input[self.start..self.end];
+        self.start += first.len_utf8();
+        if self.end < self.start {
+            // the first char didn't fit
+            self.end = self.start;
+        } else {
+            self.width -= char_width(first);
+        }
+        Some(window)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a> ExactSizeIterator for WidthWindows<'a> { }
+
+/// Returns the consecutive pieces of the input that each take at most
+/// `columns` columns in a terminal, e.g. to wrap text in a fixed width pane.
+///
+/// The widths are those of [`width_windows`]. Every piece takes as many
+/// chars as fit, never splitting one, and the pieces add up to the input.
+/// A char wider than `columns`, e.g. an ideograph when `columns` is 1, is
+/// placed alone in a piece that is wider than `columns`. A piece ends as
+/// soon as it's full, so a zero width char following a full piece starts
+/// the next one, unless [`attach_zero_width`](WidthChunks::attach_zero_width)
+/// is set to keep combining marks with the char they're drawn on.
+///
+/// This requires the `width` feature.
+///
+/// # Panics
+///
+/// Panics if `columns` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::width_chunks;
+///
+/// let chunks = width_chunks("ab漢字c", 3).collect::<Vec<_>>();
+/// assert_eq!(chunks, ["ab", "漢", "字c"]);
+///
+/// let chunks = width_chunks("cafe\u{301}", 4).collect::<Vec<_>>();
+/// assert_eq!(chunks, ["cafe", "\u{301}"]);
+/// let chunks = width_chunks("cafe\u{301}", 4).attach_zero_width(true).collect::<Vec<_>>();
+/// assert_eq!(chunks, ["cafe\u{301}"]);
+/// ```
+pub fn width_chunks(input: &str, columns: usize) -> WidthChunks<'_> {
+    assert!(columns != 0, "columns must be non-zero");
+    WidthChunks {
+        rest: input,
+        columns,
+        attach_zero_width: false,
+    }
+}
+
+/// An iterator over the consecutive pieces of a `&str` that fit in a number
+/// of columns.
+///
+/// This struct is created by [`width_chunks`].
+#[derive(Clone, Debug)]
+pub struct WidthChunks<'a> {
+    rest: &'a str,
+    columns: usize,
+    attach_zero_width: bool,
+}
+
+impl<'a> WidthChunks<'a> {
+    /// Sets whether to add the zero width chars following a full piece to
+    /// it, so that no piece but the first starts with one. Defaults to
+    /// `false`.
+    pub fn attach_zero_width(mut self, attach_zero_width: bool) -> Self {
+        self.attach_zero_width = attach_zero_width;
+        self
+    }
+}
+
+impl<'a> FusedIterator for WidthChunks<'a> { }
+
+impl<'a> Iterator for WidthChunks<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut chars = self.rest.chars();
+        // the first char is taken even if it doesn't fit
+        let mut width = char_width(chars.next()?);
+        for c in chars.clone() {
+            let char_width = char_width(c);
+            let fits = width < self.columns && width + char_width <= self.columns;
+            let attach = self.attach_zero_width && char_width == 0;
+            if !(fits || attach) {
+                break;
+            }
+            width += char_width;
+            chars.next();
+        }
+        let (chunk, rest) = self.rest.split_at(self.rest.len() - chars.as_str().len());
+        self.rest = rest;
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (!self.rest.is_empty() as usize, Some(self.rest.len()))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::{char_width, width_chunks, width_windows};
+
+    #[test]
+    fn width_test() {
+        let widths = "aé漢ｱＡ😀\u{301}\u{200b}\t\u{1160}한".chars().map(char_width);
+        assert_eq!(widths.collect::<Vec<_>>(), [1, 1, 2, 1, 2, 2, 0, 0, 0, 0, 2]);
+    }
+
+    #[test]
+    fn known_widths_test() {
+        // ideographs, kana, Hangul syllables and initial jamo, emoji, emoji
+        // modifiers, fullwidth forms, and an unassigned char of plane 2
+        let wide = [
+            '\u{6f22}', '\u{3042}', '\u{ac00}', '\u{1100}', '\u{1f600}', '\u{231a}', '\u{2329}',
+            '\u{1f3fb}', '\u{ff21}', '\u{3000}', '\u{20000}', '\u{2a6e0}',
+        ];
+        // combining and enclosing marks, format chars, variation selectors,
+        // tags, and medial and final jamo
+        let zero = [
+            '\u{301}', '\u{20dd}', '\u{200b}', '\u{200d}', '\u{feff}', '\u{61c}', '\u{fe0f}',
+            '\u{e0100}', '\u{e0001}', '\u{1160}', '\u{11a8}',
+        ];
+        // ambiguous and halfwidth chars, the soft hyphen, spacing marks and
+        // unassigned chars outside of the ideographs
+        let narrow = [
+            'a', '\u{e9}', '\u{a1}', '\u{ad}', '\u{ff61}', '\u{903}', '\u{d7b0}', '\u{378}',
+            '\u{2028}',
+        ];
+        for &c in &wide {
+            assert_eq!(char_width(c), 2, "U+{:04X}", c as u32);
+        }
+        for &c in &zero {
+            assert_eq!(char_width(c), 0, "U+{:04X}", c as u32);
+        }
+        for &c in &narrow {
+            assert_eq!(char_width(c), 1, "U+{:04X}", c as u32);
+        }
+    }
+
+    #[test]
+    fn windows_test() {
+        let src = "ab漢字c";
+        let windows = width_windows(src, 4).collect::<Vec<_>>();
+        assert_eq!(windows, ["ab漢", "b漢", "漢字", "字c", "c"]);
+        let windows = width_windows(src, 2).collect::<Vec<_>>();
+        assert_eq!(windows, ["ab", "b", "漢", "字", "c"]);
+        let windows = width_windows(src, 1).collect::<Vec<_>>();
+        assert_eq!(windows, ["a", "b", "", "", "c"]);
+        assert!(width_windows(src, 0).all(|window| window.is_empty()));
+        assert_eq!(width_windows(src, 8).next(), Some(src));
+        let iter = width_windows(src, 3);
+        assert_eq!(iter.len(), 5);
+        assert_eq!(width_windows("", 3).next(), None);
+    }
+
+    #[test]
+    fn zero_width_test() {
+        let windows = width_windows("e\u{301}漢x", 1).collect::<Vec<_>>();
+        assert_eq!(windows, ["e\u{301}", "\u{301}", "", "x"]);
+        let windows = width_windows("a\tb\u{200b}", 1).collect::<Vec<_>>();
+        assert_eq!(windows, ["a\t", "\tb\u{200b}", "b\u{200b}", "\u{200b}"]);
+        let windows = width_windows("\u{301}\u{302}", 0).collect::<Vec<_>>();
+        assert_eq!(windows, ["\u{301}\u{302}", "\u{302}"]);
+    }
+
+    #[test]
+    fn chunks_test() {
+        let src = "ab漢字c";
+        let chunks = width_chunks(src, 4).collect::<Vec<_>>();
+        assert_eq!(chunks, ["ab漢", "字c"]);
+        let chunks = width_chunks(src, 2).collect::<Vec<_>>();
+        assert_eq!(chunks, ["ab", "漢", "字", "c"]);
+        let chunks = width_chunks(src, 1).collect::<Vec<_>>();
+        assert_eq!(chunks, ["a", "b", "漢", "字", "c"]);
+        let src = "e\u{301}漢\u{302}x\u{200b}";
+        for columns in 1..=8 {
+            for attach in [false, true] {
+                let chunks = width_chunks(src, columns).attach_zero_width(attach);
+                assert_eq!(chunks.collect::<String>(), src);
+            }
+        }
+        let chunks = width_chunks(src, 1).collect::<Vec<_>>();
+        assert_eq!(chunks, ["e", "\u{301}", "漢", "\u{302}x", "\u{200b}"]);
+        let chunks = width_chunks(src, 1).attach_zero_width(true).collect::<Vec<_>>();
+        assert_eq!(chunks, ["e\u{301}", "漢\u{302}", "x\u{200b}"]);
+        let chunks = width_chunks(src, 3).collect::<Vec<_>>();
+        assert_eq!(chunks, ["e\u{301}漢", "\u{302}x\u{200b}"]);
+        let chunks = width_chunks("\u{301}ab", 1).attach_zero_width(true).collect::<Vec<_>>();
+        assert_eq!(chunks, ["\u{301}a", "b"]);
+        assert_eq!(width_chunks("", 1).next(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "columns must be non-zero")]
+    fn zero_columns() {
+        width_chunks("头尾都是", 0);
+    }
+}
@@ -0,0 +1,165 @@
+//! The display widths of chars, derived from the general categories and the
+//! East Asian widths of Unicode 14.0 by `scripts/width_tables.py`.
+
+/// The ranges of chars that are zero width or wide, sorted, with their
+/// widths. Control chars aren't included, and unassigned chars are only
+/// wide in the blocks of ideographs.
+pub(super) const WIDTHS: &[(char, char, u8)] = &[
+    ('\u{300}', '\u{36f}', 0), ('\u{483}', '\u{489}', 0), ('\u{591}', '\u{5bd}', 0),
+    ('\u{5bf}', '\u{5bf}', 0), ('\u{5c1}', '\u{5c2}', 0), ('\u{5c4}', '\u{5c5}', 0),
+    ('\u{5c7}', '\u{5c7}', 0), ('\u{600}', '\u{605}', 0), ('\u{610}', '\u{61a}', 0),
+    ('\u{61c}', '\u{61c}', 0), ('\u{64b}', '\u{65f}', 0), ('\u{670}', '\u{670}', 0),
+    ('\u{6d6}', '\u{6dd}', 0), ('\u{6df}', '\u{6e4}', 0), ('\u{6e7}', '\u{6e8}', 0),
+    ('\u{6ea}', '\u{6ed}', 0), ('\u{70f}', '\u{70f}', 0), ('\u{711}', '\u{711}', 0),
+    ('\u{730}', '\u{74a}', 0), ('\u{7a6}', '\u{7b0}', 0), ('\u{7eb}', '\u{7f3}', 0),
+    ('\u{7fd}', '\u{7fd}', 0), ('\u{816}', '\u{819}', 0), ('\u{81b}', '\u{823}', 0),
+    ('\u{825}', '\u{827}', 0), ('\u{829}', '\u{82d}', 0), ('\u{859}', '\u{85b}', 0),
+    ('\u{890}', '\u{891}', 0), ('\u{898}', '\u{89f}', 0), ('\u{8ca}', '\u{902}', 0),
+    ('\u{93a}', '\u{93a}', 0), ('\u{93c}', '\u{93c}', 0), ('\u{941}', '\u{948}', 0),
+    ('\u{94d}', '\u{94d}', 0), ('\u{951}', '\u{957}', 0), ('\u{962}', '\u{963}', 0),
+    ('\u{981}', '\u{981}', 0), ('\u{9bc}', '\u{9bc}', 0), ('\u{9c1}', '\u{9c4}', 0),
+    ('\u{9cd}', '\u{9cd}', 0), ('\u{9e2}', '\u{9e3}', 0), ('\u{9fe}', '\u{9fe}', 0),
+    ('\u{a01}', '\u{a02}', 0), ('\u{a3c}', '\u{a3c}', 0), ('\u{a41}', '\u{a42}', 0),
+    ('\u{a47}', '\u{a48}', 0), ('\u{a4b}', '\u{a4d}', 0), ('\u{a51}', '\u{a51}', 0),
+    ('\u{a70}', '\u{a71}', 0), ('\u{a75}', '\u{a75}', 0), ('\u{a81}', '\u{a82}', 0),
+    ('\u{abc}', '\u{abc}', 0), ('\u{ac1}', '\u{ac5}', 0), ('\u{ac7}', '\u{ac8}', 0),
+    ('\u{acd}', '\u{acd}', 0), ('\u{ae2}', '\u{ae3}', 0), ('\u{afa}', '\u{aff}', 0),
+    ('\u{b01}', '\u{b01}', 0), ('\u{b3c}', '\u{b3c}', 0), ('\u{b3f}', '\u{b3f}', 0),
+    ('\u{b41}', '\u{b44}', 0), ('\u{b4d}', '\u{b4d}', 0), ('\u{b55}', '\u{b56}', 0),
+    ('\u{b62}', '\u{b63}', 0), ('\u{b82}', '\u{b82}', 0), ('\u{bc0}', '\u{bc0}', 0),
+    ('\u{bcd}', '\u{bcd}', 0), ('\u{c00}', '\u{c00}', 0), ('\u{c04}', '\u{c04}', 0),
+    ('\u{c3c}', '\u{c3c}', 0), ('\u{c3e}', '\u{c40}', 0), ('\u{c46}', '\u{c48}', 0),
+    ('\u{c4a}', '\u{c4d}', 0), ('\u{c55}', '\u{c56}', 0), ('\u{c62}', '\u{c63}', 0),
+    ('\u{c81}', '\u{c81}', 0), ('\u{cbc}', '\u{cbc}', 0), ('\u{cbf}', '\u{cbf}', 0),
+    ('\u{cc6}', '\u{cc6}', 0), ('\u{ccc}', '\u{ccd}', 0), ('\u{ce2}', '\u{ce3}', 0),
+    ('\u{d00}', '\u{d01}', 0), ('\u{d3b}', '\u{d3c}', 0), ('\u{d41}', '\u{d44}', 0),
+    ('\u{d4d}', '\u{d4d}', 0), ('\u{d62}', '\u{d63}', 0), ('\u{d81}', '\u{d81}', 0),
+    ('\u{dca}', '\u{dca}', 0), ('\u{dd2}', '\u{dd4}', 0), ('\u{dd6}', '\u{dd6}', 0),
+    ('\u{e31}', '\u{e31}', 0), ('\u{e34}', '\u{e3a}', 0), ('\u{e47}', '\u{e4e}', 0),
+    ('\u{eb1}', '\u{eb1}', 0), ('\u{eb4}', '\u{ebc}', 0), ('\u{ec8}', '\u{ecd}', 0),
+    ('\u{f18}', '\u{f19}', 0), ('\u{f35}', '\u{f35}', 0), ('\u{f37}', '\u{f37}', 0),
+    ('\u{f39}', '\u{f39}', 0), ('\u{f71}', '\u{f7e}', 0), ('\u{f80}', '\u{f84}', 0),
+    ('\u{f86}', '\u{f87}', 0), ('\u{f8d}', '\u{f97}', 0), ('\u{f99}', '\u{fbc}', 0),
+    ('\u{fc6}', '\u{fc6}', 0), ('\u{102d}', '\u{1030}', 0), ('\u{1032}', '\u{1037}', 0),
+    ('\u{1039}', '\u{103a}', 0), ('\u{103d}', '\u{103e}', 0), ('\u{1058}', '\u{1059}', 0),
+    ('\u{105e}', '\u{1060}', 0), ('\u{1071}', '\u{1074}', 0), ('\u{1082}', '\u{1082}', 0),
+    ('\u{1085}', '\u{1086}', 0), ('\u{108d}', '\u{108d}', 0), ('\u{109d}', '\u{109d}', 0),
+    ('\u{1100}', '\u{115f}', 2), ('\u{1160}', '\u{11ff}', 0), ('\u{135d}', '\u{135f}', 0),
+    ('\u{1712}', '\u{1714}', 0), ('\u{1732}', '\u{1733}', 0), ('\u{1752}', '\u{1753}', 0),
+    ('\u{1772}', '\u{1773}', 0), ('\u{17b4}', '\u{17b5}', 0), ('\u{17b7}', '\u{17bd}', 0),
+    ('\u{17c6}', '\u{17c6}', 0), ('\u{17c9}', '\u{17d3}', 0), ('\u{17dd}', '\u{17dd}', 0),
+    ('\u{180b}', '\u{180f}', 0), ('\u{1885}', '\u{1886}', 0), ('\u{18a9}', '\u{18a9}', 0),
+    ('\u{1920}', '\u{1922}', 0), ('\u{1927}', '\u{1928}', 0), ('\u{1932}', '\u{1932}', 0),
+    ('\u{1939}', '\u{193b}', 0), ('\u{1a17}', '\u{1a18}', 0), ('\u{1a1b}', '\u{1a1b}', 0),
+    ('\u{1a56}', '\u{1a56}', 0), ('\u{1a58}', '\u{1a5e}', 0), ('\u{1a60}', '\u{1a60}', 0),
+    ('\u{1a62}', '\u{1a62}', 0), ('\u{1a65}', '\u{1a6c}', 0), ('\u{1a73}', '\u{1a7c}', 0),
+    ('\u{1a7f}', '\u{1a7f}', 0), ('\u{1ab0}', '\u{1ace}', 0), ('\u{1b00}', '\u{1b03}', 0),
+    ('\u{1b34}', '\u{1b34}', 0), ('\u{1b36}', '\u{1b3a}', 0), ('\u{1b3c}', '\u{1b3c}', 0),
+    ('\u{1b42}', '\u{1b42}', 0), ('\u{1b6b}', '\u{1b73}', 0), ('\u{1b80}', '\u{1b81}', 0),
+    ('\u{1ba2}', '\u{1ba5}', 0), ('\u{1ba8}', '\u{1ba9}', 0), ('\u{1bab}', '\u{1bad}', 0),
+    ('\u{1be6}', '\u{1be6}', 0), ('\u{1be8}', '\u{1be9}', 0), ('\u{1bed}', '\u{1bed}', 0),
+    ('\u{1bef}', '\u{1bf1}', 0), ('\u{1c2c}', '\u{1c33}', 0), ('\u{1c36}', '\u{1c37}', 0),
+    ('\u{1cd0}', '\u{1cd2}', 0), ('\u{1cd4}', '\u{1ce0}', 0), ('\u{1ce2}', '\u{1ce8}', 0),
+    ('\u{1ced}', '\u{1ced}', 0), ('\u{1cf4}', '\u{1cf4}', 0), ('\u{1cf8}', '\u{1cf9}', 0),
+    ('\u{1dc0}', '\u{1dff}', 0), ('\u{200b}', '\u{200f}', 0), ('\u{202a}', '\u{202e}', 0),
+    ('\u{2060}', '\u{2064}', 0), ('\u{2066}', '\u{206f}', 0), ('\u{20d0}', '\u{20f0}', 0),
+    ('\u{231a}', '\u{231b}', 2), ('\u{2329}', '\u{232a}', 2), ('\u{23e9}', '\u{23ec}', 2),
+    ('\u{23f0}', '\u{23f0}', 2), ('\u{23f3}', '\u{23f3}', 2), ('\u{25fd}', '\u{25fe}', 2),
+    ('\u{2614}', '\u{2615}', 2), ('\u{2648}', '\u{2653}', 2), ('\u{267f}', '\u{267f}', 2),
+    ('\u{2693}', '\u{2693}', 2), ('\u{26a1}', '\u{26a1}', 2), ('\u{26aa}', '\u{26ab}', 2),
+    ('\u{26bd}', '\u{26be}', 2), ('\u{26c4}', '\u{26c5}', 2), ('\u{26ce}', '\u{26ce}', 2),
+    ('\u{26d4}', '\u{26d4}', 2), ('\u{26ea}', '\u{26ea}', 2), ('\u{26f2}', '\u{26f3}', 2),
+    ('\u{26f5}', '\u{26f5}', 2), ('\u{26fa}', '\u{26fa}', 2), ('\u{26fd}', '\u{26fd}', 2),
+    ('\u{2705}', '\u{2705}', 2), ('\u{270a}', '\u{270b}', 2), ('\u{2728}', '\u{2728}', 2),
+    ('\u{274c}', '\u{274c}', 2), ('\u{274e}', '\u{274e}', 2), ('\u{2753}', '\u{2755}', 2),
+    ('\u{2757}', '\u{2757}', 2), ('\u{2795}', '\u{2797}', 2), ('\u{27b0}', '\u{27b0}', 2),
+    ('\u{27bf}', '\u{27bf}', 2), ('\u{2b1b}', '\u{2b1c}', 2), ('\u{2b50}', '\u{2b50}', 2),
+    ('\u{2b55}', '\u{2b55}', 2), ('\u{2cef}', '\u{2cf1}', 0), ('\u{2d7f}', '\u{2d7f}', 0),
+    ('\u{2de0}', '\u{2dff}', 0), ('\u{2e80}', '\u{2e99}', 2), ('\u{2e9b}', '\u{2ef3}', 2),
+    ('\u{2f00}', '\u{2fd5}', 2), ('\u{2ff0}', '\u{2ffb}', 2), ('\u{3000}', '\u{3029}', 2),
+    ('\u{302a}', '\u{302d}', 0), ('\u{302e}', '\u{303e}', 2), ('\u{3041}', '\u{3096}', 2),
+    ('\u{3099}', '\u{309a}', 0), ('\u{309b}', '\u{30ff}', 2), ('\u{3105}', '\u{312f}', 2),
+    ('\u{3131}', '\u{318e}', 2), ('\u{3190}', '\u{31e3}', 2), ('\u{31f0}', '\u{321e}', 2),
+    ('\u{3220}', '\u{3247}', 2), ('\u{3250}', '\u{4dbf}', 2), ('\u{4e00}', '\u{a48c}', 2),
+    ('\u{a490}', '\u{a4c6}', 2), ('\u{a66f}', '\u{a672}', 0), ('\u{a674}', '\u{a67d}', 0),
+    ('\u{a69e}', '\u{a69f}', 0), ('\u{a6f0}', '\u{a6f1}', 0), ('\u{a802}', '\u{a802}', 0),
+    ('\u{a806}', '\u{a806}', 0), ('\u{a80b}', '\u{a80b}', 0), ('\u{a825}', '\u{a826}', 0),
+    ('\u{a82c}', '\u{a82c}', 0), ('\u{a8c4}', '\u{a8c5}', 0), ('\u{a8e0}', '\u{a8f1}', 0),
+    ('\u{a8ff}', '\u{a8ff}', 0), ('\u{a926}', '\u{a92d}', 0), ('\u{a947}', '\u{a951}', 0),
+    ('\u{a960}', '\u{a97c}', 2), ('\u{a980}', '\u{a982}', 0), ('\u{a9b3}', '\u{a9b3}', 0),
+    ('\u{a9b6}', '\u{a9b9}', 0), ('\u{a9bc}', '\u{a9bd}', 0), ('\u{a9e5}', '\u{a9e5}', 0),
+    ('\u{aa29}', '\u{aa2e}', 0), ('\u{aa31}', '\u{aa32}', 0), ('\u{aa35}', '\u{aa36}', 0),
+    ('\u{aa43}', '\u{aa43}', 0), ('\u{aa4c}', '\u{aa4c}', 0), ('\u{aa7c}', '\u{aa7c}', 0),
+    ('\u{aab0}', '\u{aab0}', 0), ('\u{aab2}', '\u{aab4}', 0), ('\u{aab7}', '\u{aab8}', 0),
+    ('\u{aabe}', '\u{aabf}', 0), ('\u{aac1}', '\u{aac1}', 0), ('\u{aaec}', '\u{aaed}', 0),
+    ('\u{aaf6}', '\u{aaf6}', 0), ('\u{abe5}', '\u{abe5}', 0), ('\u{abe8}', '\u{abe8}', 0),
+    ('\u{abed}', '\u{abed}', 0), ('\u{ac00}', '\u{d7a3}', 2), ('\u{f900}', '\u{faff}', 2),
+    ('\u{fb1e}', '\u{fb1e}', 0), ('\u{fe00}', '\u{fe0f}', 0), ('\u{fe10}', '\u{fe19}', 2),
+    ('\u{fe20}', '\u{fe2f}', 0), ('\u{fe30}', '\u{fe52}', 2), ('\u{fe54}', '\u{fe66}', 2),
+    ('\u{fe68}', '\u{fe6b}', 2), ('\u{feff}', '\u{feff}', 0), ('\u{ff01}', '\u{ff60}', 2),
+    ('\u{ffe0}', '\u{ffe6}', 2), ('\u{fff9}', '\u{fffb}', 0), ('\u{101fd}', '\u{101fd}', 0),
+    ('\u{102e0}', '\u{102e0}', 0), ('\u{10376}', '\u{1037a}', 0), ('\u{10a01}', '\u{10a03}', 0),
+    ('\u{10a05}', '\u{10a06}', 0), ('\u{10a0c}', '\u{10a0f}', 0), ('\u{10a38}', '\u{10a3a}', 0),
+    ('\u{10a3f}', '\u{10a3f}', 0), ('\u{10ae5}', '\u{10ae6}', 0), ('\u{10d24}', '\u{10d27}', 0),
+    ('\u{10eab}', '\u{10eac}', 0), ('\u{10f46}', '\u{10f50}', 0), ('\u{10f82}', '\u{10f85}', 0),
+    ('\u{11001}', '\u{11001}', 0), ('\u{11038}', '\u{11046}', 0), ('\u{11070}', '\u{11070}', 0),
+    ('\u{11073}', '\u{11074}', 0), ('\u{1107f}', '\u{11081}', 0), ('\u{110b3}', '\u{110b6}', 0),
+    ('\u{110b9}', '\u{110ba}', 0), ('\u{110bd}', '\u{110bd}', 0), ('\u{110c2}', '\u{110c2}', 0),
+    ('\u{110cd}', '\u{110cd}', 0), ('\u{11100}', '\u{11102}', 0), ('\u{11127}', '\u{1112b}', 0),
+    ('\u{1112d}', '\u{11134}', 0), ('\u{11173}', '\u{11173}', 0), ('\u{11180}', '\u{11181}', 0),
+    ('\u{111b6}', '\u{111be}', 0), ('\u{111c9}', '\u{111cc}', 0), ('\u{111cf}', '\u{111cf}', 0),
+    ('\u{1122f}', '\u{11231}', 0), ('\u{11234}', '\u{11234}', 0), ('\u{11236}', '\u{11237}', 0),
+    ('\u{1123e}', '\u{1123e}', 0), ('\u{112df}', '\u{112df}', 0), ('\u{112e3}', '\u{112ea}', 0),
+    ('\u{11300}', '\u{11301}', 0), ('\u{1133b}', '\u{1133c}', 0), ('\u{11340}', '\u{11340}', 0),
+    ('\u{11366}', '\u{1136c}', 0), ('\u{11370}', '\u{11374}', 0), ('\u{11438}', '\u{1143f}', 0),
+    ('\u{11442}', '\u{11444}', 0), ('\u{11446}', '\u{11446}', 0), ('\u{1145e}', '\u{1145e}', 0),
+    ('\u{114b3}', '\u{114b8}', 0), ('\u{114ba}', '\u{114ba}', 0), ('\u{114bf}', '\u{114c0}', 0),
+    ('\u{114c2}', '\u{114c3}', 0), ('\u{115b2}', '\u{115b5}', 0), ('\u{115bc}', '\u{115bd}', 0),
+    ('\u{115bf}', '\u{115c0}', 0), ('\u{115dc}', '\u{115dd}', 0), ('\u{11633}', '\u{1163a}', 0),
+    ('\u{1163d}', '\u{1163d}', 0), ('\u{1163f}', '\u{11640}', 0), ('\u{116ab}', '\u{116ab}', 0),
+    ('\u{116ad}', '\u{116ad}', 0), ('\u{116b0}', '\u{116b5}', 0), ('\u{116b7}', '\u{116b7}', 0),
+    ('\u{1171d}', '\u{1171f}', 0), ('\u{11722}', '\u{11725}', 0), ('\u{11727}', '\u{1172b}', 0),
+    ('\u{1182f}', '\u{11837}', 0), ('\u{11839}', '\u{1183a}', 0), ('\u{1193b}', '\u{1193c}', 0),
+    ('\u{1193e}', '\u{1193e}', 0), ('\u{11943}', '\u{11943}', 0), ('\u{119d4}', '\u{119d7}', 0),
+    ('\u{119da}', '\u{119db}', 0), ('\u{119e0}', '\u{119e0}', 0), ('\u{11a01}', '\u{11a0a}', 0),
+    ('\u{11a33}', '\u{11a38}', 0), ('\u{11a3b}', '\u{11a3e}', 0), ('\u{11a47}', '\u{11a47}', 0),
+    ('\u{11a51}', '\u{11a56}', 0), ('\u{11a59}', '\u{11a5b}', 0), ('\u{11a8a}', '\u{11a96}', 0),
+    ('\u{11a98}', '\u{11a99}', 0), ('\u{11c30}', '\u{11c36}', 0), ('\u{11c38}', '\u{11c3d}', 0),
+    ('\u{11c3f}', '\u{11c3f}', 0), ('\u{11c92}', '\u{11ca7}', 0), ('\u{11caa}', '\u{11cb0}', 0),
+    ('\u{11cb2}', '\u{11cb3}', 0), ('\u{11cb5}', '\u{11cb6}', 0), ('\u{11d31}', '\u{11d36}', 0),
+    ('\u{11d3a}', '\u{11d3a}', 0), ('\u{11d3c}', '\u{11d3d}', 0), ('\u{11d3f}', '\u{11d45}', 0),
+    ('\u{11d47}', '\u{11d47}', 0), ('\u{11d90}', '\u{11d91}', 0), ('\u{11d95}', '\u{11d95}', 0),
+    ('\u{11d97}', '\u{11d97}', 0), ('\u{11ef3}', '\u{11ef4}', 0), ('\u{13430}', '\u{13438}', 0),
+    ('\u{16af0}', '\u{16af4}', 0), ('\u{16b30}', '\u{16b36}', 0), ('\u{16f4f}', '\u{16f4f}', 0),
+    ('\u{16f8f}', '\u{16f92}', 0), ('\u{16fe0}', '\u{16fe3}', 2), ('\u{16fe4}', '\u{16fe4}', 0),
+    ('\u{16ff0}', '\u{16ff1}', 2), ('\u{17000}', '\u{187f7}', 2), ('\u{18800}', '\u{18cd5}', 2),
+    ('\u{18d00}', '\u{18d08}', 2), ('\u{1aff0}', '\u{1aff3}', 2), ('\u{1aff5}', '\u{1affb}', 2),
+    ('\u{1affd}', '\u{1affe}', 2), ('\u{1b000}', '\u{1b122}', 2), ('\u{1b150}', '\u{1b152}', 2),
+    ('\u{1b164}', '\u{1b167}', 2), ('\u{1b170}', '\u{1b2fb}', 2), ('\u{1bc9d}', '\u{1bc9e}', 0),
+    ('\u{1bca0}', '\u{1bca3}', 0), ('\u{1cf00}', '\u{1cf2d}', 0), ('\u{1cf30}', '\u{1cf46}', 0),
+    ('\u{1d167}', '\u{1d169}', 0), ('\u{1d173}', '\u{1d182}', 0), ('\u{1d185}', '\u{1d18b}', 0),
+    ('\u{1d1aa}', '\u{1d1ad}', 0), ('\u{1d242}', '\u{1d244}', 0), ('\u{1da00}', '\u{1da36}', 0),
+    ('\u{1da3b}', '\u{1da6c}', 0), ('\u{1da75}', '\u{1da75}', 0), ('\u{1da84}', '\u{1da84}', 0),
+    ('\u{1da9b}', '\u{1da9f}', 0), ('\u{1daa1}', '\u{1daaf}', 0), ('\u{1e000}', '\u{1e006}', 0),
+    ('\u{1e008}', '\u{1e018}', 0), ('\u{1e01b}', '\u{1e021}', 0), ('\u{1e023}', '\u{1e024}', 0),
+    ('\u{1e026}', '\u{1e02a}', 0), ('\u{1e130}', '\u{1e136}', 0), ('\u{1e2ae}', '\u{1e2ae}', 0),
+    ('\u{1e2ec}', '\u{1e2ef}', 0), ('\u{1e8d0}', '\u{1e8d6}', 0), ('\u{1e944}', '\u{1e94a}', 0),
+    ('\u{1f004}', '\u{1f004}', 2), ('\u{1f0cf}', '\u{1f0cf}', 2), ('\u{1f18e}', '\u{1f18e}', 2),
+    ('\u{1f191}', '\u{1f19a}', 2), ('\u{1f200}', '\u{1f202}', 2), ('\u{1f210}', '\u{1f23b}', 2),
+    ('\u{1f240}', '\u{1f248}', 2), ('\u{1f250}', '\u{1f251}', 2), ('\u{1f260}', '\u{1f265}', 2),
+    ('\u{1f300}', '\u{1f320}', 2), ('\u{1f32d}', '\u{1f335}', 2), ('\u{1f337}', '\u{1f37c}', 2),
+    ('\u{1f37e}', '\u{1f393}', 2), ('\u{1f3a0}', '\u{1f3ca}', 2), ('\u{1f3cf}', '\u{1f3d3}', 2),
+    ('\u{1f3e0}', '\u{1f3f0}', 2), ('\u{1f3f4}', '\u{1f3f4}', 2), ('\u{1f3f8}', '\u{1f43e}', 2),
+    ('\u{1f440}', '\u{1f440}', 2), ('\u{1f442}', '\u{1f4fc}', 2), ('\u{1f4ff}', '\u{1f53d}', 2),
+    ('\u{1f54b}', '\u{1f54e}', 2), ('\u{1f550}', '\u{1f567}', 2), ('\u{1f57a}', '\u{1f57a}', 2),
+    ('\u{1f595}', '\u{1f596}', 2), ('\u{1f5a4}', '\u{1f5a4}', 2), ('\u{1f5fb}', '\u{1f64f}', 2),
+    ('\u{1f680}', '\u{1f6c5}', 2), ('\u{1f6cc}', '\u{1f6cc}', 2), ('\u{1f6d0}', '\u{1f6d2}', 2),
+    ('\u{1f6d5}', '\u{1f6d7}', 2), ('\u{1f6dd}', '\u{1f6df}', 2), ('\u{1f6eb}', '\u{1f6ec}', 2),
+    ('\u{1f6f4}', '\u{1f6fc}', 2), ('\u{1f7e0}', '\u{1f7eb}', 2), ('\u{1f7f0}', '\u{1f7f0}', 2),
+    ('\u{1f90c}', '\u{1f93a}', 2), ('\u{1f93c}', '\u{1f945}', 2), ('\u{1f947}', '\u{1f9ff}', 2),
+    ('\u{1fa70}', '\u{1fa74}', 2), ('\u{1fa78}', '\u{1fa7c}', 2), ('\u{1fa80}', '\u{1fa86}', 2),
+    ('\u{1fa90}', '\u{1faac}', 2), ('\u{1fab0}', '\u{1faba}', 2), ('\u{1fac0}', '\u{1fac5}', 2),
+    ('\u{1fad0}', '\u{1fad9}', 2), ('\u{1fae0}', '\u{1fae7}', 2), ('\u{1faf0}', '\u{1faf6}', 2),
+    ('\u{20000}', '\u{2fffd}', 2), ('\u{30000}', '\u{3fffd}', 2), ('\u{e0001}', '\u{e0001}', 0),
+    ('\u{e0020}', '\u{e007f}', 0), ('\u{e0100}', '\u{e01ef}', 0),
+];
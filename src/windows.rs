@@ -0,0 +1,249 @@
+use std::iter::FusedIterator;
+
+use boundary::next_char_boundary;
+
+/// A segmentation of a `&str` into units, the boundaries [`Windows`] slides
+/// windows over.
+///
+/// Implementing it for a custom segmenter, e.g. one splitting Thai text with
+/// a dictionary, gives windows of its units without reimplementing the
+/// windows.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::{Boundaries, Windows};
+///
+/// /// Numbers are units of their own, other chars are single units.
+/// struct Numbers;
+///
+/// impl Boundaries for Numbers {
+///     fn next_boundary(&self, s: &str, from: usize) -> Option<usize> {
+///         let rest = &s[from..];
+///         let c = rest.chars().next()?;
+///         let len = if c.is_ascii_digit() {
+///             rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len())
+///         } else {
+///             c.len_utf8()
+///         };
+///         Some(from + len)
+///     }
+/// }
+///
+/// let windows = Windows::new("x=42+7", 3, Numbers).collect::<Vec<_>>();
+/// assert_eq!(windows, ["x=42", "=42+", "42+7"]);
+/// ```
+pub trait Boundaries {
+    /// Returns the end of the first unit of `s` from the boundary `from`, or
+    /// `None` if no unit is left.
+    fn next_boundary(&self, s: &str, from: usize) -> Option<usize>;
+
+    /// Returns the start of the first unit of `s` from the boundary `from`,
+    /// or `s.len()` if no unit is left, for segmentations with text between
+    /// their units that windows neither start nor end with, like the
+    /// separators between words. Defaults to `from`, the units then cover
+    /// `s`.
+    fn unit_start(&self, s: &str, from: usize) -> usize {
+        let _ = s;
+        from
+    }
+
+    /// Returns the number of units of `s`, if it's known or cheap to count,
+    /// to give [`Windows`] an exact `size_hint`. Defaults to `None`.
+    fn count_hint(&self, s: &str) -> Option<usize> {
+        let _ = s;
+        None
+    }
+}
+
+/// The boundaries of the chars of a `&str`, the units of [`str_windows`].
+///
+/// [`str_windows`]: crate::str_windows
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CharBoundaries;
+
+impl Boundaries for CharBoundaries {
+    fn next_boundary(&self, s: &str, from: usize) -> Option<usize> {
+        if from < s.len() {
+            Some(next_char_boundary(s, from))
+        } else {
+            None
+        }
+    }
+
+    fn count_hint(&self, s: &str) -> Option<usize> {
+        Some(s.chars().count())
+    }
+}
+
+/// An iterator over the windows of `size` consecutive units of a `&str`, for
+/// any segmentation into units given by [`Boundaries`].
+///
+/// With [`CharBoundaries`] it yields the windows of [`str_windows`], and as
+/// with it a `size` of 0 yields `Some("")` forever. The windows of grapheme
+/// clusters and words of this crate are built on it, while the specialized
+/// iterators of chars, like [`StrWindows`], add double-ended iteration and
+/// other methods that a segmentation only known from the front can't
+/// support.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::{CharBoundaries, Windows};
+///
+/// let windows = Windows::new("s 😀😁", 3, CharBoundaries).collect::<Vec<_>>();
+/// assert_eq!(windows, ["s 😀", " 😀😁"]);
+/// ```
+///
+/// [`str_windows`]: crate::str_windows
+/// [`StrWindows`]: crate::StrWindows
+#[derive(Clone, Debug)]
+pub struct Windows<'a, B> {
+    input: &'a str,
+    /// Start of the next window.
+    start: usize,
+    /// End of the next window, if there is one.
+    end: Option<usize>,
+    boundaries: B,
+    size: usize,
+    /// Number of windows left to yield, if the boundaries counted the units.
+    len: Option<usize>,
+}
+
+impl<'a, B: Boundaries> Windows<'a, B> {
+    /// Returns the windows of `size` units of `input` split by `boundaries`.
+    pub fn new(input: &'a str, size: usize, boundaries: B) -> Self {
+        let start = boundaries.unit_start(input, 0);
+        let mut end = Some(start);
+        for _ in 0..size {
+            end = match end {
+                Some(end) => boundaries.next_boundary(input, end),
+                None => break,
+            };
+        }
+        let len = boundaries.count_hint(input).map(|units| (units + 1).saturating_sub(size));
+        Windows {
+            input,
+            start,
+            end,
+            boundaries,
+            size,
+            len,
+        }
+    }
+
+    /// Returns the byte offset, within the input, at which the next window
+    /// starts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use str_windows::{CharBoundaries, Windows};
+    ///
+    /// let mut iter = Windows::new("头尾都是", 2, CharBoundaries);
+    /// assert_eq!(iter.offset(), 0);
+    /// iter.next();
+    /// assert_eq!(iter.offset(), "头".len());
+    /// ```
+    pub fn offset(&self) -> usize {
+        self.start
+    }
+}
+
+impl<'a, B: Boundaries> FusedIterator for Windows<'a, B> { }
+
+impl<'a, B: Boundaries> Iterator for Windows<'a, B> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // degenerate case
+        if self.size == 0 {
+            return Some("");
+        }
+        let end = self.end?;
+        let window = &self.input[self.start..end];
+        // a window has at least one unit
+        let start = self.boundaries.next_boundary(self.input, self.start).unwrap();
+        self.start = self.boundaries.unit_start(self.input, start);
+        self.end = self.boundaries.next_boundary(self.input, end);
+        if let Some(len) = self.len.as_mut() {
+            *len -= 1;
+        }
+        Some(window)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.size == 0 {
+            return (usize::MAX, None);
+        }
+        match self.len {
+            Some(len) => (len, Some(len)),
+            // a window starts at each unit, which has at least one byte
+            None => (self.end.is_some() as usize, Some(self.input.len() - self.start)),
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::{Boundaries, Windows};
+
+    /// Splits after every `'/'` or at the end.
+    struct Components;
+
+    impl Boundaries for Components {
+        fn next_boundary(&self, s: &str, from: usize) -> Option<usize> {
+            if from == s.len() {
+                return None;
+            }
+            Some(s[from..].find('/').map_or(s.len(), |i| from + i + 1))
+        }
+    }
+
+    #[test]
+    fn degenerate() {
+        let mut iter = Windows::new("a/b", 0, Components);
+        assert_eq!(iter.size_hint(), (usize::MAX, None));
+        assert_eq!(iter.nth(100), Some(""));
+    }
+
+    #[test]
+    fn custom_test() {
+        let iter = Windows::new("usr/local/bin", 2, Components);
+        assert_eq!(iter.size_hint(), (1, Some(13)));
+        assert_eq!(iter.collect::<Vec<_>>(), ["usr/local/", "local/bin"]);
+        assert_eq!(Windows::new("a/b/", 3, Components).next(), None);
+        assert_eq!(Windows::new("", 1, Components).next(), None);
+        assert_eq!(Windows::new("a/b", usize::MAX, Components).next(), None);
+    }
+
+    /// Runs of ASCII digits, separated by any other chars.
+    struct Digits;
+
+    impl Boundaries for Digits {
+        fn next_boundary(&self, s: &str, from: usize) -> Option<usize> {
+            let start = self.unit_start(s, from);
+            if start == s.len() {
+                return None;
+            }
+            let len = s[start..].find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len() - start);
+            Some(start + len)
+        }
+
+        fn unit_start(&self, s: &str, from: usize) -> usize {
+            s[from..].find(|c: char| c.is_ascii_digit()).map_or(s.len(), |i| from + i)
+        }
+    }
+
+    #[test]
+    fn gaps_test() {
+        let mut iter = Windows::new(" 1, 22; 333 ", 2, Digits);
+        assert_eq!(iter.offset(), 1);
+        assert_eq!(iter.next(), Some("1, 22"));
+        assert_eq!(iter.offset(), 4);
+        assert_eq!(iter.collect::<Vec<_>>(), ["22; 333"]);
+        assert_eq!(Windows::new("a 1 b", 1, Digits).collect::<Vec<_>>(), ["1"]);
+        assert_eq!(Windows::new("no digits", 1, Digits).next(), None);
+    }
+}
@@ -0,0 +1,112 @@
+use std::iter::FusedIterator;
+
+use {str_windows, StrWindows};
+
+/// Returns the windows of `size` chars paired with the char following each
+/// of them, e.g. the (context, next char) pairs of a character-level language
+/// model.
+///
+/// The last window has no following char and isn't yielded, so there is one
+/// pair less than with [`str_windows`].
+///
+/// # Panics
+///
+/// Panics if `size` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::str_windows_with_next;
+///
+/// let pairs = str_windows_with_next("abc😀", 2).collect::<Vec<_>>();
+/// assert_eq!(pairs, [("ab", 'c'), ("bc", '😀')]);
+/// ```
+///
+/// [`str_windows`]: crate::str_windows
+pub fn str_windows_with_next(input: &str, size: usize) -> StrWindowsWithNext<'_> {
+    assert!(size != 0, "window size must be non-zero");
+    StrWindowsWithNext {
+        inner: str_windows(input, size.saturating_add(1)),
+    }
+}
+
+/// Splits the last char off a window.
+fn split_next(window: &str) -> (&str, char) {
+    // windows have at least one char
+    let next = window.chars().next_back().unwrap();
+    (&window[..window.len() - next.len_utf8()], next)
+}
+
+/// An iterator over the windows of `size` chars of a `&str` and the char
+/// following each of them.
+///
+/// This struct is created by [`str_windows_with_next`].
+#[derive(Clone, Debug)]
+pub struct StrWindowsWithNext<'a> {
+    /// The windows of `size + 1` chars.
+    inner: StrWindows<'a>,
+}
+
+impl<'a> FusedIterator for StrWindowsWithNext<'a> { }
+
+impl<'a> Iterator for StrWindowsWithNext<'a> {
+    type Item = (&'a str, char);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(split_next)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.inner.nth(n).map(split_next)
+    }
+}
+
+impl<'a> DoubleEndedIterator for StrWindowsWithNext<'a> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(split_next)
+    }
+}
+
+impl<'a> ExactSizeIterator for StrWindowsWithNext<'a> { }
+
+
+#[cfg(test)]
+mod tests {
+    use super::str_windows_with_next;
+    use str_windows;
+
+    #[test]
+    fn with_next_test() {
+        let pairs = str_windows_with_next("头尾都😀", 2).collect::<Vec<_>>();
+        assert_eq!(pairs, [("头尾", '都'), ("尾都", '😀')]);
+        assert_eq!(str_windows_with_next("头尾都", 3).next(), None);
+        assert_eq!(str_windows_with_next("头尾都是", 2).next_back(), Some(("尾都", '是')));
+        assert_eq!(str_windows_with_next("", 1).next(), None);
+    }
+
+    #[test]
+    fn pairs_test() {
+        for &src in &["hello, 你好, hi", "头尾都是", "s 😀😁", ""] {
+            for size in 1..=6 {
+                let iter = str_windows_with_next(src, size);
+                let windows = str_windows(src, size).collect::<Vec<_>>();
+                assert_eq!(iter.len(), windows.len().saturating_sub(1));
+                for ((window, next), expected) in iter.zip(windows) {
+                    assert_eq!(window, expected);
+                    assert!(src[expected.as_ptr() as usize - src.as_ptr() as usize..]
+                        .starts_with(&format!("{}{}", window, next)));
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "window size must be non-zero")]
+    fn zero_size() {
+        str_windows_with_next("头尾都是", 0);
+    }
+}
@@ -0,0 +1,108 @@
+use std::iter::FusedIterator;
+use std::str::Split;
+
+use {str_windows_strict, StrWindowsNonZero};
+
+/// Returns the windows of `size` chars of [`str_windows`] that don't contain
+/// `delimiter`, e.g. the windows of every line of a text for `'\n'`.
+///
+/// This is the same as windowing every segment of `input.split(delimiter)`,
+/// without collecting the segments: the windows of a segment are yielded in
+/// order, then iteration resumes after the next delimiter. Segments shorter
+/// than `size` yield nothing.
+///
+/// # Panics
+///
+/// Panics if `size` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::str_windows_within;
+///
+/// let windows = str_windows_within("abc\nde\n\nf", 2, '\n').collect::<Vec<_>>();
+/// assert_eq!(windows, ["ab", "bc", "de"]);
+/// ```
+///
+/// [`str_windows`]: crate::str_windows
+pub fn str_windows_within(input: &str, size: usize, delimiter: char) -> StrWindowsWithin<'_> {
+    assert!(size != 0, "window size must be non-zero");
+    let mut segments = input.split(delimiter);
+    // `split` yields at least one segment
+    let windows = str_windows_strict(segments.next().unwrap_or(""), size);
+    StrWindowsWithin {
+        segments,
+        windows,
+        size,
+    }
+}
+
+/// An iterator over the windows of `size` chars of a `&str` that don't
+/// contain a delimiter.
+///
+/// This struct is created by [`str_windows_within`].
+#[derive(Clone, Debug)]
+pub struct StrWindowsWithin<'a> {
+    segments: Split<'a, char>,
+    /// Windows of the current segment.
+    windows: StrWindowsNonZero<'a>,
+    size: usize,
+}
+
+impl<'a> FusedIterator for StrWindowsWithin<'a> { }
+
+impl<'a> Iterator for StrWindowsWithin<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(window) = self.windows.next() {
+                return Some(window);
+            }
+            self.windows = str_windows_strict(self.segments.next()?, self.size);
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.windows.len(), None)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::str_windows_within;
+    use str_windows;
+
+    #[test]
+    fn within_test() {
+        let windows = str_windows_within("头尾,,都是😀,a,", 2, ',').collect::<Vec<_>>();
+        assert_eq!(windows, ["头尾", "都是", "是😀"]);
+        let windows = str_windows_within("\n\nab\n", 1, '\n').collect::<Vec<_>>();
+        assert_eq!(windows, ["a", "b"]);
+        assert_eq!(str_windows_within("头尾", 2, '尾').next(), None);
+        assert_eq!(str_windows_within("", 1, '\n').next(), None);
+    }
+
+    #[test]
+    fn matches_split_test() {
+        for &src in &["hello, 你好, hi", "a\n\nbc\nd😀e\n", "头尾都是", "s 😀😁", ""] {
+            for &delimiter in &[',', '\n', ' ', '😀'] {
+                for size in 1..=4 {
+                    let windows = str_windows_within(src, size, delimiter).collect::<Vec<_>>();
+                    let expected = src.split(delimiter)
+                        .flat_map(|segment| str_windows(segment, size))
+                        .collect::<Vec<_>>();
+                    assert_eq!(windows, expected);
+                    assert!(windows.iter().all(|window| !window.contains(delimiter)));
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "window size must be non-zero")]
+    fn zero_size() {
+        str_windows_within("头尾都是", 0, ',');
+    }
+}
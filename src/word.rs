@@ -0,0 +1,398 @@
+use std::iter::FusedIterator;
+
+use boundary::{next_char_boundary, nth_char_boundary};
+use windows::{Boundaries, Windows};
+
+/// Returns the windows of `size` chars that start at the beginning of a word,
+/// for keyword spotting.
+///
+/// A word begins at a char that isn't whitespace and is either the first char
+/// of the input or preceded by whitespace, so punctuation sticking to a word
+/// starts the word. Windows extend freely over the following words. Like
+/// [`str_windows`], no window is shorter than `size`.
+///
+/// # Panics
+///
+/// Panics if `size` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::str_windows_at_word_starts;
+///
+/// let windows = str_windows_at_word_starts("to be or", 4).collect::<Vec<_>>();
+/// assert_eq!(windows, ["to b", "be o"]);
+/// ```
+///
+/// [`str_windows`]: crate::str_windows
+pub fn str_windows_at_word_starts(input: &str, size: usize) -> StrWindowsAtWordStarts<'_> {
+    assert!(size != 0, "window size must be non-zero");
+    StrWindowsAtWordStarts {
+        rest: input,
+        after_space: true,
+        size,
+    }
+}
+
+/// An iterator over the windows of `size` chars of a `&str` starting at the
+/// beginning of a word.
+///
+/// This struct is created by [`str_windows_at_word_starts`].
+#[derive(Clone, Debug)]
+pub struct StrWindowsAtWordStarts<'a> {
+    /// The input after the start of the last window.
+    rest: &'a str,
+    /// Whether the char before `rest` is whitespace.
+    after_space: bool,
+    size: usize,
+}
+
+impl<'a> FusedIterator for StrWindowsAtWordStarts<'a> { }
+
+impl<'a> Iterator for StrWindowsAtWordStarts<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut after_space = self.after_space;
+        let start = self.rest.char_indices().find(|&(_, c)| {
+            let starts_word = after_space && !c.is_whitespace();
+            after_space = c.is_whitespace();
+            starts_word
+        });
+        let rest = match start {
+            Some((start, _)) => &self.rest[start..],
+            None => "",
+        };
+        // the next word starts after the first char of the window
+        self.after_space = false;
+        let last = nth_char_boundary(rest, self.size - 1);
+        if last == rest.len() {
+            // later windows would be shorter too
+            self.rest = "";
+            return None;
+        }
+        self.rest = &rest[next_char_boundary(rest, 0)..];
+        Some(&rest[..next_char_boundary(rest, last)])
+    }
+}
+
+/// Returns the byte range of the first whitespace-separated token of `input`
+/// from `i`.
+fn next_token(input: &str, i: usize) -> Option<(usize, usize)> {
+    let rest = input[i..].trim_start();
+    if rest.is_empty() {
+        return None;
+    }
+    let start = input.len() - rest.len();
+    let len = rest.find(char::is_whitespace).unwrap_or(rest.len());
+    Some((start, start + len))
+}
+
+/// The boundaries of the whitespace-separated tokens of a `&str`.
+#[derive(Clone, Copy, Debug)]
+struct TokenBoundaries;
+
+impl Boundaries for TokenBoundaries {
+    fn next_boundary(&self, s: &str, from: usize) -> Option<usize> {
+        next_token(s, from).map(|(_, end)| end)
+    }
+
+    fn unit_start(&self, s: &str, from: usize) -> usize {
+        next_token(s, from).map_or(s.len(), |(start, _)| start)
+    }
+
+    fn count_hint(&self, s: &str) -> Option<usize> {
+        Some(token_count(s))
+    }
+}
+
+/// Returns the windows of `n` consecutive tokens of `split_whitespace`, each
+/// as the slice of the input from the start of its first token to the end of
+/// its last one.
+///
+/// The whitespace between the tokens of a window is kept as it is in the
+/// input, while the whitespace before the first token and after the last one
+/// isn't part of any window. An input of fewer than `n` tokens yields
+/// nothing.
+///
+/// # Panics
+///
+/// Panics if `n` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::ws_word_windows;
+///
+/// let windows = ws_word_windows(" to  be\tor ", 2).collect::<Vec<_>>();
+/// assert_eq!(windows, ["to  be", "be\tor"]);
+/// ```
+pub fn ws_word_windows(input: &str, n: usize) -> WsWordWindows<'_> {
+    assert!(n != 0, "window size must be non-zero");
+    WsWordWindows {
+        inner: Windows::new(input, n, TokenBoundaries),
+    }
+}
+
+/// An iterator over the windows of `n` consecutive whitespace-separated
+/// tokens of a `&str`.
+///
+/// This struct is created by [`ws_word_windows`].
+#[derive(Clone, Debug)]
+pub struct WsWordWindows<'a> {
+    inner: Windows<'a, TokenBoundaries>,
+}
+
+impl<'a> FusedIterator for WsWordWindows<'a> { }
+
+impl<'a> Iterator for WsWordWindows<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a> ExactSizeIterator for WsWordWindows<'a> { }
+
+/// Returns the number of whitespace-separated tokens of `input`.
+fn token_count(input: &str) -> usize {
+    input.split_whitespace().count()
+}
+
+/// Returns consecutive chunks of `n` tokens of `split_whitespace`, each as
+/// the slice of the input from the start of its first token to the end of
+/// its last one, e.g. to batch a long text into pieces of a number of words.
+///
+/// The chunks are the windows of [`ws_word_windows`] that don't overlap, and
+/// the last chunk has fewer tokens if they don't divide evenly. The
+/// whitespace between the tokens of a chunk is kept as it is in the input,
+/// so the chunks and the whitespace between them make up the input, except
+/// for the whitespace before the first token and after the last one. With
+/// the `words` feature, [`unicode_words`](WordChunks::unicode_words) makes
+/// chunks of the words of [`word_windows`] instead.
+///
+/// # Panics
+///
+/// Panics if `n` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::word_chunks;
+///
+/// let chunks = word_chunks(" to  be\tor not ", 3).collect::<Vec<_>>();
+/// assert_eq!(chunks, ["to  be\tor", "not"]);
+/// ```
+///
+/// [`word_windows`]: crate::word_windows
+pub fn word_chunks(input: &str, n: usize) -> WordChunks<'_> {
+    assert!(n != 0, "chunk size must be non-zero");
+    WordChunks {
+        input,
+        start: 0,
+        n,
+        next_word: next_token,
+        len: token_count(input).div_ceil(n),
+    }
+}
+
+/// An iterator over consecutive chunks of `n` words of a `&str`.
+///
+/// This struct is created by [`word_chunks`].
+#[derive(Clone, Debug)]
+pub struct WordChunks<'a> {
+    input: &'a str,
+    /// End of the last chunk yielded.
+    start: usize,
+    n: usize,
+    /// Returns the byte range of the first word from an offset.
+    next_word: fn(&str, usize) -> Option<(usize, usize)>,
+    /// Number of chunks left to yield.
+    len: usize,
+}
+
+impl<'a> WordChunks<'a> {
+    /// Sets whether the words are the words of UAX #29 of [`word_windows`]
+    /// instead of whitespace-separated tokens, so punctuation isn't counted
+    /// as words and is skipped between chunks. Defaults to `false`.
+    ///
+    /// This requires the `words` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use str_windows::word_chunks;
+    ///
+    /// let chunks = word_chunks("hello, world - now!", 2).unicode_words(true);
+    /// assert_eq!(chunks.collect::<Vec<_>>(), ["hello, world", "now"]);
+    /// ```
+    ///
+    /// [`word_windows`]: crate::word_windows
+    #[cfg(feature = "words")]
+    pub fn unicode_words(mut self, unicode_words: bool) -> Self {
+        let count: fn(&str) -> usize = if unicode_words {
+            self.next_word = ::words::next_word;
+            ::words::word_count
+        } else {
+            self.next_word = next_token;
+            token_count
+        };
+        self.len = count(&self.input[self.start..]).div_ceil(self.n);
+        self
+    }
+}
+
+impl<'a> FusedIterator for WordChunks<'a> { }
+
+impl<'a> Iterator for WordChunks<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        // there are words left for the chunk
+        let (start, mut end) = (self.next_word)(self.input, self.start).unwrap();
+        for _ in 1..self.n {
+            match (self.next_word)(self.input, end) {
+                Some((_, word_end)) => end = word_end,
+                None => break,
+            }
+        }
+        self.start = end;
+        Some(&self.input[start..end])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a> ExactSizeIterator for WordChunks<'a> { }
+
+
+#[cfg(test)]
+mod tests {
+    use super::{str_windows_at_word_starts, word_chunks, ws_word_windows};
+
+    #[test]
+    fn word_starts_test() {
+        let windows = str_windows_at_word_starts("(hi), 你好 ɛ́x!", 3).collect::<Vec<_>>();
+        assert_eq!(windows, ["(hi", "你好 ", "ɛ́x"]);
+        let windows = str_windows_at_word_starts("  😀a\t\nébc d", 2).collect::<Vec<_>>();
+        assert_eq!(windows, ["😀a", "éb"]);
+        let windows = str_windows_at_word_starts("a b c", 1).collect::<Vec<_>>();
+        assert_eq!(windows, ["a", "b", "c"]);
+        assert_eq!(str_windows_at_word_starts("头尾", 3).next(), None);
+        assert_eq!(str_windows_at_word_starts("   ", 1).next(), None);
+        assert_eq!(str_windows_at_word_starts("", 1).next(), None);
+    }
+
+    #[test]
+    fn filtered_test() {
+        for &src in &["hello, 你好, hi", " a  bc d😀e ", "头尾 都是", "s 😀😁"] {
+            for size in 1..=5 {
+                let expected = src.char_indices()
+                    .filter(|&(i, c)| {
+                        !c.is_whitespace()
+                            && src[..i].chars().next_back().is_none_or(char::is_whitespace)
+                    })
+                    .filter_map(|(i, _)| {
+                        let rest = &src[i..];
+                        rest.char_indices()
+                            .map(|x| x.0)
+                            .chain(Some(rest.len()))
+                            .nth(size)
+                            .map(|end| &rest[..end])
+                    })
+                    .collect::<Vec<_>>();
+                let windows = str_windows_at_word_starts(src, size).collect::<Vec<_>>();
+                assert_eq!(windows, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn ws_words_test() {
+        let src = "\n the   quick\tbrown\r\n\nfox  ";
+        let windows = ws_word_windows(src, 2).collect::<Vec<_>>();
+        assert_eq!(windows, ["the   quick", "quick\tbrown", "brown\r\n\nfox"]);
+        let windows = ws_word_windows(src, 4).collect::<Vec<_>>();
+        assert_eq!(windows, [src.trim()]);
+        let windows = ws_word_windows("头尾\u{3000}都是 😀", 1).collect::<Vec<_>>();
+        assert_eq!(windows, ["头尾", "都是", "😀"]);
+        assert_eq!(ws_word_windows(src, 5).next(), None);
+        assert_eq!(ws_word_windows(src, usize::MAX).next(), None);
+        assert_eq!(ws_word_windows(" \t\n", 1).next(), None);
+
+        for n in 1..=5 {
+            let tokens = src.split_whitespace().collect::<Vec<_>>();
+            let iter = ws_word_windows(src, n);
+            assert_eq!(iter.len(), (tokens.len() + 1).saturating_sub(n));
+            for (window, tokens) in iter.zip(tokens.windows(n)) {
+                assert_eq!(window.split_whitespace().collect::<Vec<_>>(), tokens);
+            }
+        }
+    }
+
+    #[test]
+    fn word_chunks_test() {
+        let src = "\n the   quick\tbrown\r\n\nfox jumps  ";
+        let chunks = word_chunks(src, 2).collect::<Vec<_>>();
+        assert_eq!(chunks, ["the   quick", "brown\r\n\nfox", "jumps"]);
+        assert_eq!(word_chunks(src, 5).collect::<Vec<_>>(), [src.trim()]);
+        assert_eq!(word_chunks(src, 9).collect::<Vec<_>>(), [src.trim()]);
+        assert_eq!(word_chunks("头尾", 3).collect::<Vec<_>>(), ["头尾"]);
+        assert_eq!(word_chunks(" 😀\u{3000}", 1).collect::<Vec<_>>(), ["😀"]);
+        assert_eq!(word_chunks(" \t\n", 1).next(), None);
+
+        let tokens = src.split_whitespace().collect::<Vec<_>>();
+        for n in 1..=6 {
+            let iter = word_chunks(src, n);
+            assert_eq!(iter.len(), tokens.len().div_ceil(n));
+            let chunks = iter.collect::<Vec<_>>();
+            for (chunk, tokens) in chunks.iter().zip(tokens.chunks(n)) {
+                assert_eq!(chunk.split_whitespace().collect::<Vec<_>>(), tokens);
+            }
+            // the chunks and the whitespace between them make up the input
+            let mut rest = src.trim();
+            for chunk in chunks {
+                rest = rest.trim_start().strip_prefix(chunk).unwrap();
+            }
+            assert_eq!(rest, "");
+        }
+    }
+
+    #[cfg(feature = "words")]
+    #[test]
+    fn unicode_word_chunks_test() {
+        let src = "(hello), world's—ünïcode 42; ok ";
+        let chunks = word_chunks(src, 2).unicode_words(true).collect::<Vec<_>>();
+        assert_eq!(chunks, ["hello), world's", "ünïcode 42", "ok"]);
+        let mut iter = word_chunks(src, 3);
+        assert_eq!(iter.next(), Some("(hello), world's—ünïcode 42;"));
+        let mut iter = iter.unicode_words(true);
+        assert_eq!(iter.len(), 1);
+        assert_eq!(iter.next(), Some("ok"));
+        let chunks = word_chunks(src, 2).unicode_words(true).unicode_words(false);
+        assert_eq!(chunks.collect::<Vec<_>>(), ["(hello), world's—ünïcode", "42; ok"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "window size must be non-zero")]
+    fn zero_size() {
+        str_windows_at_word_starts("头尾都是", 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk size must be non-zero")]
+    fn zero_chunk_size() {
+        word_chunks("头尾 都是", 0);
+    }
+}
@@ -0,0 +1,327 @@
+use std::iter::FusedIterator;
+use std::ops::Range;
+
+use grapheme::is_extend;
+use windows::{Boundaries, Windows};
+
+/// The word break property of a char, reduced to what finding words needs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Wb {
+    Other,
+    /// A letter of a script written without spaces, each a word of its own.
+    Ideographic,
+    ALetter,
+    Numeric,
+    Katakana,
+    ExtendNumLet,
+    MidLetter,
+    MidNum,
+    MidNumLet,
+}
+
+/// Returns the word break property of `c`.
+fn wb(c: char) -> Wb {
+    match c {
+        '\u{3040}'..='\u{309f}'
+        | '\u{3400}'..='\u{4dbf}'
+        | '\u{4e00}'..='\u{9fff}'
+        | '\u{f900}'..='\u{faff}'
+        | '\u{20000}'..='\u{3ffff}' => Wb::Ideographic,
+        '\u{30a0}'..='\u{30ff}'
+        | '\u{31f0}'..='\u{31ff}'
+        | '\u{32d0}'..='\u{32fe}'
+        | '\u{3300}'..='\u{3357}'
+        | '\u{ff66}'..='\u{ff9d}' => Wb::Katakana,
+        '_' | '\u{202f}' | '\u{203f}' | '\u{2040}' | '\u{2054}' | '\u{fe33}' | '\u{fe34}'
+        | '\u{fe4d}'..='\u{fe4f}' | '\u{ff3f}' => Wb::ExtendNumLet,
+        ':' | '\u{b7}' | '\u{387}' | '\u{55f}' | '\u{5f4}' | '\u{2027}' | '\u{fe13}'
+        | '\u{fe55}' | '\u{ff1a}' => Wb::MidLetter,
+        ',' | ';' | '\u{37e}' | '\u{589}' | '\u{60c}' | '\u{60d}' | '\u{66c}' | '\u{7f8}'
+        | '\u{2044}' | '\u{fe10}' | '\u{fe14}' | '\u{fe50}' | '\u{fe54}' | '\u{ff0c}'
+        | '\u{ff1b}' => Wb::MidNum,
+        '.' | '\'' | '\u{2018}' | '\u{2019}' | '\u{2024}' | '\u{fe52}' | '\u{ff07}'
+        | '\u{ff0e}' => Wb::MidNumLet,
+        _ if c.is_numeric() => Wb::Numeric,
+        _ if c.is_alphabetic() => Wb::ALetter,
+        _ => Wb::Other,
+    }
+}
+
+/// Returns the end of the char of `input` starting at `i` and the marks
+/// extending it, with its word break property.
+fn next_char(input: &str, i: usize) -> Option<(usize, Wb)> {
+    let mut chars = input[i..].chars();
+    let c = chars.next()?;
+    let rest = chars.as_str().trim_start_matches(is_extend);
+    Some((input.len() - rest.len(), wb(c)))
+}
+
+/// Returns the byte range of the first word of `input` from `i`.
+///
+/// A word is a segment of the word boundaries of UAX #29 that has a letter
+/// or a digit. Letters, digits and connectors like `'_'` join, and so do
+/// letters around an apostrophe, a period or a colon, and digits around a
+/// comma or a period. Ideographs and hiragana are words of one char.
+pub(crate) fn next_word(input: &str, mut i: usize) -> Option<(usize, usize)> {
+    loop {
+        let (end, class) = next_char(input, i)?;
+        match class {
+            Wb::Ideographic => return Some((i, end)),
+            Wb::ALetter | Wb::Numeric | Wb::Katakana | Wb::ExtendNumLet => {
+                let (word_end, has_alphanumeric) = word_end(input, end, class);
+                if has_alphanumeric {
+                    return Some((i, word_end));
+                }
+                i = word_end;
+            }
+            _ => i = end,
+        }
+    }
+}
+
+/// Returns the end of the word of `input` whose last char before `i` has
+/// the property `prev`, and whether it has a letter or a digit.
+fn word_end(input: &str, mut i: usize, mut prev: Wb) -> (usize, bool) {
+    let mut has_alphanumeric = prev != Wb::ExtendNumLet;
+    while let Some((end, cur)) = next_char(input, i) {
+        let alphanumeric = |class| class == Wb::ALetter || class == Wb::Numeric;
+        let joins = match (prev, cur) {
+            (Wb::ALetter, Wb::ALetter) | (Wb::ALetter, Wb::Numeric) => true,
+            (Wb::Numeric, Wb::Numeric) | (Wb::Numeric, Wb::ALetter) => true,
+            (Wb::Katakana, Wb::Katakana) => true,
+            (_, Wb::ExtendNumLet) => true,
+            (Wb::ExtendNumLet, _) => alphanumeric(cur) || cur == Wb::Katakana,
+            _ => false,
+        };
+        if joins {
+            has_alphanumeric |= alphanumeric(cur) || cur == Wb::Katakana;
+            prev = cur;
+            i = end;
+            continue;
+        }
+        // a separator only joins two letters or two digits
+        let separates = matches!(
+            (prev, cur),
+            (Wb::ALetter, Wb::MidLetter)
+                | (Wb::ALetter, Wb::MidNumLet)
+                | (Wb::Numeric, Wb::MidNum)
+                | (Wb::Numeric, Wb::MidNumLet)
+        );
+        match next_char(input, end) {
+            Some((after, next)) if separates && next == prev => i = after,
+            _ => break,
+        }
+    }
+    (i, has_alphanumeric)
+}
+
+/// Returns the number of words of `input`.
+pub(crate) fn word_count(input: &str) -> usize {
+    let mut count = 0;
+    let mut i = 0;
+    while let Some((_, end)) = next_word(input, i) {
+        count += 1;
+        i = end;
+    }
+    count
+}
+
+/// The boundaries of the words of [`word_windows`], to use them with
+/// [`Windows`].
+///
+/// The text between the words, like punctuation and whitespace, isn't part of
+/// any word, so windows of words start and end with a word. This requires the
+/// `words` feature.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::{WordBoundaries, Windows};
+///
+/// let windows = Windows::new("(hello, world) now", 2, WordBoundaries).collect::<Vec<_>>();
+/// assert_eq!(windows, ["hello, world", "world) now"]);
+/// ```
+///
+/// [`Windows`]: crate::Windows
+#[derive(Clone, Copy, Debug, Default)]
+pub struct WordBoundaries;
+
+impl Boundaries for WordBoundaries {
+    fn next_boundary(&self, s: &str, from: usize) -> Option<usize> {
+        next_word(s, from).map(|(_, end)| end)
+    }
+
+    fn unit_start(&self, s: &str, from: usize) -> usize {
+        next_word(s, from).map_or(s.len(), |(start, _)| start)
+    }
+
+    fn count_hint(&self, s: &str) -> Option<usize> {
+        Some(word_count(s))
+    }
+}
+
+/// Returns the windows of `n` consecutive words, each as the slice of the
+/// input from the start of its first word to the end of its last one, with
+/// the separators between them, e.g. `"hello, world"` for the first window
+/// of 2 words of `"hello, world now"`.
+///
+/// The words are the segments of the word boundaries of UAX #29 that have a
+/// letter or a digit, so punctuation and whitespace don't count as words but
+/// are kept inside the windows. Hyphens split words, and ideographs and
+/// hiragana are words of one char. An input of fewer than `n` words yields
+/// nothing.
+///
+/// The segmentation is built in: it implements the rules for letters,
+/// digits, katakana and their separators, with the letters and digits of
+/// the standard library, but no dictionary for scripts like Thai. This
+/// requires the `words` feature.
+///
+/// # Panics
+///
+/// Panics if `n` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::word_windows;
+///
+/// let windows = word_windows("hello, world now", 2).collect::<Vec<_>>();
+/// assert_eq!(windows, ["hello, world", "world now"]);
+/// ```
+pub fn word_windows(input: &str, n: usize) -> WordWindows<'_> {
+    assert!(n != 0, "window size must be non-zero");
+    WordWindows {
+        inner: Windows::new(input, n, WordBoundaries),
+    }
+}
+
+/// An iterator over the windows of `n` consecutive words of a `&str`.
+///
+/// This struct is created by [`word_windows`].
+#[derive(Clone, Debug)]
+pub struct WordWindows<'a> {
+    inner: Windows<'a, WordBoundaries>,
+}
+
+impl<'a> FusedIterator for WordWindows<'a> { }
+
+impl<'a> Iterator for WordWindows<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a> ExactSizeIterator for WordWindows<'a> { }
+
+/// Returns the windows of [`word_windows`] together with the byte range of
+/// each window in the input, e.g. to project spans found in the windows
+/// back onto the input.
+///
+/// This requires the `words` feature.
+///
+/// # Panics
+///
+/// Panics if `n` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::word_windows_indices;
+///
+/// let windows = word_windows_indices("(hello, world) now", 2).collect::<Vec<_>>();
+/// assert_eq!(windows, [(1..13, "hello, world"), (8..18, "world) now")]);
+/// ```
+pub fn word_windows_indices(input: &str, n: usize) -> WordWindowsIndices<'_> {
+    WordWindowsIndices {
+        inner: word_windows(input, n),
+    }
+}
+
+/// An iterator over the windows of `n` consecutive words of a `&str` and
+/// their byte ranges.
+///
+/// This struct is created by [`word_windows_indices`].
+#[derive(Clone, Debug)]
+pub struct WordWindowsIndices<'a> {
+    inner: WordWindows<'a>,
+}
+
+impl<'a> FusedIterator for WordWindowsIndices<'a> { }
+
+impl<'a> Iterator for WordWindowsIndices<'a> {
+    type Item = (Range<usize>, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let offset = self.inner.inner.offset();
+        self.inner.next().map(|window| (offset..offset + window.len(), window))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a> ExactSizeIterator for WordWindowsIndices<'a> { }
+
+
+#[cfg(test)]
+mod tests {
+    use super::{word_count, word_windows, word_windows_indices};
+
+    #[test]
+    fn words_test() {
+        let src = "Don't re-use 3.14, 1,000 or e.g. foo_bar!";
+        let words = word_windows(src, 1).collect::<Vec<_>>();
+        assert_eq!(words, ["Don't", "re", "use", "3.14", "1,000", "or", "e.g", "foo_bar"]);
+        let words = word_windows("東京タワーへ行く。", 1).collect::<Vec<_>>();
+        assert_eq!(words, ["東", "京", "タワー", "へ", "行", "く"]);
+        let words = word_windows("cafe\u{301} naïve _ __init__", 1).collect::<Vec<_>>();
+        assert_eq!(words, ["cafe\u{301}", "naïve", "__init__"]);
+        assert_eq!(word_count("... --- !!!"), 0);
+        assert_eq!(word_count("한국어 텍스트"), 2);
+    }
+
+    #[test]
+    fn windows_test() {
+        let windows = word_windows("  well-known, (fact)  here. ", 2).collect::<Vec<_>>();
+        assert_eq!(windows, ["well-known", "known, (fact", "fact)  here"]);
+        let windows = word_windows("我爱北京", 3).collect::<Vec<_>>();
+        assert_eq!(windows, ["我爱北", "爱北京"]);
+        let iter = word_windows("a b c", 2);
+        assert_eq!(iter.len(), 2);
+        assert_eq!(word_windows("one two", 3).next(), None);
+        assert_eq!(word_windows("one two", usize::MAX).next(), None);
+        assert_eq!(word_windows("a b c", 3).collect::<Vec<_>>(), ["a b c"]);
+        assert_eq!(word_windows("", 1).next(), None);
+    }
+
+    #[test]
+    fn indices_test() {
+        let src = "«Cre\u{300}me» brûlée, s'il vous plaît!";
+        let windows = word_windows_indices(src, 2).collect::<Vec<_>>();
+        assert_eq!(windows[0], (2..20, "Cre\u{300}me» brûlée"));
+        assert_eq!(windows[1].1, "brûlée, s'il");
+        assert_eq!(windows.last().unwrap().1, "vous plaît");
+        for n in 1..=6 {
+            let iter = word_windows_indices(src, n);
+            assert_eq!(iter.len(), 6 - n);
+            for (range, window) in iter {
+                assert_eq!(&src[range], window);
+            }
+        }
+        assert_eq!(word_windows_indices("…!", 1).next(), None);
+        assert_eq!(word_windows_indices(src, usize::MAX).next(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "window size must be non-zero")]
+    fn zero_size() {
+        word_windows("头尾都是", 0);
+    }
+}
@@ -0,0 +1,119 @@
+use std::iter::FusedIterator;
+
+use boundary::nth_char_boundary;
+
+/// Returns chunks of at most `max_chars` chars for soft-wrapping text,
+/// breaking at whitespace when possible.
+///
+/// Each chunk ends at the last whitespace that keeps it within `max_chars`.
+/// Only a single word longer than `max_chars` is broken, into pieces of
+/// `max_chars` chars, starting on a chunk of its own. Text without
+/// whitespace, like CJK, is thus broken every `max_chars` chars.
+///
+/// The whitespace at each break is consumed, as is the whitespace before the
+/// first chunk and after the last one. Whitespace between the words of a
+/// chunk is kept. The chunks are slices of the input in order, so putting
+/// the consumed whitespace back between them gives the input.
+///
+/// # Panics
+///
+/// Panics if `max_chars` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::str_wrap_chunks;
+///
+/// let chunks = str_wrap_chunks("the quick brown fox", 10).collect::<Vec<_>>();
+/// assert_eq!(chunks, ["the quick", "brown fox"]);
+/// ```
+pub fn str_wrap_chunks(input: &str, max_chars: usize) -> StrWrapChunks<'_> {
+    assert!(max_chars != 0, "chunk size must be non-zero");
+    StrWrapChunks {
+        rest: input,
+        max_chars,
+    }
+}
+
+/// An iterator over chunks of a `&str` wrapped at whitespace.
+///
+/// This struct is created by [`str_wrap_chunks`].
+#[derive(Clone, Debug)]
+pub struct StrWrapChunks<'a> {
+    /// The input after the last chunk.
+    rest: &'a str,
+    max_chars: usize,
+}
+
+impl<'a> FusedIterator for StrWrapChunks<'a> { }
+
+impl<'a> Iterator for StrWrapChunks<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = self.rest.trim_start();
+        if rest.is_empty() {
+            self.rest = rest;
+            return None;
+        }
+        let limit = nth_char_boundary(rest, self.max_chars);
+        let end = if limit == rest.len() || rest[limit..].starts_with(char::is_whitespace) {
+            // the chunk ends at a word end
+            limit
+        } else {
+            // the first char doesn't start a break since `rest` is trimmed
+            rest[..limit].rfind(char::is_whitespace).unwrap_or(limit)
+        };
+        let chunk = rest[..end].trim_end();
+        self.rest = &rest[chunk.len()..];
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.rest.len()))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::str_wrap_chunks;
+
+    #[test]
+    fn wrap_test() {
+        let chunks = str_wrap_chunks("  lorem  ipsum dolor\n sit ", 11).collect::<Vec<_>>();
+        assert_eq!(chunks, ["lorem", "ipsum dolor", "sit"]);
+        let chunks = str_wrap_chunks("hi abcdefghijklmnopqrstuvwxyz0123 yo", 10).collect::<Vec<_>>();
+        assert_eq!(chunks, ["hi", "abcdefghij", "klmnopqrst", "uvwxyz0123", "yo"]);
+        let chunks = str_wrap_chunks("东京都庁東京都庁東", 4).collect::<Vec<_>>();
+        assert_eq!(chunks, ["东京都庁", "東京都庁", "東"]);
+        let chunks = str_wrap_chunks("😀 😁 😂", 3).collect::<Vec<_>>();
+        assert_eq!(chunks, ["😀 😁", "😂"]);
+        assert_eq!(str_wrap_chunks(" \t ", 3).next(), None);
+        assert_eq!(str_wrap_chunks("", 3).next(), None);
+    }
+
+    #[test]
+    fn reconstruct_test() {
+        let src = "  Lorem ipsum dolor sit amet, 你好世界你好世界 an extraordinarily \
+                   long   word: pneumonoultramicroscopic 😀😁😂 é ";
+        for max in 1..=20 {
+            let mut rest = src;
+            for chunk in str_wrap_chunks(src, max) {
+                assert!(chunk.chars().count() <= max);
+                assert!(!chunk.starts_with(char::is_whitespace));
+                assert!(!chunk.ends_with(char::is_whitespace));
+                let start = chunk.as_ptr() as usize - rest.as_ptr() as usize;
+                assert!(rest[..start].chars().all(char::is_whitespace));
+                rest = &rest[start + chunk.len()..];
+            }
+            assert!(rest.chars().all(char::is_whitespace));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk size must be non-zero")]
+    fn zero_size() {
+        str_wrap_chunks("头尾都是", 0);
+    }
+}
@@ -0,0 +1,171 @@
+use std::borrow::Cow;
+use std::error::Error;
+use std::fmt;
+use std::iter::FusedIterator;
+
+use boundary::{next_char_boundary, nth_char_boundary};
+
+/// Returns windows of `size` chars that wrap around from the end of the input
+/// back to its start, one window starting at every char.
+///
+/// Windows that span the seam can't be a single slice of the input, so the
+/// windows are `Cow`s: borrowed when they don't wrap, owned when they do.
+///
+/// Returns an error if `size` is greater than the number of chars of the
+/// input, which includes any `size` for an empty input.
+///
+/// # Panics
+///
+/// Panics if `size` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use std::borrow::Cow;
+/// use str_windows::str_windows_wrapping;
+///
+/// let mut iter = str_windows_wrapping("abc", 2).unwrap();
+/// assert_eq!(iter.next(), Some(Cow::Borrowed("ab")));
+/// assert_eq!(iter.next(), Some(Cow::Borrowed("bc")));
+/// assert_eq!(iter.next(), Some(Cow::Owned("ca".to_string())));
+/// assert!(iter.next().is_none());
+///
+/// assert!(str_windows_wrapping("abc", 4).is_err());
+/// ```
+pub fn str_windows_wrapping(
+    input: &str,
+    size: usize,
+) -> Result<StrWindowsWrapping<'_>, WrappingSizeError> {
+    assert!(size != 0, "window size must be non-zero");
+    let chars = input.chars().count();
+    if size > chars {
+        return Err(WrappingSizeError { size, chars });
+    }
+    Ok(StrWindowsWrapping {
+        input,
+        start: 0,
+        end: nth_char_boundary(input, size),
+        wrapped: false,
+        len: chars,
+    })
+}
+
+/// The error returned by [`str_windows_wrapping`] when the window size is
+/// greater than the number of chars of the input.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WrappingSizeError {
+    size: usize,
+    chars: usize,
+}
+
+impl fmt::Display for WrappingSizeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "window size {} is greater than the {} chars of the input", self.size, self.chars)
+    }
+}
+
+impl Error for WrappingSizeError { }
+
+/// An iterator over windows of `size` chars of a `&str`, wrapping around at
+/// its end.
+///
+/// This struct is created by [`str_windows_wrapping`].
+#[derive(Clone, Debug)]
+pub struct StrWindowsWrapping<'a> {
+    input: &'a str,
+    /// Start of the next window.
+    start: usize,
+    /// End of the next window, before `start` once it wrapped around.
+    end: usize,
+    wrapped: bool,
+    /// Number of windows left to yield.
+    len: usize,
+}
+
+impl<'a> FusedIterator for StrWindowsWrapping<'a> { }
+
+impl<'a> Iterator for StrWindowsWrapping<'a> {
+    type Item = Cow<'a, str>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        let window = if self.wrapped {
+            let mut window = String::with_capacity(self.input.len() - self.start + self.end);
+            window.push_str(&self.input[self.start..]);
+            window.push_str(&self.input[..self.end]);
+            Cow::Owned(window)
+        } else {
+            Cow::Borrowed(&self.input[self.start..self.end])
+        };
+        self.start = next_char_boundary(self.input, self.start);
+        if self.end == self.input.len() {
+            self.end = 0;
+            self.wrapped = true;
+        }
+        self.end = next_char_boundary(self.input, self.end);
+        Some(window)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a> ExactSizeIterator for StrWindowsWrapping<'a> { }
+
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+    use super::str_windows_wrapping;
+    use str_windows;
+
+    #[test]
+    fn wrapping_test() {
+        let windows = str_windows_wrapping("abc", 2).unwrap().collect::<Vec<_>>();
+        assert_eq!(windows, ["ab", "bc", "ca"]);
+        assert!(matches!(windows[1], Cow::Borrowed(_)));
+        assert!(matches!(windows[2], Cow::Owned(_)));
+
+        let windows = str_windows_wrapping("头尾都😀", 3).unwrap().collect::<Vec<_>>();
+        assert_eq!(windows, ["头尾都", "尾都😀", "都😀头", "😀头尾"]);
+        assert!(matches!(windows[1], Cow::Borrowed(_)));
+        assert!(matches!(windows[2], Cow::Owned(_)));
+
+        let windows = str_windows_wrapping("😀尾😁", 3).unwrap().collect::<Vec<_>>();
+        assert_eq!(windows, ["😀尾😁", "尾😁😀", "😁😀尾"]);
+
+        let windows = str_windows_wrapping("😀", 1).unwrap().collect::<Vec<_>>();
+        assert_eq!(windows, ["😀"]);
+    }
+
+    #[test]
+    fn matches_repeated_test() {
+        for &src in &["hello, 你好, hi", "头尾都是", "s 😀😁"] {
+            let chars = src.chars().count();
+            let doubled = src.repeat(2);
+            for size in 1..=chars {
+                let iter = str_windows_wrapping(src, size).unwrap();
+                assert_eq!(iter.len(), chars);
+                let expected = str_windows(&doubled, size).take(chars).collect::<Vec<_>>();
+                assert_eq!(iter.collect::<Vec<_>>(), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn too_large_test() {
+        let err = str_windows_wrapping("头尾都是", 5).unwrap_err();
+        assert_eq!(err.to_string(), "window size 5 is greater than the 4 chars of the input");
+        assert!(str_windows_wrapping("", 1).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "window size must be non-zero")]
+    fn zero_size() {
+        let _ = str_windows_wrapping("头尾都是", 0);
+    }
+}
@@ -0,0 +1,184 @@
+use std::io::{self, Write};
+
+/// How [`write_windows_to`] escapes the windows it writes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Escape {
+    /// The windows are written as they are.
+    None,
+    /// Tabs, newlines, carriage returns and backslashes are written as
+    /// `\t`, `\n`, `\r` and `\\`, as in the TSV of databases, so the windows
+    /// can be separated by tabs or newlines.
+    Tsv,
+    /// The windows containing a double quote, a comma, a newline, a carriage
+    /// return or a byte of the separator are quoted with double quotes, with
+    /// their double quotes doubled, as in CSV.
+    Csv,
+}
+
+/// Writes the windows of `iter` to `writer`, separated by `separator` and
+/// escaped with `escape`, and returns the number of bytes written, e.g. to
+/// export windows to a TSV file.
+///
+/// The windows are written in slices between the escaped chars, without
+/// buffering or allocating, so a `BufWriter` is best for writers like files.
+/// The first error of the writer is returned, and no more is written.
+///
+/// # Examples
+///
+/// ```
+/// use str_windows::{str_windows, write_windows_to, Escape};
+///
+/// let mut out = Vec::new();
+/// let written = write_windows_to(str_windows("a\t头😀", 2), &mut out, b"\n", Escape::Tsv);
+/// assert_eq!(out, "a\\t\n\\t头\n头😀".as_bytes());
+/// assert_eq!(written.unwrap(), out.len() as u64);
+///
+/// let mut out = Vec::new();
+/// write_windows_to(str_windows("a,头\"", 2), &mut out, b",", Escape::Csv).unwrap();
+/// assert_eq!(out, "\"a,\",\",头\",\"头\"\"\"".as_bytes());
+/// ```
+pub fn write_windows_to<I, W>(
+    iter: I,
+    mut writer: W,
+    separator: &[u8],
+    escape: Escape,
+) -> io::Result<u64>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+    W: Write,
+{
+    let mut written = 0;
+    for (i, window) in iter.into_iter().enumerate() {
+        if i != 0 {
+            writer.write_all(separator)?;
+            written += separator.len() as u64;
+        }
+        written += write_window(&mut writer, window.as_ref(), separator, escape)?;
+    }
+    Ok(written)
+}
+
+/// Writes `window` escaped with `escape` and returns the number of bytes
+/// written.
+fn write_window<W: Write>(
+    writer: &mut W,
+    window: &str,
+    separator: &[u8],
+    escape: Escape,
+) -> io::Result<u64> {
+    let bytes = window.as_bytes();
+    match escape {
+        Escape::None => {
+            writer.write_all(bytes)?;
+            Ok(bytes.len() as u64)
+        }
+        Escape::Tsv => {
+            let mut written = 0;
+            let mut start = 0;
+            for (i, &byte) in bytes.iter().enumerate() {
+                let escaped: &[u8] = match byte {
+                    b'\t' => b"\\t",
+                    b'\n' => b"\\n",
+                    b'\r' => b"\\r",
+                    b'\\' => b"\\\\",
+                    _ => continue,
+                };
+                writer.write_all(&bytes[start..i])?;
+                writer.write_all(escaped)?;
+                written += (i - start + escaped.len()) as u64;
+                start = i + 1;
+            }
+            writer.write_all(&bytes[start..])?;
+            Ok(written + (bytes.len() - start) as u64)
+        }
+        Escape::Csv => {
+            let quoted = bytes.iter().any(|byte| b"\",\n\r".contains(byte) || separator.contains(byte));
+            if !quoted {
+                writer.write_all(bytes)?;
+                return Ok(bytes.len() as u64);
+            }
+            writer.write_all(b"\"")?;
+            let mut written = 2;
+            // each piece ends with a quote, written again to double it
+            for piece in window.split_inclusive('"') {
+                writer.write_all(piece.as_bytes())?;
+                written += piece.len() as u64;
+                if piece.ends_with('"') {
+                    writer.write_all(b"\"")?;
+                    written += 1;
+                }
+            }
+            writer.write_all(b"\"")?;
+            Ok(written)
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, Write};
+
+    use super::{write_windows_to, Escape};
+    use str_windows;
+
+    fn write(src: &str, size: usize, separator: &[u8], escape: Escape) -> String {
+        let mut out = Vec::new();
+        let written = write_windows_to(str_windows(src, size), &mut out, separator, escape);
+        assert_eq!(written.unwrap(), out.len() as u64);
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn none_test() {
+        assert_eq!(write("头\t尾😀", 2, b"\t", Escape::None), "头\t\t\t尾\t尾😀");
+        assert_eq!(write("头尾", 1, b"", Escape::None), "头尾");
+        assert_eq!(write("头", 2, b"\t", Escape::None), "");
+    }
+
+    #[test]
+    fn tsv_test() {
+        let out = write("头\t尾\\\r\n😀", 2, b"\t", Escape::Tsv);
+        assert_eq!(out, "头\\t\t\\t尾\t尾\\\\\t\\\\\\r\t\\r\\n\t\\n😀");
+        let windows = out.split('\t').collect::<Vec<_>>();
+        assert_eq!(windows.len(), str_windows("头\t尾\\\r\n😀", 2).count());
+    }
+
+    #[test]
+    fn csv_test() {
+        let out = write("头,\"尾\n😀", 3, b",", Escape::Csv);
+        assert_eq!(out, "\"头,\"\"\",\",\"\"尾\",\"\"\"尾\n\",\"尾\n😀\"");
+        assert_eq!(write("头;尾😀", 2, b";", Escape::Csv), "\"头;\";\";尾\";尾😀");
+        assert_eq!(write("头尾😀", 2, b",", Escape::Csv), "头尾,尾😀");
+    }
+
+    /// A writer failing after `limit` bytes.
+    struct Limited {
+        written: usize,
+        limit: usize,
+    }
+
+    impl Write for Limited {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            if self.written + buf.len() > self.limit {
+                return Err(io::Error::new(io::ErrorKind::WriteZero, "full"));
+            }
+            self.written += buf.len();
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn error_test() {
+        let mut writer = Limited { written: 0, limit: 8 };
+        let windows = str_windows("头尾😀都是", 2);
+        let result = write_windows_to(windows, &mut writer, b"\t", Escape::Tsv);
+        assert_eq!(result.unwrap_err().kind(), io::ErrorKind::WriteZero);
+        assert_eq!(writer.written, 7);
+    }
+}